@@ -7,6 +7,8 @@ extern crate criterion;
 use criterion::black_box;
 use criterion::Criterion;
 
+static LARGE: &str = include_str!("fixtures/large.js");
+
 fn ascii_string() -> String {
     string_from_range(0..256)
 }
@@ -27,7 +29,7 @@ fn chars_ascii_chars(c: &mut Criterion) {
     chars(c, &s, "chars_ascii_chars");
 }
 fn chars_non_ascii_chars(c: &mut Criterion) {
-    let mut s = non_ascii_string();
+    let s = non_ascii_string();
     chars(c, &s, "chars_non_ascii_chars");
 }
 fn jsb_ascii_chars(c: &mut Criterion) {
@@ -38,11 +40,20 @@ fn jsb_non_ascii_chars(c: &mut Criterion) {
     let s = non_ascii_string();
     js_buffer(c, &s, "jsb_non_ascii_chars")
 }
+/// The same comparison over real JS, see
+/// `benches/fixtures.rs` for the full tokenizing
+/// cost of this file
+fn chars_large_fixture(c: &mut Criterion) {
+    chars(c, LARGE, "chars_large_fixture");
+}
+fn jsb_large_fixture(c: &mut Criterion) {
+    js_buffer(c, LARGE, "jsb_large_fixture");
+}
 fn chars(c: &mut Criterion, s: &str, name: &str) {
     c.bench_function(name, |b| {
         b.iter(|| {
-            let mut chs = s.chars();
-            while let Some(ch) = chs.next() {
+            let chs = s.chars();
+            for ch in chs {
                 black_box(ch);
             }
         });
@@ -65,5 +76,7 @@ criterion_group!(
     chars_non_ascii_chars,
     jsb_ascii_chars,
     jsb_non_ascii_chars,
+    chars_large_fixture,
+    jsb_large_fixture,
 );
 criterion_main!(benches);
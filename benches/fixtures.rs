@@ -0,0 +1,75 @@
+#![cfg(test)]
+//! Throughput over the vendored files in `benches/fixtures`,
+//! these don't need npm or the network.
+//!
+//! Each file is measured twice, `scanner` is the full `Scanner`
+//! and `tokenizer` is just the raw `Tokenizer` being told where
+//! the regular expressions are ahead of time. The difference
+//! between the two is roughly the cost of the look behind
+//! book keeping used to tell a regex from a `/`.
+extern crate ress;
+
+#[macro_use]
+extern crate criterion;
+
+use criterion::{black_box, Criterion, Throughput};
+use ress::{Scanner, Tokenizer};
+use std::collections::HashSet;
+
+static SMALL: &str = include_str!("fixtures/small.js");
+static MEDIUM: &str = include_str!("fixtures/medium.js");
+static LARGE: &str = include_str!("fixtures/large.js");
+
+fn small(c: &mut Criterion) {
+    run_bench(c, SMALL, "small");
+}
+
+fn medium(c: &mut Criterion) {
+    run_bench(c, MEDIUM, "medium");
+}
+
+fn large(c: &mut Criterion) {
+    run_bench(c, LARGE, "large");
+}
+
+/// The start of every regular expression in `js`
+fn regex_starts(js: &str) -> HashSet<usize> {
+    Scanner::new(js)
+        .map(Result::unwrap)
+        .filter(|item| item.token.is_regex())
+        .map(|item| item.span.start)
+        .collect()
+}
+
+fn run_bench(c: &mut Criterion, js: &str, name: &str) {
+    let regexes = regex_starts(js);
+    let mut group = c.benchmark_group(name);
+    group.throughput(Throughput::Bytes(js.len() as u64));
+    group.bench_function("scanner", |b| {
+        b.iter(|| {
+            for item in Scanner::new(js) {
+                black_box(item.unwrap());
+            }
+        })
+    });
+    group.bench_function("tokenizer", |b| {
+        b.iter(|| {
+            let mut t = Tokenizer::new(js);
+            loop {
+                t.skip_whitespace();
+                let mut item = t.next(true).unwrap();
+                if regexes.contains(&item.start) {
+                    item = t.next_regex(item.end - item.start).unwrap();
+                }
+                if item.start == item.end {
+                    break;
+                }
+                black_box(item);
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, small, medium, large);
+criterion_main!(benches);
@@ -0,0 +1,4017 @@
+/*
+ * A bundler style file: a module table holding
+ * copies of small.js and medium.js
+ */
+(function (modules) {
+    var cache = {};
+    function require(id) {
+        if (cache[id]) {
+            return cache[id].exports;
+        }
+        var module = (cache[id] = { id: id, exports: {} });
+        modules[id].call(module.exports, module, module.exports, require);
+        return module.exports;
+    }
+    return require(0);
+})({
+    0: function (module, exports, require) {
+        /*
+         * A small client side application: an event emitter,
+         * a hash router, a markdown renderer and a store.
+         */
+        (function (global) {
+            'use strict';
+
+            class EventEmitter {
+                constructor() {
+                    this.listeners = new Map();
+                }
+
+                on(name, cb) {
+                    if (!this.listeners.has(name)) {
+                        this.listeners.set(name, []);
+                    }
+                    this.listeners.get(name).push(cb);
+                    return () => this.off(name, cb);
+                }
+
+                off(name, cb) {
+                    const list = this.listeners.get(name);
+                    if (!list) {
+                        return false;
+                    }
+                    const idx = list.indexOf(cb);
+                    if (idx > -1) {
+                        list.splice(idx, 1);
+                    }
+                    return idx > -1;
+                }
+
+                once(name, cb) {
+                    const off = this.on(name, (...args) => {
+                        off();
+                        cb(...args);
+                    });
+                    return off;
+                }
+
+                emit(name, ...args) {
+                    const list = this.listeners.get(name) || [];
+                    for (const cb of list.slice()) {
+                        try {
+                            cb.apply(this, args);
+                        } catch (e) {
+                            console.error(`listener for ${name} failed`, e);
+                        }
+                    }
+                    return list.length > 0;
+                }
+            }
+
+            // Routes look like `/users/:id/posts/:post?`
+            const PARAM = /:(\w+)(\?)?/g;
+            const TRAILING = /\/+$/;
+
+            function compileRoute(path) {
+                const keys = [];
+                const source = path
+                    .replace(TRAILING, '')
+                    .replace(/[.+*()]/g, '\\$&')
+                    .replace(PARAM, (_, key, optional) => {
+                        keys.push({ key, optional: !!optional });
+                        return optional ? '(?:/([^/]+))?' : '/([^/]+)';
+                    })
+                    .replace(/\/\(\?:/g, '(?:');
+                return { keys, re: new RegExp('^' + source + '/?$', 'i') };
+            }
+
+            class Router extends EventEmitter {
+                constructor(routes = {}) {
+                    super();
+                    this.routes = Object.keys(routes).map((path) => ({
+                        path,
+                        handler: routes[path],
+                        ...compileRoute(path),
+                    }));
+                    this.current = null;
+                }
+
+                match(url) {
+                    const [path, query = ''] = url.split('?');
+                    for (const route of this.routes) {
+                        const m = route.re.exec(path);
+                        if (m === null) {
+                            continue;
+                        }
+                        const params = {};
+                        route.keys.forEach(({ key }, i) => {
+                            params[key] = m[i + 1] !== undefined ? decodeURIComponent(m[i + 1]) : null;
+                        });
+                        return { route, params, query: parseQuery(query) };
+                    }
+                    return null;
+                }
+
+                async navigate(url) {
+                    const found = this.match(url);
+                    if (!found) {
+                        this.emit('notfound', url);
+                        return false;
+                    }
+                    const prev = this.current;
+                    this.current = found;
+                    this.emit('change', found, prev);
+                    await found.route.handler(found.params, found.query);
+                    return true;
+                }
+            }
+
+            function parseQuery(query) {
+                return query
+                    .split('&')
+                    .filter(Boolean)
+                    .reduce((acc, pair) => {
+                        const [k, v = ''] = pair.split('=').map(decodeURIComponent);
+                        if (k in acc) {
+                            acc[k] = [].concat(acc[k], v);
+                        } else {
+                            acc[k] = v;
+                        }
+                        return acc;
+                    }, {});
+            }
+
+            const ESCAPES = {
+                '&': '&amp;',
+                '<': '&lt;',
+                '>': '&gt;',
+                '"': '&quot;',
+                "'": '&#39;',
+            };
+
+            function escapeHtml(s) {
+                return String(s).replace(/[&<>"']/g, (c) => ESCAPES[c]);
+            }
+
+            const INLINE = [
+                [/\*\*(.+?)\*\*/g, '<strong>$1</strong>'],
+                [/\*(.+?)\*/g, '<em>$1</em>'],
+                [/~~(.+?)~~/g, '<del>$1</del>'],
+                [/\[([^\]]+)\]\(([^)\s]+)\)/g, '<a href="$2">$1</a>'],
+            ];
+
+            function renderInline(text) {
+                let out = escapeHtml(text);
+                for (let i = 0; i < INLINE.length; i++) {
+                    const [re, replacement] = INLINE[i];
+                    out = out.replace(re, replacement);
+                }
+                return out;
+            }
+
+            function renderMarkdown(src) {
+                const lines = src.split(/\r?\n/);
+                const html = [];
+                let list = null;
+                let code = null;
+                for (let i = 0, len = lines.length; i < len; i++) {
+                    const line = lines[i];
+                    if (code !== null) {
+                        if (/^```/.test(line)) {
+                            html.push(`<pre><code>${escapeHtml(code.join('\n'))}</code></pre>`);
+                            code = null;
+                        } else {
+                            code.push(line);
+                        }
+                        continue;
+                    }
+                    if (/^```/.test(line)) {
+                        code = [];
+                        continue;
+                    }
+                    const heading = /^(#{1,6})\s+(.*)$/.exec(line);
+                    if (heading) {
+                        const level = heading[1].length;
+                        html.push(`<h${level}>${renderInline(heading[2])}</h${level}>`);
+                        continue;
+                    }
+                    const item = /^\s*[-*]\s+(.*)$/.exec(line);
+                    if (item) {
+                        if (list === null) {
+                            list = [];
+                        }
+                        list.push(`<li>${renderInline(item[1])}</li>`);
+                        continue;
+                    } else if (list !== null) {
+                        html.push(`<ul>${list.join('')}</ul>`);
+                        list = null;
+                    }
+                    if (line.trim().length) {
+                        html.push('<p>' + renderInline(line) + '</p>');
+                    }
+                }
+                if (list !== null) {
+                    html.push(`<ul>${list.join('')}</ul>`);
+                }
+                return html.join('\n');
+            }
+
+            function createStore(reducer, initial) {
+                let state = initial;
+                let dispatching = false;
+                const events = new EventEmitter();
+                return {
+                    getState: () => state,
+                    subscribe: (cb) => events.on('change', cb),
+                    dispatch(action) {
+                        if (dispatching) {
+                            throw new Error('reducers may not dispatch actions');
+                        }
+                        if (typeof action !== 'object' || action === null || !('type' in action)) {
+                            throw new TypeError(`invalid action ${JSON.stringify(action)}`);
+                        }
+                        dispatching = true;
+                        try {
+                            const next = reducer(state, action);
+                            const changed = next !== state;
+                            state = next;
+                            if (changed) {
+                                events.emit('change', state, action);
+                            }
+                        } finally {
+                            dispatching = false;
+                        }
+                        return action;
+                    },
+                };
+            }
+
+            const initialTodos = { items: [], filter: 'all', nextId: 1 };
+
+            function todos(state = initialTodos, action) {
+                switch (action.type) {
+                    case 'add':
+                        return {
+                            ...state,
+                            items: state.items.concat({ id: state.nextId, text: action.text, done: false }),
+                            nextId: state.nextId + 1,
+                        };
+                    case 'toggle':
+                        return {
+                            ...state,
+                            items: state.items.map((t) => (t.id === action.id ? { ...t, done: !t.done } : t)),
+                        };
+                    case 'remove':
+                        return { ...state, items: state.items.filter((t) => t.id !== action.id) };
+                    case 'filter':
+                        return { ...state, filter: action.filter };
+                    default:
+                        return state;
+                }
+            }
+
+            function visible({ items, filter }) {
+                if (filter === 'done') {
+                    return items.filter((t) => t.done);
+                }
+                if (filter === 'open') {
+                    return items.filter((t) => !t.done);
+                }
+                return items;
+            }
+
+            function percentDone(items) {
+                if (!items.length) {
+                    return 0;
+                }
+                const done = items.filter((t) => t.done).length;
+                return Math.round((done / items.length) * 100) / 100;
+            }
+
+            function* chunks(list, size) {
+                for (let i = 0; i < list.length; i += size) {
+                    yield list.slice(i, i + size);
+                }
+            }
+
+            async function fetchJson(url, { retries = 3, delay = 100 } = {}) {
+                let attempt = 0;
+                while (true) {
+                    try {
+                        const res = await fetch(url, { headers: { Accept: 'application/json' } });
+                        if (!res.ok) {
+                            throw new Error(`HTTP ${res.status}`);
+                        }
+                        return await res.json();
+                    } catch (e) {
+                        attempt += 1;
+                        if (attempt >= retries) {
+                            throw e;
+                        }
+                        await new Promise((resolve) => setTimeout(resolve, delay * 2 ** attempt));
+                    }
+                }
+            }
+
+            const BYTE_UNITS = ['B', 'KB', 'MB', 'GB', 'TB'];
+
+            function formatBytes(n, digits = 1) {
+                let i = 0;
+                while (n >= 1024 && i < BYTE_UNITS.length - 1) {
+                    n /= 1024;
+                    i++;
+                }
+                return `${n.toFixed(i === 0 ? 0 : digits)} ${BYTE_UNITS[i]}`;
+            }
+
+            const COLOR = /^#?([0-9a-f]{2})([0-9a-f]{2})([0-9a-f]{2})$/i;
+
+            function hexToRgb(hex) {
+                const m = COLOR.exec(hex);
+                return m ? m.slice(1).map((c) => parseInt(c, 16)) : null;
+            }
+
+            function luminance([r, g, b]) {
+                const [lr, lg, lb] = [r, g, b].map((v) => {
+                    v /= 255;
+                    return v <= 0.03928 ? v / 12.92 : ((v + 0.055) / 1.055) ** 2.4;
+                });
+                return 0.2126 * lr + 0.7152 * lg + 0.0722 * lb;
+            }
+
+            function contrast(a, b) {
+                const la = luminance(hexToRgb(a)) + 0.05;
+                const lb = luminance(hexToRgb(b)) + 0.05;
+                return la > lb ? la / lb : lb / la;
+            }
+
+            const app = {
+                EventEmitter,
+                Router,
+                createStore,
+                todos,
+                visible,
+                percentDone,
+                renderMarkdown,
+                escapeHtml,
+                chunks,
+                fetchJson,
+                formatBytes,
+                contrast,
+            };
+
+            if (typeof module !== 'undefined' && module.exports) {
+                module.exports = app;
+            } else {
+                global.app = app;
+            }
+        })(typeof window !== 'undefined' ? window : this);
+    },
+    1: function (module, exports, require) {
+        'use strict';
+        // A tiny debounce helper with a couple of
+        // formatting utilities
+        const DEFAULT_WAIT = 250;
+
+        function debounce(fn, wait = DEFAULT_WAIT) {
+            let timer = null;
+            return function (...args) {
+                if (timer !== null) {
+                    clearTimeout(timer);
+                }
+                timer = setTimeout(() => {
+                    timer = null;
+                    fn.apply(this, args);
+                }, wait);
+            };
+        }
+
+        function pad(n, width) {
+            let s = String(n);
+            while (s.length < width) {
+                s = '0' + s;
+            }
+            return s;
+        }
+
+        /**
+         * Format a date as YYYY-MM-DD HH:MM
+         */
+        function formatDate(d) {
+            const date = [d.getFullYear(), pad(d.getMonth() + 1, 2), pad(d.getDate(), 2)].join('-');
+            const time = `${pad(d.getHours(), 2)}:${pad(d.getMinutes(), 2)}`;
+            return `${date} ${time}`;
+        }
+
+        function slugify(text) {
+            return text
+                .toLowerCase()
+                .replace(/[^a-z0-9]+/g, '-')
+                .replace(/^-+|-+$/g, '');
+        }
+
+        const ratio = 16 / 9, half = ratio / 2;
+
+        module.exports = { debounce, pad, formatDate, slugify, ratio, half };
+    },
+    2: function (module, exports, require) {
+        /*
+         * A small client side application: an event emitter,
+         * a hash router, a markdown renderer and a store.
+         */
+        (function (global) {
+            'use strict';
+
+            class EventEmitter {
+                constructor() {
+                    this.listeners = new Map();
+                }
+
+                on(name, cb) {
+                    if (!this.listeners.has(name)) {
+                        this.listeners.set(name, []);
+                    }
+                    this.listeners.get(name).push(cb);
+                    return () => this.off(name, cb);
+                }
+
+                off(name, cb) {
+                    const list = this.listeners.get(name);
+                    if (!list) {
+                        return false;
+                    }
+                    const idx = list.indexOf(cb);
+                    if (idx > -1) {
+                        list.splice(idx, 1);
+                    }
+                    return idx > -1;
+                }
+
+                once(name, cb) {
+                    const off = this.on(name, (...args) => {
+                        off();
+                        cb(...args);
+                    });
+                    return off;
+                }
+
+                emit(name, ...args) {
+                    const list = this.listeners.get(name) || [];
+                    for (const cb of list.slice()) {
+                        try {
+                            cb.apply(this, args);
+                        } catch (e) {
+                            console.error(`listener for ${name} failed`, e);
+                        }
+                    }
+                    return list.length > 0;
+                }
+            }
+
+            // Routes look like `/users/:id/posts/:post?`
+            const PARAM = /:(\w+)(\?)?/g;
+            const TRAILING = /\/+$/;
+
+            function compileRoute(path) {
+                const keys = [];
+                const source = path
+                    .replace(TRAILING, '')
+                    .replace(/[.+*()]/g, '\\$&')
+                    .replace(PARAM, (_, key, optional) => {
+                        keys.push({ key, optional: !!optional });
+                        return optional ? '(?:/([^/]+))?' : '/([^/]+)';
+                    })
+                    .replace(/\/\(\?:/g, '(?:');
+                return { keys, re: new RegExp('^' + source + '/?$', 'i') };
+            }
+
+            class Router extends EventEmitter {
+                constructor(routes = {}) {
+                    super();
+                    this.routes = Object.keys(routes).map((path) => ({
+                        path,
+                        handler: routes[path],
+                        ...compileRoute(path),
+                    }));
+                    this.current = null;
+                }
+
+                match(url) {
+                    const [path, query = ''] = url.split('?');
+                    for (const route of this.routes) {
+                        const m = route.re.exec(path);
+                        if (m === null) {
+                            continue;
+                        }
+                        const params = {};
+                        route.keys.forEach(({ key }, i) => {
+                            params[key] = m[i + 1] !== undefined ? decodeURIComponent(m[i + 1]) : null;
+                        });
+                        return { route, params, query: parseQuery(query) };
+                    }
+                    return null;
+                }
+
+                async navigate(url) {
+                    const found = this.match(url);
+                    if (!found) {
+                        this.emit('notfound', url);
+                        return false;
+                    }
+                    const prev = this.current;
+                    this.current = found;
+                    this.emit('change', found, prev);
+                    await found.route.handler(found.params, found.query);
+                    return true;
+                }
+            }
+
+            function parseQuery(query) {
+                return query
+                    .split('&')
+                    .filter(Boolean)
+                    .reduce((acc, pair) => {
+                        const [k, v = ''] = pair.split('=').map(decodeURIComponent);
+                        if (k in acc) {
+                            acc[k] = [].concat(acc[k], v);
+                        } else {
+                            acc[k] = v;
+                        }
+                        return acc;
+                    }, {});
+            }
+
+            const ESCAPES = {
+                '&': '&amp;',
+                '<': '&lt;',
+                '>': '&gt;',
+                '"': '&quot;',
+                "'": '&#39;',
+            };
+
+            function escapeHtml(s) {
+                return String(s).replace(/[&<>"']/g, (c) => ESCAPES[c]);
+            }
+
+            const INLINE = [
+                [/\*\*(.+?)\*\*/g, '<strong>$1</strong>'],
+                [/\*(.+?)\*/g, '<em>$1</em>'],
+                [/~~(.+?)~~/g, '<del>$1</del>'],
+                [/\[([^\]]+)\]\(([^)\s]+)\)/g, '<a href="$2">$1</a>'],
+            ];
+
+            function renderInline(text) {
+                let out = escapeHtml(text);
+                for (let i = 0; i < INLINE.length; i++) {
+                    const [re, replacement] = INLINE[i];
+                    out = out.replace(re, replacement);
+                }
+                return out;
+            }
+
+            function renderMarkdown(src) {
+                const lines = src.split(/\r?\n/);
+                const html = [];
+                let list = null;
+                let code = null;
+                for (let i = 0, len = lines.length; i < len; i++) {
+                    const line = lines[i];
+                    if (code !== null) {
+                        if (/^```/.test(line)) {
+                            html.push(`<pre><code>${escapeHtml(code.join('\n'))}</code></pre>`);
+                            code = null;
+                        } else {
+                            code.push(line);
+                        }
+                        continue;
+                    }
+                    if (/^```/.test(line)) {
+                        code = [];
+                        continue;
+                    }
+                    const heading = /^(#{1,6})\s+(.*)$/.exec(line);
+                    if (heading) {
+                        const level = heading[1].length;
+                        html.push(`<h${level}>${renderInline(heading[2])}</h${level}>`);
+                        continue;
+                    }
+                    const item = /^\s*[-*]\s+(.*)$/.exec(line);
+                    if (item) {
+                        if (list === null) {
+                            list = [];
+                        }
+                        list.push(`<li>${renderInline(item[1])}</li>`);
+                        continue;
+                    } else if (list !== null) {
+                        html.push(`<ul>${list.join('')}</ul>`);
+                        list = null;
+                    }
+                    if (line.trim().length) {
+                        html.push('<p>' + renderInline(line) + '</p>');
+                    }
+                }
+                if (list !== null) {
+                    html.push(`<ul>${list.join('')}</ul>`);
+                }
+                return html.join('\n');
+            }
+
+            function createStore(reducer, initial) {
+                let state = initial;
+                let dispatching = false;
+                const events = new EventEmitter();
+                return {
+                    getState: () => state,
+                    subscribe: (cb) => events.on('change', cb),
+                    dispatch(action) {
+                        if (dispatching) {
+                            throw new Error('reducers may not dispatch actions');
+                        }
+                        if (typeof action !== 'object' || action === null || !('type' in action)) {
+                            throw new TypeError(`invalid action ${JSON.stringify(action)}`);
+                        }
+                        dispatching = true;
+                        try {
+                            const next = reducer(state, action);
+                            const changed = next !== state;
+                            state = next;
+                            if (changed) {
+                                events.emit('change', state, action);
+                            }
+                        } finally {
+                            dispatching = false;
+                        }
+                        return action;
+                    },
+                };
+            }
+
+            const initialTodos = { items: [], filter: 'all', nextId: 1 };
+
+            function todos(state = initialTodos, action) {
+                switch (action.type) {
+                    case 'add':
+                        return {
+                            ...state,
+                            items: state.items.concat({ id: state.nextId, text: action.text, done: false }),
+                            nextId: state.nextId + 1,
+                        };
+                    case 'toggle':
+                        return {
+                            ...state,
+                            items: state.items.map((t) => (t.id === action.id ? { ...t, done: !t.done } : t)),
+                        };
+                    case 'remove':
+                        return { ...state, items: state.items.filter((t) => t.id !== action.id) };
+                    case 'filter':
+                        return { ...state, filter: action.filter };
+                    default:
+                        return state;
+                }
+            }
+
+            function visible({ items, filter }) {
+                if (filter === 'done') {
+                    return items.filter((t) => t.done);
+                }
+                if (filter === 'open') {
+                    return items.filter((t) => !t.done);
+                }
+                return items;
+            }
+
+            function percentDone(items) {
+                if (!items.length) {
+                    return 0;
+                }
+                const done = items.filter((t) => t.done).length;
+                return Math.round((done / items.length) * 100) / 100;
+            }
+
+            function* chunks(list, size) {
+                for (let i = 0; i < list.length; i += size) {
+                    yield list.slice(i, i + size);
+                }
+            }
+
+            async function fetchJson(url, { retries = 3, delay = 100 } = {}) {
+                let attempt = 0;
+                while (true) {
+                    try {
+                        const res = await fetch(url, { headers: { Accept: 'application/json' } });
+                        if (!res.ok) {
+                            throw new Error(`HTTP ${res.status}`);
+                        }
+                        return await res.json();
+                    } catch (e) {
+                        attempt += 1;
+                        if (attempt >= retries) {
+                            throw e;
+                        }
+                        await new Promise((resolve) => setTimeout(resolve, delay * 2 ** attempt));
+                    }
+                }
+            }
+
+            const BYTE_UNITS = ['B', 'KB', 'MB', 'GB', 'TB'];
+
+            function formatBytes(n, digits = 1) {
+                let i = 0;
+                while (n >= 1024 && i < BYTE_UNITS.length - 1) {
+                    n /= 1024;
+                    i++;
+                }
+                return `${n.toFixed(i === 0 ? 0 : digits)} ${BYTE_UNITS[i]}`;
+            }
+
+            const COLOR = /^#?([0-9a-f]{2})([0-9a-f]{2})([0-9a-f]{2})$/i;
+
+            function hexToRgb(hex) {
+                const m = COLOR.exec(hex);
+                return m ? m.slice(1).map((c) => parseInt(c, 16)) : null;
+            }
+
+            function luminance([r, g, b]) {
+                const [lr, lg, lb] = [r, g, b].map((v) => {
+                    v /= 255;
+                    return v <= 0.03928 ? v / 12.92 : ((v + 0.055) / 1.055) ** 2.4;
+                });
+                return 0.2126 * lr + 0.7152 * lg + 0.0722 * lb;
+            }
+
+            function contrast(a, b) {
+                const la = luminance(hexToRgb(a)) + 0.05;
+                const lb = luminance(hexToRgb(b)) + 0.05;
+                return la > lb ? la / lb : lb / la;
+            }
+
+            const app = {
+                EventEmitter,
+                Router,
+                createStore,
+                todos,
+                visible,
+                percentDone,
+                renderMarkdown,
+                escapeHtml,
+                chunks,
+                fetchJson,
+                formatBytes,
+                contrast,
+            };
+
+            if (typeof module !== 'undefined' && module.exports) {
+                module.exports = app;
+            } else {
+                global.app = app;
+            }
+        })(typeof window !== 'undefined' ? window : this);
+    },
+    3: function (module, exports, require) {
+        'use strict';
+        // A tiny debounce helper with a couple of
+        // formatting utilities
+        const DEFAULT_WAIT = 250;
+
+        function debounce(fn, wait = DEFAULT_WAIT) {
+            let timer = null;
+            return function (...args) {
+                if (timer !== null) {
+                    clearTimeout(timer);
+                }
+                timer = setTimeout(() => {
+                    timer = null;
+                    fn.apply(this, args);
+                }, wait);
+            };
+        }
+
+        function pad(n, width) {
+            let s = String(n);
+            while (s.length < width) {
+                s = '0' + s;
+            }
+            return s;
+        }
+
+        /**
+         * Format a date as YYYY-MM-DD HH:MM
+         */
+        function formatDate(d) {
+            const date = [d.getFullYear(), pad(d.getMonth() + 1, 2), pad(d.getDate(), 2)].join('-');
+            const time = `${pad(d.getHours(), 2)}:${pad(d.getMinutes(), 2)}`;
+            return `${date} ${time}`;
+        }
+
+        function slugify(text) {
+            return text
+                .toLowerCase()
+                .replace(/[^a-z0-9]+/g, '-')
+                .replace(/^-+|-+$/g, '');
+        }
+
+        const ratio = 16 / 9, half = ratio / 2;
+
+        module.exports = { debounce, pad, formatDate, slugify, ratio, half };
+    },
+    4: function (module, exports, require) {
+        /*
+         * A small client side application: an event emitter,
+         * a hash router, a markdown renderer and a store.
+         */
+        (function (global) {
+            'use strict';
+
+            class EventEmitter {
+                constructor() {
+                    this.listeners = new Map();
+                }
+
+                on(name, cb) {
+                    if (!this.listeners.has(name)) {
+                        this.listeners.set(name, []);
+                    }
+                    this.listeners.get(name).push(cb);
+                    return () => this.off(name, cb);
+                }
+
+                off(name, cb) {
+                    const list = this.listeners.get(name);
+                    if (!list) {
+                        return false;
+                    }
+                    const idx = list.indexOf(cb);
+                    if (idx > -1) {
+                        list.splice(idx, 1);
+                    }
+                    return idx > -1;
+                }
+
+                once(name, cb) {
+                    const off = this.on(name, (...args) => {
+                        off();
+                        cb(...args);
+                    });
+                    return off;
+                }
+
+                emit(name, ...args) {
+                    const list = this.listeners.get(name) || [];
+                    for (const cb of list.slice()) {
+                        try {
+                            cb.apply(this, args);
+                        } catch (e) {
+                            console.error(`listener for ${name} failed`, e);
+                        }
+                    }
+                    return list.length > 0;
+                }
+            }
+
+            // Routes look like `/users/:id/posts/:post?`
+            const PARAM = /:(\w+)(\?)?/g;
+            const TRAILING = /\/+$/;
+
+            function compileRoute(path) {
+                const keys = [];
+                const source = path
+                    .replace(TRAILING, '')
+                    .replace(/[.+*()]/g, '\\$&')
+                    .replace(PARAM, (_, key, optional) => {
+                        keys.push({ key, optional: !!optional });
+                        return optional ? '(?:/([^/]+))?' : '/([^/]+)';
+                    })
+                    .replace(/\/\(\?:/g, '(?:');
+                return { keys, re: new RegExp('^' + source + '/?$', 'i') };
+            }
+
+            class Router extends EventEmitter {
+                constructor(routes = {}) {
+                    super();
+                    this.routes = Object.keys(routes).map((path) => ({
+                        path,
+                        handler: routes[path],
+                        ...compileRoute(path),
+                    }));
+                    this.current = null;
+                }
+
+                match(url) {
+                    const [path, query = ''] = url.split('?');
+                    for (const route of this.routes) {
+                        const m = route.re.exec(path);
+                        if (m === null) {
+                            continue;
+                        }
+                        const params = {};
+                        route.keys.forEach(({ key }, i) => {
+                            params[key] = m[i + 1] !== undefined ? decodeURIComponent(m[i + 1]) : null;
+                        });
+                        return { route, params, query: parseQuery(query) };
+                    }
+                    return null;
+                }
+
+                async navigate(url) {
+                    const found = this.match(url);
+                    if (!found) {
+                        this.emit('notfound', url);
+                        return false;
+                    }
+                    const prev = this.current;
+                    this.current = found;
+                    this.emit('change', found, prev);
+                    await found.route.handler(found.params, found.query);
+                    return true;
+                }
+            }
+
+            function parseQuery(query) {
+                return query
+                    .split('&')
+                    .filter(Boolean)
+                    .reduce((acc, pair) => {
+                        const [k, v = ''] = pair.split('=').map(decodeURIComponent);
+                        if (k in acc) {
+                            acc[k] = [].concat(acc[k], v);
+                        } else {
+                            acc[k] = v;
+                        }
+                        return acc;
+                    }, {});
+            }
+
+            const ESCAPES = {
+                '&': '&amp;',
+                '<': '&lt;',
+                '>': '&gt;',
+                '"': '&quot;',
+                "'": '&#39;',
+            };
+
+            function escapeHtml(s) {
+                return String(s).replace(/[&<>"']/g, (c) => ESCAPES[c]);
+            }
+
+            const INLINE = [
+                [/\*\*(.+?)\*\*/g, '<strong>$1</strong>'],
+                [/\*(.+?)\*/g, '<em>$1</em>'],
+                [/~~(.+?)~~/g, '<del>$1</del>'],
+                [/\[([^\]]+)\]\(([^)\s]+)\)/g, '<a href="$2">$1</a>'],
+            ];
+
+            function renderInline(text) {
+                let out = escapeHtml(text);
+                for (let i = 0; i < INLINE.length; i++) {
+                    const [re, replacement] = INLINE[i];
+                    out = out.replace(re, replacement);
+                }
+                return out;
+            }
+
+            function renderMarkdown(src) {
+                const lines = src.split(/\r?\n/);
+                const html = [];
+                let list = null;
+                let code = null;
+                for (let i = 0, len = lines.length; i < len; i++) {
+                    const line = lines[i];
+                    if (code !== null) {
+                        if (/^```/.test(line)) {
+                            html.push(`<pre><code>${escapeHtml(code.join('\n'))}</code></pre>`);
+                            code = null;
+                        } else {
+                            code.push(line);
+                        }
+                        continue;
+                    }
+                    if (/^```/.test(line)) {
+                        code = [];
+                        continue;
+                    }
+                    const heading = /^(#{1,6})\s+(.*)$/.exec(line);
+                    if (heading) {
+                        const level = heading[1].length;
+                        html.push(`<h${level}>${renderInline(heading[2])}</h${level}>`);
+                        continue;
+                    }
+                    const item = /^\s*[-*]\s+(.*)$/.exec(line);
+                    if (item) {
+                        if (list === null) {
+                            list = [];
+                        }
+                        list.push(`<li>${renderInline(item[1])}</li>`);
+                        continue;
+                    } else if (list !== null) {
+                        html.push(`<ul>${list.join('')}</ul>`);
+                        list = null;
+                    }
+                    if (line.trim().length) {
+                        html.push('<p>' + renderInline(line) + '</p>');
+                    }
+                }
+                if (list !== null) {
+                    html.push(`<ul>${list.join('')}</ul>`);
+                }
+                return html.join('\n');
+            }
+
+            function createStore(reducer, initial) {
+                let state = initial;
+                let dispatching = false;
+                const events = new EventEmitter();
+                return {
+                    getState: () => state,
+                    subscribe: (cb) => events.on('change', cb),
+                    dispatch(action) {
+                        if (dispatching) {
+                            throw new Error('reducers may not dispatch actions');
+                        }
+                        if (typeof action !== 'object' || action === null || !('type' in action)) {
+                            throw new TypeError(`invalid action ${JSON.stringify(action)}`);
+                        }
+                        dispatching = true;
+                        try {
+                            const next = reducer(state, action);
+                            const changed = next !== state;
+                            state = next;
+                            if (changed) {
+                                events.emit('change', state, action);
+                            }
+                        } finally {
+                            dispatching = false;
+                        }
+                        return action;
+                    },
+                };
+            }
+
+            const initialTodos = { items: [], filter: 'all', nextId: 1 };
+
+            function todos(state = initialTodos, action) {
+                switch (action.type) {
+                    case 'add':
+                        return {
+                            ...state,
+                            items: state.items.concat({ id: state.nextId, text: action.text, done: false }),
+                            nextId: state.nextId + 1,
+                        };
+                    case 'toggle':
+                        return {
+                            ...state,
+                            items: state.items.map((t) => (t.id === action.id ? { ...t, done: !t.done } : t)),
+                        };
+                    case 'remove':
+                        return { ...state, items: state.items.filter((t) => t.id !== action.id) };
+                    case 'filter':
+                        return { ...state, filter: action.filter };
+                    default:
+                        return state;
+                }
+            }
+
+            function visible({ items, filter }) {
+                if (filter === 'done') {
+                    return items.filter((t) => t.done);
+                }
+                if (filter === 'open') {
+                    return items.filter((t) => !t.done);
+                }
+                return items;
+            }
+
+            function percentDone(items) {
+                if (!items.length) {
+                    return 0;
+                }
+                const done = items.filter((t) => t.done).length;
+                return Math.round((done / items.length) * 100) / 100;
+            }
+
+            function* chunks(list, size) {
+                for (let i = 0; i < list.length; i += size) {
+                    yield list.slice(i, i + size);
+                }
+            }
+
+            async function fetchJson(url, { retries = 3, delay = 100 } = {}) {
+                let attempt = 0;
+                while (true) {
+                    try {
+                        const res = await fetch(url, { headers: { Accept: 'application/json' } });
+                        if (!res.ok) {
+                            throw new Error(`HTTP ${res.status}`);
+                        }
+                        return await res.json();
+                    } catch (e) {
+                        attempt += 1;
+                        if (attempt >= retries) {
+                            throw e;
+                        }
+                        await new Promise((resolve) => setTimeout(resolve, delay * 2 ** attempt));
+                    }
+                }
+            }
+
+            const BYTE_UNITS = ['B', 'KB', 'MB', 'GB', 'TB'];
+
+            function formatBytes(n, digits = 1) {
+                let i = 0;
+                while (n >= 1024 && i < BYTE_UNITS.length - 1) {
+                    n /= 1024;
+                    i++;
+                }
+                return `${n.toFixed(i === 0 ? 0 : digits)} ${BYTE_UNITS[i]}`;
+            }
+
+            const COLOR = /^#?([0-9a-f]{2})([0-9a-f]{2})([0-9a-f]{2})$/i;
+
+            function hexToRgb(hex) {
+                const m = COLOR.exec(hex);
+                return m ? m.slice(1).map((c) => parseInt(c, 16)) : null;
+            }
+
+            function luminance([r, g, b]) {
+                const [lr, lg, lb] = [r, g, b].map((v) => {
+                    v /= 255;
+                    return v <= 0.03928 ? v / 12.92 : ((v + 0.055) / 1.055) ** 2.4;
+                });
+                return 0.2126 * lr + 0.7152 * lg + 0.0722 * lb;
+            }
+
+            function contrast(a, b) {
+                const la = luminance(hexToRgb(a)) + 0.05;
+                const lb = luminance(hexToRgb(b)) + 0.05;
+                return la > lb ? la / lb : lb / la;
+            }
+
+            const app = {
+                EventEmitter,
+                Router,
+                createStore,
+                todos,
+                visible,
+                percentDone,
+                renderMarkdown,
+                escapeHtml,
+                chunks,
+                fetchJson,
+                formatBytes,
+                contrast,
+            };
+
+            if (typeof module !== 'undefined' && module.exports) {
+                module.exports = app;
+            } else {
+                global.app = app;
+            }
+        })(typeof window !== 'undefined' ? window : this);
+    },
+    5: function (module, exports, require) {
+        'use strict';
+        // A tiny debounce helper with a couple of
+        // formatting utilities
+        const DEFAULT_WAIT = 250;
+
+        function debounce(fn, wait = DEFAULT_WAIT) {
+            let timer = null;
+            return function (...args) {
+                if (timer !== null) {
+                    clearTimeout(timer);
+                }
+                timer = setTimeout(() => {
+                    timer = null;
+                    fn.apply(this, args);
+                }, wait);
+            };
+        }
+
+        function pad(n, width) {
+            let s = String(n);
+            while (s.length < width) {
+                s = '0' + s;
+            }
+            return s;
+        }
+
+        /**
+         * Format a date as YYYY-MM-DD HH:MM
+         */
+        function formatDate(d) {
+            const date = [d.getFullYear(), pad(d.getMonth() + 1, 2), pad(d.getDate(), 2)].join('-');
+            const time = `${pad(d.getHours(), 2)}:${pad(d.getMinutes(), 2)}`;
+            return `${date} ${time}`;
+        }
+
+        function slugify(text) {
+            return text
+                .toLowerCase()
+                .replace(/[^a-z0-9]+/g, '-')
+                .replace(/^-+|-+$/g, '');
+        }
+
+        const ratio = 16 / 9, half = ratio / 2;
+
+        module.exports = { debounce, pad, formatDate, slugify, ratio, half };
+    },
+    6: function (module, exports, require) {
+        /*
+         * A small client side application: an event emitter,
+         * a hash router, a markdown renderer and a store.
+         */
+        (function (global) {
+            'use strict';
+
+            class EventEmitter {
+                constructor() {
+                    this.listeners = new Map();
+                }
+
+                on(name, cb) {
+                    if (!this.listeners.has(name)) {
+                        this.listeners.set(name, []);
+                    }
+                    this.listeners.get(name).push(cb);
+                    return () => this.off(name, cb);
+                }
+
+                off(name, cb) {
+                    const list = this.listeners.get(name);
+                    if (!list) {
+                        return false;
+                    }
+                    const idx = list.indexOf(cb);
+                    if (idx > -1) {
+                        list.splice(idx, 1);
+                    }
+                    return idx > -1;
+                }
+
+                once(name, cb) {
+                    const off = this.on(name, (...args) => {
+                        off();
+                        cb(...args);
+                    });
+                    return off;
+                }
+
+                emit(name, ...args) {
+                    const list = this.listeners.get(name) || [];
+                    for (const cb of list.slice()) {
+                        try {
+                            cb.apply(this, args);
+                        } catch (e) {
+                            console.error(`listener for ${name} failed`, e);
+                        }
+                    }
+                    return list.length > 0;
+                }
+            }
+
+            // Routes look like `/users/:id/posts/:post?`
+            const PARAM = /:(\w+)(\?)?/g;
+            const TRAILING = /\/+$/;
+
+            function compileRoute(path) {
+                const keys = [];
+                const source = path
+                    .replace(TRAILING, '')
+                    .replace(/[.+*()]/g, '\\$&')
+                    .replace(PARAM, (_, key, optional) => {
+                        keys.push({ key, optional: !!optional });
+                        return optional ? '(?:/([^/]+))?' : '/([^/]+)';
+                    })
+                    .replace(/\/\(\?:/g, '(?:');
+                return { keys, re: new RegExp('^' + source + '/?$', 'i') };
+            }
+
+            class Router extends EventEmitter {
+                constructor(routes = {}) {
+                    super();
+                    this.routes = Object.keys(routes).map((path) => ({
+                        path,
+                        handler: routes[path],
+                        ...compileRoute(path),
+                    }));
+                    this.current = null;
+                }
+
+                match(url) {
+                    const [path, query = ''] = url.split('?');
+                    for (const route of this.routes) {
+                        const m = route.re.exec(path);
+                        if (m === null) {
+                            continue;
+                        }
+                        const params = {};
+                        route.keys.forEach(({ key }, i) => {
+                            params[key] = m[i + 1] !== undefined ? decodeURIComponent(m[i + 1]) : null;
+                        });
+                        return { route, params, query: parseQuery(query) };
+                    }
+                    return null;
+                }
+
+                async navigate(url) {
+                    const found = this.match(url);
+                    if (!found) {
+                        this.emit('notfound', url);
+                        return false;
+                    }
+                    const prev = this.current;
+                    this.current = found;
+                    this.emit('change', found, prev);
+                    await found.route.handler(found.params, found.query);
+                    return true;
+                }
+            }
+
+            function parseQuery(query) {
+                return query
+                    .split('&')
+                    .filter(Boolean)
+                    .reduce((acc, pair) => {
+                        const [k, v = ''] = pair.split('=').map(decodeURIComponent);
+                        if (k in acc) {
+                            acc[k] = [].concat(acc[k], v);
+                        } else {
+                            acc[k] = v;
+                        }
+                        return acc;
+                    }, {});
+            }
+
+            const ESCAPES = {
+                '&': '&amp;',
+                '<': '&lt;',
+                '>': '&gt;',
+                '"': '&quot;',
+                "'": '&#39;',
+            };
+
+            function escapeHtml(s) {
+                return String(s).replace(/[&<>"']/g, (c) => ESCAPES[c]);
+            }
+
+            const INLINE = [
+                [/\*\*(.+?)\*\*/g, '<strong>$1</strong>'],
+                [/\*(.+?)\*/g, '<em>$1</em>'],
+                [/~~(.+?)~~/g, '<del>$1</del>'],
+                [/\[([^\]]+)\]\(([^)\s]+)\)/g, '<a href="$2">$1</a>'],
+            ];
+
+            function renderInline(text) {
+                let out = escapeHtml(text);
+                for (let i = 0; i < INLINE.length; i++) {
+                    const [re, replacement] = INLINE[i];
+                    out = out.replace(re, replacement);
+                }
+                return out;
+            }
+
+            function renderMarkdown(src) {
+                const lines = src.split(/\r?\n/);
+                const html = [];
+                let list = null;
+                let code = null;
+                for (let i = 0, len = lines.length; i < len; i++) {
+                    const line = lines[i];
+                    if (code !== null) {
+                        if (/^```/.test(line)) {
+                            html.push(`<pre><code>${escapeHtml(code.join('\n'))}</code></pre>`);
+                            code = null;
+                        } else {
+                            code.push(line);
+                        }
+                        continue;
+                    }
+                    if (/^```/.test(line)) {
+                        code = [];
+                        continue;
+                    }
+                    const heading = /^(#{1,6})\s+(.*)$/.exec(line);
+                    if (heading) {
+                        const level = heading[1].length;
+                        html.push(`<h${level}>${renderInline(heading[2])}</h${level}>`);
+                        continue;
+                    }
+                    const item = /^\s*[-*]\s+(.*)$/.exec(line);
+                    if (item) {
+                        if (list === null) {
+                            list = [];
+                        }
+                        list.push(`<li>${renderInline(item[1])}</li>`);
+                        continue;
+                    } else if (list !== null) {
+                        html.push(`<ul>${list.join('')}</ul>`);
+                        list = null;
+                    }
+                    if (line.trim().length) {
+                        html.push('<p>' + renderInline(line) + '</p>');
+                    }
+                }
+                if (list !== null) {
+                    html.push(`<ul>${list.join('')}</ul>`);
+                }
+                return html.join('\n');
+            }
+
+            function createStore(reducer, initial) {
+                let state = initial;
+                let dispatching = false;
+                const events = new EventEmitter();
+                return {
+                    getState: () => state,
+                    subscribe: (cb) => events.on('change', cb),
+                    dispatch(action) {
+                        if (dispatching) {
+                            throw new Error('reducers may not dispatch actions');
+                        }
+                        if (typeof action !== 'object' || action === null || !('type' in action)) {
+                            throw new TypeError(`invalid action ${JSON.stringify(action)}`);
+                        }
+                        dispatching = true;
+                        try {
+                            const next = reducer(state, action);
+                            const changed = next !== state;
+                            state = next;
+                            if (changed) {
+                                events.emit('change', state, action);
+                            }
+                        } finally {
+                            dispatching = false;
+                        }
+                        return action;
+                    },
+                };
+            }
+
+            const initialTodos = { items: [], filter: 'all', nextId: 1 };
+
+            function todos(state = initialTodos, action) {
+                switch (action.type) {
+                    case 'add':
+                        return {
+                            ...state,
+                            items: state.items.concat({ id: state.nextId, text: action.text, done: false }),
+                            nextId: state.nextId + 1,
+                        };
+                    case 'toggle':
+                        return {
+                            ...state,
+                            items: state.items.map((t) => (t.id === action.id ? { ...t, done: !t.done } : t)),
+                        };
+                    case 'remove':
+                        return { ...state, items: state.items.filter((t) => t.id !== action.id) };
+                    case 'filter':
+                        return { ...state, filter: action.filter };
+                    default:
+                        return state;
+                }
+            }
+
+            function visible({ items, filter }) {
+                if (filter === 'done') {
+                    return items.filter((t) => t.done);
+                }
+                if (filter === 'open') {
+                    return items.filter((t) => !t.done);
+                }
+                return items;
+            }
+
+            function percentDone(items) {
+                if (!items.length) {
+                    return 0;
+                }
+                const done = items.filter((t) => t.done).length;
+                return Math.round((done / items.length) * 100) / 100;
+            }
+
+            function* chunks(list, size) {
+                for (let i = 0; i < list.length; i += size) {
+                    yield list.slice(i, i + size);
+                }
+            }
+
+            async function fetchJson(url, { retries = 3, delay = 100 } = {}) {
+                let attempt = 0;
+                while (true) {
+                    try {
+                        const res = await fetch(url, { headers: { Accept: 'application/json' } });
+                        if (!res.ok) {
+                            throw new Error(`HTTP ${res.status}`);
+                        }
+                        return await res.json();
+                    } catch (e) {
+                        attempt += 1;
+                        if (attempt >= retries) {
+                            throw e;
+                        }
+                        await new Promise((resolve) => setTimeout(resolve, delay * 2 ** attempt));
+                    }
+                }
+            }
+
+            const BYTE_UNITS = ['B', 'KB', 'MB', 'GB', 'TB'];
+
+            function formatBytes(n, digits = 1) {
+                let i = 0;
+                while (n >= 1024 && i < BYTE_UNITS.length - 1) {
+                    n /= 1024;
+                    i++;
+                }
+                return `${n.toFixed(i === 0 ? 0 : digits)} ${BYTE_UNITS[i]}`;
+            }
+
+            const COLOR = /^#?([0-9a-f]{2})([0-9a-f]{2})([0-9a-f]{2})$/i;
+
+            function hexToRgb(hex) {
+                const m = COLOR.exec(hex);
+                return m ? m.slice(1).map((c) => parseInt(c, 16)) : null;
+            }
+
+            function luminance([r, g, b]) {
+                const [lr, lg, lb] = [r, g, b].map((v) => {
+                    v /= 255;
+                    return v <= 0.03928 ? v / 12.92 : ((v + 0.055) / 1.055) ** 2.4;
+                });
+                return 0.2126 * lr + 0.7152 * lg + 0.0722 * lb;
+            }
+
+            function contrast(a, b) {
+                const la = luminance(hexToRgb(a)) + 0.05;
+                const lb = luminance(hexToRgb(b)) + 0.05;
+                return la > lb ? la / lb : lb / la;
+            }
+
+            const app = {
+                EventEmitter,
+                Router,
+                createStore,
+                todos,
+                visible,
+                percentDone,
+                renderMarkdown,
+                escapeHtml,
+                chunks,
+                fetchJson,
+                formatBytes,
+                contrast,
+            };
+
+            if (typeof module !== 'undefined' && module.exports) {
+                module.exports = app;
+            } else {
+                global.app = app;
+            }
+        })(typeof window !== 'undefined' ? window : this);
+    },
+    7: function (module, exports, require) {
+        'use strict';
+        // A tiny debounce helper with a couple of
+        // formatting utilities
+        const DEFAULT_WAIT = 250;
+
+        function debounce(fn, wait = DEFAULT_WAIT) {
+            let timer = null;
+            return function (...args) {
+                if (timer !== null) {
+                    clearTimeout(timer);
+                }
+                timer = setTimeout(() => {
+                    timer = null;
+                    fn.apply(this, args);
+                }, wait);
+            };
+        }
+
+        function pad(n, width) {
+            let s = String(n);
+            while (s.length < width) {
+                s = '0' + s;
+            }
+            return s;
+        }
+
+        /**
+         * Format a date as YYYY-MM-DD HH:MM
+         */
+        function formatDate(d) {
+            const date = [d.getFullYear(), pad(d.getMonth() + 1, 2), pad(d.getDate(), 2)].join('-');
+            const time = `${pad(d.getHours(), 2)}:${pad(d.getMinutes(), 2)}`;
+            return `${date} ${time}`;
+        }
+
+        function slugify(text) {
+            return text
+                .toLowerCase()
+                .replace(/[^a-z0-9]+/g, '-')
+                .replace(/^-+|-+$/g, '');
+        }
+
+        const ratio = 16 / 9, half = ratio / 2;
+
+        module.exports = { debounce, pad, formatDate, slugify, ratio, half };
+    },
+    8: function (module, exports, require) {
+        /*
+         * A small client side application: an event emitter,
+         * a hash router, a markdown renderer and a store.
+         */
+        (function (global) {
+            'use strict';
+
+            class EventEmitter {
+                constructor() {
+                    this.listeners = new Map();
+                }
+
+                on(name, cb) {
+                    if (!this.listeners.has(name)) {
+                        this.listeners.set(name, []);
+                    }
+                    this.listeners.get(name).push(cb);
+                    return () => this.off(name, cb);
+                }
+
+                off(name, cb) {
+                    const list = this.listeners.get(name);
+                    if (!list) {
+                        return false;
+                    }
+                    const idx = list.indexOf(cb);
+                    if (idx > -1) {
+                        list.splice(idx, 1);
+                    }
+                    return idx > -1;
+                }
+
+                once(name, cb) {
+                    const off = this.on(name, (...args) => {
+                        off();
+                        cb(...args);
+                    });
+                    return off;
+                }
+
+                emit(name, ...args) {
+                    const list = this.listeners.get(name) || [];
+                    for (const cb of list.slice()) {
+                        try {
+                            cb.apply(this, args);
+                        } catch (e) {
+                            console.error(`listener for ${name} failed`, e);
+                        }
+                    }
+                    return list.length > 0;
+                }
+            }
+
+            // Routes look like `/users/:id/posts/:post?`
+            const PARAM = /:(\w+)(\?)?/g;
+            const TRAILING = /\/+$/;
+
+            function compileRoute(path) {
+                const keys = [];
+                const source = path
+                    .replace(TRAILING, '')
+                    .replace(/[.+*()]/g, '\\$&')
+                    .replace(PARAM, (_, key, optional) => {
+                        keys.push({ key, optional: !!optional });
+                        return optional ? '(?:/([^/]+))?' : '/([^/]+)';
+                    })
+                    .replace(/\/\(\?:/g, '(?:');
+                return { keys, re: new RegExp('^' + source + '/?$', 'i') };
+            }
+
+            class Router extends EventEmitter {
+                constructor(routes = {}) {
+                    super();
+                    this.routes = Object.keys(routes).map((path) => ({
+                        path,
+                        handler: routes[path],
+                        ...compileRoute(path),
+                    }));
+                    this.current = null;
+                }
+
+                match(url) {
+                    const [path, query = ''] = url.split('?');
+                    for (const route of this.routes) {
+                        const m = route.re.exec(path);
+                        if (m === null) {
+                            continue;
+                        }
+                        const params = {};
+                        route.keys.forEach(({ key }, i) => {
+                            params[key] = m[i + 1] !== undefined ? decodeURIComponent(m[i + 1]) : null;
+                        });
+                        return { route, params, query: parseQuery(query) };
+                    }
+                    return null;
+                }
+
+                async navigate(url) {
+                    const found = this.match(url);
+                    if (!found) {
+                        this.emit('notfound', url);
+                        return false;
+                    }
+                    const prev = this.current;
+                    this.current = found;
+                    this.emit('change', found, prev);
+                    await found.route.handler(found.params, found.query);
+                    return true;
+                }
+            }
+
+            function parseQuery(query) {
+                return query
+                    .split('&')
+                    .filter(Boolean)
+                    .reduce((acc, pair) => {
+                        const [k, v = ''] = pair.split('=').map(decodeURIComponent);
+                        if (k in acc) {
+                            acc[k] = [].concat(acc[k], v);
+                        } else {
+                            acc[k] = v;
+                        }
+                        return acc;
+                    }, {});
+            }
+
+            const ESCAPES = {
+                '&': '&amp;',
+                '<': '&lt;',
+                '>': '&gt;',
+                '"': '&quot;',
+                "'": '&#39;',
+            };
+
+            function escapeHtml(s) {
+                return String(s).replace(/[&<>"']/g, (c) => ESCAPES[c]);
+            }
+
+            const INLINE = [
+                [/\*\*(.+?)\*\*/g, '<strong>$1</strong>'],
+                [/\*(.+?)\*/g, '<em>$1</em>'],
+                [/~~(.+?)~~/g, '<del>$1</del>'],
+                [/\[([^\]]+)\]\(([^)\s]+)\)/g, '<a href="$2">$1</a>'],
+            ];
+
+            function renderInline(text) {
+                let out = escapeHtml(text);
+                for (let i = 0; i < INLINE.length; i++) {
+                    const [re, replacement] = INLINE[i];
+                    out = out.replace(re, replacement);
+                }
+                return out;
+            }
+
+            function renderMarkdown(src) {
+                const lines = src.split(/\r?\n/);
+                const html = [];
+                let list = null;
+                let code = null;
+                for (let i = 0, len = lines.length; i < len; i++) {
+                    const line = lines[i];
+                    if (code !== null) {
+                        if (/^```/.test(line)) {
+                            html.push(`<pre><code>${escapeHtml(code.join('\n'))}</code></pre>`);
+                            code = null;
+                        } else {
+                            code.push(line);
+                        }
+                        continue;
+                    }
+                    if (/^```/.test(line)) {
+                        code = [];
+                        continue;
+                    }
+                    const heading = /^(#{1,6})\s+(.*)$/.exec(line);
+                    if (heading) {
+                        const level = heading[1].length;
+                        html.push(`<h${level}>${renderInline(heading[2])}</h${level}>`);
+                        continue;
+                    }
+                    const item = /^\s*[-*]\s+(.*)$/.exec(line);
+                    if (item) {
+                        if (list === null) {
+                            list = [];
+                        }
+                        list.push(`<li>${renderInline(item[1])}</li>`);
+                        continue;
+                    } else if (list !== null) {
+                        html.push(`<ul>${list.join('')}</ul>`);
+                        list = null;
+                    }
+                    if (line.trim().length) {
+                        html.push('<p>' + renderInline(line) + '</p>');
+                    }
+                }
+                if (list !== null) {
+                    html.push(`<ul>${list.join('')}</ul>`);
+                }
+                return html.join('\n');
+            }
+
+            function createStore(reducer, initial) {
+                let state = initial;
+                let dispatching = false;
+                const events = new EventEmitter();
+                return {
+                    getState: () => state,
+                    subscribe: (cb) => events.on('change', cb),
+                    dispatch(action) {
+                        if (dispatching) {
+                            throw new Error('reducers may not dispatch actions');
+                        }
+                        if (typeof action !== 'object' || action === null || !('type' in action)) {
+                            throw new TypeError(`invalid action ${JSON.stringify(action)}`);
+                        }
+                        dispatching = true;
+                        try {
+                            const next = reducer(state, action);
+                            const changed = next !== state;
+                            state = next;
+                            if (changed) {
+                                events.emit('change', state, action);
+                            }
+                        } finally {
+                            dispatching = false;
+                        }
+                        return action;
+                    },
+                };
+            }
+
+            const initialTodos = { items: [], filter: 'all', nextId: 1 };
+
+            function todos(state = initialTodos, action) {
+                switch (action.type) {
+                    case 'add':
+                        return {
+                            ...state,
+                            items: state.items.concat({ id: state.nextId, text: action.text, done: false }),
+                            nextId: state.nextId + 1,
+                        };
+                    case 'toggle':
+                        return {
+                            ...state,
+                            items: state.items.map((t) => (t.id === action.id ? { ...t, done: !t.done } : t)),
+                        };
+                    case 'remove':
+                        return { ...state, items: state.items.filter((t) => t.id !== action.id) };
+                    case 'filter':
+                        return { ...state, filter: action.filter };
+                    default:
+                        return state;
+                }
+            }
+
+            function visible({ items, filter }) {
+                if (filter === 'done') {
+                    return items.filter((t) => t.done);
+                }
+                if (filter === 'open') {
+                    return items.filter((t) => !t.done);
+                }
+                return items;
+            }
+
+            function percentDone(items) {
+                if (!items.length) {
+                    return 0;
+                }
+                const done = items.filter((t) => t.done).length;
+                return Math.round((done / items.length) * 100) / 100;
+            }
+
+            function* chunks(list, size) {
+                for (let i = 0; i < list.length; i += size) {
+                    yield list.slice(i, i + size);
+                }
+            }
+
+            async function fetchJson(url, { retries = 3, delay = 100 } = {}) {
+                let attempt = 0;
+                while (true) {
+                    try {
+                        const res = await fetch(url, { headers: { Accept: 'application/json' } });
+                        if (!res.ok) {
+                            throw new Error(`HTTP ${res.status}`);
+                        }
+                        return await res.json();
+                    } catch (e) {
+                        attempt += 1;
+                        if (attempt >= retries) {
+                            throw e;
+                        }
+                        await new Promise((resolve) => setTimeout(resolve, delay * 2 ** attempt));
+                    }
+                }
+            }
+
+            const BYTE_UNITS = ['B', 'KB', 'MB', 'GB', 'TB'];
+
+            function formatBytes(n, digits = 1) {
+                let i = 0;
+                while (n >= 1024 && i < BYTE_UNITS.length - 1) {
+                    n /= 1024;
+                    i++;
+                }
+                return `${n.toFixed(i === 0 ? 0 : digits)} ${BYTE_UNITS[i]}`;
+            }
+
+            const COLOR = /^#?([0-9a-f]{2})([0-9a-f]{2})([0-9a-f]{2})$/i;
+
+            function hexToRgb(hex) {
+                const m = COLOR.exec(hex);
+                return m ? m.slice(1).map((c) => parseInt(c, 16)) : null;
+            }
+
+            function luminance([r, g, b]) {
+                const [lr, lg, lb] = [r, g, b].map((v) => {
+                    v /= 255;
+                    return v <= 0.03928 ? v / 12.92 : ((v + 0.055) / 1.055) ** 2.4;
+                });
+                return 0.2126 * lr + 0.7152 * lg + 0.0722 * lb;
+            }
+
+            function contrast(a, b) {
+                const la = luminance(hexToRgb(a)) + 0.05;
+                const lb = luminance(hexToRgb(b)) + 0.05;
+                return la > lb ? la / lb : lb / la;
+            }
+
+            const app = {
+                EventEmitter,
+                Router,
+                createStore,
+                todos,
+                visible,
+                percentDone,
+                renderMarkdown,
+                escapeHtml,
+                chunks,
+                fetchJson,
+                formatBytes,
+                contrast,
+            };
+
+            if (typeof module !== 'undefined' && module.exports) {
+                module.exports = app;
+            } else {
+                global.app = app;
+            }
+        })(typeof window !== 'undefined' ? window : this);
+    },
+    9: function (module, exports, require) {
+        'use strict';
+        // A tiny debounce helper with a couple of
+        // formatting utilities
+        const DEFAULT_WAIT = 250;
+
+        function debounce(fn, wait = DEFAULT_WAIT) {
+            let timer = null;
+            return function (...args) {
+                if (timer !== null) {
+                    clearTimeout(timer);
+                }
+                timer = setTimeout(() => {
+                    timer = null;
+                    fn.apply(this, args);
+                }, wait);
+            };
+        }
+
+        function pad(n, width) {
+            let s = String(n);
+            while (s.length < width) {
+                s = '0' + s;
+            }
+            return s;
+        }
+
+        /**
+         * Format a date as YYYY-MM-DD HH:MM
+         */
+        function formatDate(d) {
+            const date = [d.getFullYear(), pad(d.getMonth() + 1, 2), pad(d.getDate(), 2)].join('-');
+            const time = `${pad(d.getHours(), 2)}:${pad(d.getMinutes(), 2)}`;
+            return `${date} ${time}`;
+        }
+
+        function slugify(text) {
+            return text
+                .toLowerCase()
+                .replace(/[^a-z0-9]+/g, '-')
+                .replace(/^-+|-+$/g, '');
+        }
+
+        const ratio = 16 / 9, half = ratio / 2;
+
+        module.exports = { debounce, pad, formatDate, slugify, ratio, half };
+    },
+    10: function (module, exports, require) {
+        /*
+         * A small client side application: an event emitter,
+         * a hash router, a markdown renderer and a store.
+         */
+        (function (global) {
+            'use strict';
+
+            class EventEmitter {
+                constructor() {
+                    this.listeners = new Map();
+                }
+
+                on(name, cb) {
+                    if (!this.listeners.has(name)) {
+                        this.listeners.set(name, []);
+                    }
+                    this.listeners.get(name).push(cb);
+                    return () => this.off(name, cb);
+                }
+
+                off(name, cb) {
+                    const list = this.listeners.get(name);
+                    if (!list) {
+                        return false;
+                    }
+                    const idx = list.indexOf(cb);
+                    if (idx > -1) {
+                        list.splice(idx, 1);
+                    }
+                    return idx > -1;
+                }
+
+                once(name, cb) {
+                    const off = this.on(name, (...args) => {
+                        off();
+                        cb(...args);
+                    });
+                    return off;
+                }
+
+                emit(name, ...args) {
+                    const list = this.listeners.get(name) || [];
+                    for (const cb of list.slice()) {
+                        try {
+                            cb.apply(this, args);
+                        } catch (e) {
+                            console.error(`listener for ${name} failed`, e);
+                        }
+                    }
+                    return list.length > 0;
+                }
+            }
+
+            // Routes look like `/users/:id/posts/:post?`
+            const PARAM = /:(\w+)(\?)?/g;
+            const TRAILING = /\/+$/;
+
+            function compileRoute(path) {
+                const keys = [];
+                const source = path
+                    .replace(TRAILING, '')
+                    .replace(/[.+*()]/g, '\\$&')
+                    .replace(PARAM, (_, key, optional) => {
+                        keys.push({ key, optional: !!optional });
+                        return optional ? '(?:/([^/]+))?' : '/([^/]+)';
+                    })
+                    .replace(/\/\(\?:/g, '(?:');
+                return { keys, re: new RegExp('^' + source + '/?$', 'i') };
+            }
+
+            class Router extends EventEmitter {
+                constructor(routes = {}) {
+                    super();
+                    this.routes = Object.keys(routes).map((path) => ({
+                        path,
+                        handler: routes[path],
+                        ...compileRoute(path),
+                    }));
+                    this.current = null;
+                }
+
+                match(url) {
+                    const [path, query = ''] = url.split('?');
+                    for (const route of this.routes) {
+                        const m = route.re.exec(path);
+                        if (m === null) {
+                            continue;
+                        }
+                        const params = {};
+                        route.keys.forEach(({ key }, i) => {
+                            params[key] = m[i + 1] !== undefined ? decodeURIComponent(m[i + 1]) : null;
+                        });
+                        return { route, params, query: parseQuery(query) };
+                    }
+                    return null;
+                }
+
+                async navigate(url) {
+                    const found = this.match(url);
+                    if (!found) {
+                        this.emit('notfound', url);
+                        return false;
+                    }
+                    const prev = this.current;
+                    this.current = found;
+                    this.emit('change', found, prev);
+                    await found.route.handler(found.params, found.query);
+                    return true;
+                }
+            }
+
+            function parseQuery(query) {
+                return query
+                    .split('&')
+                    .filter(Boolean)
+                    .reduce((acc, pair) => {
+                        const [k, v = ''] = pair.split('=').map(decodeURIComponent);
+                        if (k in acc) {
+                            acc[k] = [].concat(acc[k], v);
+                        } else {
+                            acc[k] = v;
+                        }
+                        return acc;
+                    }, {});
+            }
+
+            const ESCAPES = {
+                '&': '&amp;',
+                '<': '&lt;',
+                '>': '&gt;',
+                '"': '&quot;',
+                "'": '&#39;',
+            };
+
+            function escapeHtml(s) {
+                return String(s).replace(/[&<>"']/g, (c) => ESCAPES[c]);
+            }
+
+            const INLINE = [
+                [/\*\*(.+?)\*\*/g, '<strong>$1</strong>'],
+                [/\*(.+?)\*/g, '<em>$1</em>'],
+                [/~~(.+?)~~/g, '<del>$1</del>'],
+                [/\[([^\]]+)\]\(([^)\s]+)\)/g, '<a href="$2">$1</a>'],
+            ];
+
+            function renderInline(text) {
+                let out = escapeHtml(text);
+                for (let i = 0; i < INLINE.length; i++) {
+                    const [re, replacement] = INLINE[i];
+                    out = out.replace(re, replacement);
+                }
+                return out;
+            }
+
+            function renderMarkdown(src) {
+                const lines = src.split(/\r?\n/);
+                const html = [];
+                let list = null;
+                let code = null;
+                for (let i = 0, len = lines.length; i < len; i++) {
+                    const line = lines[i];
+                    if (code !== null) {
+                        if (/^```/.test(line)) {
+                            html.push(`<pre><code>${escapeHtml(code.join('\n'))}</code></pre>`);
+                            code = null;
+                        } else {
+                            code.push(line);
+                        }
+                        continue;
+                    }
+                    if (/^```/.test(line)) {
+                        code = [];
+                        continue;
+                    }
+                    const heading = /^(#{1,6})\s+(.*)$/.exec(line);
+                    if (heading) {
+                        const level = heading[1].length;
+                        html.push(`<h${level}>${renderInline(heading[2])}</h${level}>`);
+                        continue;
+                    }
+                    const item = /^\s*[-*]\s+(.*)$/.exec(line);
+                    if (item) {
+                        if (list === null) {
+                            list = [];
+                        }
+                        list.push(`<li>${renderInline(item[1])}</li>`);
+                        continue;
+                    } else if (list !== null) {
+                        html.push(`<ul>${list.join('')}</ul>`);
+                        list = null;
+                    }
+                    if (line.trim().length) {
+                        html.push('<p>' + renderInline(line) + '</p>');
+                    }
+                }
+                if (list !== null) {
+                    html.push(`<ul>${list.join('')}</ul>`);
+                }
+                return html.join('\n');
+            }
+
+            function createStore(reducer, initial) {
+                let state = initial;
+                let dispatching = false;
+                const events = new EventEmitter();
+                return {
+                    getState: () => state,
+                    subscribe: (cb) => events.on('change', cb),
+                    dispatch(action) {
+                        if (dispatching) {
+                            throw new Error('reducers may not dispatch actions');
+                        }
+                        if (typeof action !== 'object' || action === null || !('type' in action)) {
+                            throw new TypeError(`invalid action ${JSON.stringify(action)}`);
+                        }
+                        dispatching = true;
+                        try {
+                            const next = reducer(state, action);
+                            const changed = next !== state;
+                            state = next;
+                            if (changed) {
+                                events.emit('change', state, action);
+                            }
+                        } finally {
+                            dispatching = false;
+                        }
+                        return action;
+                    },
+                };
+            }
+
+            const initialTodos = { items: [], filter: 'all', nextId: 1 };
+
+            function todos(state = initialTodos, action) {
+                switch (action.type) {
+                    case 'add':
+                        return {
+                            ...state,
+                            items: state.items.concat({ id: state.nextId, text: action.text, done: false }),
+                            nextId: state.nextId + 1,
+                        };
+                    case 'toggle':
+                        return {
+                            ...state,
+                            items: state.items.map((t) => (t.id === action.id ? { ...t, done: !t.done } : t)),
+                        };
+                    case 'remove':
+                        return { ...state, items: state.items.filter((t) => t.id !== action.id) };
+                    case 'filter':
+                        return { ...state, filter: action.filter };
+                    default:
+                        return state;
+                }
+            }
+
+            function visible({ items, filter }) {
+                if (filter === 'done') {
+                    return items.filter((t) => t.done);
+                }
+                if (filter === 'open') {
+                    return items.filter((t) => !t.done);
+                }
+                return items;
+            }
+
+            function percentDone(items) {
+                if (!items.length) {
+                    return 0;
+                }
+                const done = items.filter((t) => t.done).length;
+                return Math.round((done / items.length) * 100) / 100;
+            }
+
+            function* chunks(list, size) {
+                for (let i = 0; i < list.length; i += size) {
+                    yield list.slice(i, i + size);
+                }
+            }
+
+            async function fetchJson(url, { retries = 3, delay = 100 } = {}) {
+                let attempt = 0;
+                while (true) {
+                    try {
+                        const res = await fetch(url, { headers: { Accept: 'application/json' } });
+                        if (!res.ok) {
+                            throw new Error(`HTTP ${res.status}`);
+                        }
+                        return await res.json();
+                    } catch (e) {
+                        attempt += 1;
+                        if (attempt >= retries) {
+                            throw e;
+                        }
+                        await new Promise((resolve) => setTimeout(resolve, delay * 2 ** attempt));
+                    }
+                }
+            }
+
+            const BYTE_UNITS = ['B', 'KB', 'MB', 'GB', 'TB'];
+
+            function formatBytes(n, digits = 1) {
+                let i = 0;
+                while (n >= 1024 && i < BYTE_UNITS.length - 1) {
+                    n /= 1024;
+                    i++;
+                }
+                return `${n.toFixed(i === 0 ? 0 : digits)} ${BYTE_UNITS[i]}`;
+            }
+
+            const COLOR = /^#?([0-9a-f]{2})([0-9a-f]{2})([0-9a-f]{2})$/i;
+
+            function hexToRgb(hex) {
+                const m = COLOR.exec(hex);
+                return m ? m.slice(1).map((c) => parseInt(c, 16)) : null;
+            }
+
+            function luminance([r, g, b]) {
+                const [lr, lg, lb] = [r, g, b].map((v) => {
+                    v /= 255;
+                    return v <= 0.03928 ? v / 12.92 : ((v + 0.055) / 1.055) ** 2.4;
+                });
+                return 0.2126 * lr + 0.7152 * lg + 0.0722 * lb;
+            }
+
+            function contrast(a, b) {
+                const la = luminance(hexToRgb(a)) + 0.05;
+                const lb = luminance(hexToRgb(b)) + 0.05;
+                return la > lb ? la / lb : lb / la;
+            }
+
+            const app = {
+                EventEmitter,
+                Router,
+                createStore,
+                todos,
+                visible,
+                percentDone,
+                renderMarkdown,
+                escapeHtml,
+                chunks,
+                fetchJson,
+                formatBytes,
+                contrast,
+            };
+
+            if (typeof module !== 'undefined' && module.exports) {
+                module.exports = app;
+            } else {
+                global.app = app;
+            }
+        })(typeof window !== 'undefined' ? window : this);
+    },
+    11: function (module, exports, require) {
+        'use strict';
+        // A tiny debounce helper with a couple of
+        // formatting utilities
+        const DEFAULT_WAIT = 250;
+
+        function debounce(fn, wait = DEFAULT_WAIT) {
+            let timer = null;
+            return function (...args) {
+                if (timer !== null) {
+                    clearTimeout(timer);
+                }
+                timer = setTimeout(() => {
+                    timer = null;
+                    fn.apply(this, args);
+                }, wait);
+            };
+        }
+
+        function pad(n, width) {
+            let s = String(n);
+            while (s.length < width) {
+                s = '0' + s;
+            }
+            return s;
+        }
+
+        /**
+         * Format a date as YYYY-MM-DD HH:MM
+         */
+        function formatDate(d) {
+            const date = [d.getFullYear(), pad(d.getMonth() + 1, 2), pad(d.getDate(), 2)].join('-');
+            const time = `${pad(d.getHours(), 2)}:${pad(d.getMinutes(), 2)}`;
+            return `${date} ${time}`;
+        }
+
+        function slugify(text) {
+            return text
+                .toLowerCase()
+                .replace(/[^a-z0-9]+/g, '-')
+                .replace(/^-+|-+$/g, '');
+        }
+
+        const ratio = 16 / 9, half = ratio / 2;
+
+        module.exports = { debounce, pad, formatDate, slugify, ratio, half };
+    },
+    12: function (module, exports, require) {
+        /*
+         * A small client side application: an event emitter,
+         * a hash router, a markdown renderer and a store.
+         */
+        (function (global) {
+            'use strict';
+
+            class EventEmitter {
+                constructor() {
+                    this.listeners = new Map();
+                }
+
+                on(name, cb) {
+                    if (!this.listeners.has(name)) {
+                        this.listeners.set(name, []);
+                    }
+                    this.listeners.get(name).push(cb);
+                    return () => this.off(name, cb);
+                }
+
+                off(name, cb) {
+                    const list = this.listeners.get(name);
+                    if (!list) {
+                        return false;
+                    }
+                    const idx = list.indexOf(cb);
+                    if (idx > -1) {
+                        list.splice(idx, 1);
+                    }
+                    return idx > -1;
+                }
+
+                once(name, cb) {
+                    const off = this.on(name, (...args) => {
+                        off();
+                        cb(...args);
+                    });
+                    return off;
+                }
+
+                emit(name, ...args) {
+                    const list = this.listeners.get(name) || [];
+                    for (const cb of list.slice()) {
+                        try {
+                            cb.apply(this, args);
+                        } catch (e) {
+                            console.error(`listener for ${name} failed`, e);
+                        }
+                    }
+                    return list.length > 0;
+                }
+            }
+
+            // Routes look like `/users/:id/posts/:post?`
+            const PARAM = /:(\w+)(\?)?/g;
+            const TRAILING = /\/+$/;
+
+            function compileRoute(path) {
+                const keys = [];
+                const source = path
+                    .replace(TRAILING, '')
+                    .replace(/[.+*()]/g, '\\$&')
+                    .replace(PARAM, (_, key, optional) => {
+                        keys.push({ key, optional: !!optional });
+                        return optional ? '(?:/([^/]+))?' : '/([^/]+)';
+                    })
+                    .replace(/\/\(\?:/g, '(?:');
+                return { keys, re: new RegExp('^' + source + '/?$', 'i') };
+            }
+
+            class Router extends EventEmitter {
+                constructor(routes = {}) {
+                    super();
+                    this.routes = Object.keys(routes).map((path) => ({
+                        path,
+                        handler: routes[path],
+                        ...compileRoute(path),
+                    }));
+                    this.current = null;
+                }
+
+                match(url) {
+                    const [path, query = ''] = url.split('?');
+                    for (const route of this.routes) {
+                        const m = route.re.exec(path);
+                        if (m === null) {
+                            continue;
+                        }
+                        const params = {};
+                        route.keys.forEach(({ key }, i) => {
+                            params[key] = m[i + 1] !== undefined ? decodeURIComponent(m[i + 1]) : null;
+                        });
+                        return { route, params, query: parseQuery(query) };
+                    }
+                    return null;
+                }
+
+                async navigate(url) {
+                    const found = this.match(url);
+                    if (!found) {
+                        this.emit('notfound', url);
+                        return false;
+                    }
+                    const prev = this.current;
+                    this.current = found;
+                    this.emit('change', found, prev);
+                    await found.route.handler(found.params, found.query);
+                    return true;
+                }
+            }
+
+            function parseQuery(query) {
+                return query
+                    .split('&')
+                    .filter(Boolean)
+                    .reduce((acc, pair) => {
+                        const [k, v = ''] = pair.split('=').map(decodeURIComponent);
+                        if (k in acc) {
+                            acc[k] = [].concat(acc[k], v);
+                        } else {
+                            acc[k] = v;
+                        }
+                        return acc;
+                    }, {});
+            }
+
+            const ESCAPES = {
+                '&': '&amp;',
+                '<': '&lt;',
+                '>': '&gt;',
+                '"': '&quot;',
+                "'": '&#39;',
+            };
+
+            function escapeHtml(s) {
+                return String(s).replace(/[&<>"']/g, (c) => ESCAPES[c]);
+            }
+
+            const INLINE = [
+                [/\*\*(.+?)\*\*/g, '<strong>$1</strong>'],
+                [/\*(.+?)\*/g, '<em>$1</em>'],
+                [/~~(.+?)~~/g, '<del>$1</del>'],
+                [/\[([^\]]+)\]\(([^)\s]+)\)/g, '<a href="$2">$1</a>'],
+            ];
+
+            function renderInline(text) {
+                let out = escapeHtml(text);
+                for (let i = 0; i < INLINE.length; i++) {
+                    const [re, replacement] = INLINE[i];
+                    out = out.replace(re, replacement);
+                }
+                return out;
+            }
+
+            function renderMarkdown(src) {
+                const lines = src.split(/\r?\n/);
+                const html = [];
+                let list = null;
+                let code = null;
+                for (let i = 0, len = lines.length; i < len; i++) {
+                    const line = lines[i];
+                    if (code !== null) {
+                        if (/^```/.test(line)) {
+                            html.push(`<pre><code>${escapeHtml(code.join('\n'))}</code></pre>`);
+                            code = null;
+                        } else {
+                            code.push(line);
+                        }
+                        continue;
+                    }
+                    if (/^```/.test(line)) {
+                        code = [];
+                        continue;
+                    }
+                    const heading = /^(#{1,6})\s+(.*)$/.exec(line);
+                    if (heading) {
+                        const level = heading[1].length;
+                        html.push(`<h${level}>${renderInline(heading[2])}</h${level}>`);
+                        continue;
+                    }
+                    const item = /^\s*[-*]\s+(.*)$/.exec(line);
+                    if (item) {
+                        if (list === null) {
+                            list = [];
+                        }
+                        list.push(`<li>${renderInline(item[1])}</li>`);
+                        continue;
+                    } else if (list !== null) {
+                        html.push(`<ul>${list.join('')}</ul>`);
+                        list = null;
+                    }
+                    if (line.trim().length) {
+                        html.push('<p>' + renderInline(line) + '</p>');
+                    }
+                }
+                if (list !== null) {
+                    html.push(`<ul>${list.join('')}</ul>`);
+                }
+                return html.join('\n');
+            }
+
+            function createStore(reducer, initial) {
+                let state = initial;
+                let dispatching = false;
+                const events = new EventEmitter();
+                return {
+                    getState: () => state,
+                    subscribe: (cb) => events.on('change', cb),
+                    dispatch(action) {
+                        if (dispatching) {
+                            throw new Error('reducers may not dispatch actions');
+                        }
+                        if (typeof action !== 'object' || action === null || !('type' in action)) {
+                            throw new TypeError(`invalid action ${JSON.stringify(action)}`);
+                        }
+                        dispatching = true;
+                        try {
+                            const next = reducer(state, action);
+                            const changed = next !== state;
+                            state = next;
+                            if (changed) {
+                                events.emit('change', state, action);
+                            }
+                        } finally {
+                            dispatching = false;
+                        }
+                        return action;
+                    },
+                };
+            }
+
+            const initialTodos = { items: [], filter: 'all', nextId: 1 };
+
+            function todos(state = initialTodos, action) {
+                switch (action.type) {
+                    case 'add':
+                        return {
+                            ...state,
+                            items: state.items.concat({ id: state.nextId, text: action.text, done: false }),
+                            nextId: state.nextId + 1,
+                        };
+                    case 'toggle':
+                        return {
+                            ...state,
+                            items: state.items.map((t) => (t.id === action.id ? { ...t, done: !t.done } : t)),
+                        };
+                    case 'remove':
+                        return { ...state, items: state.items.filter((t) => t.id !== action.id) };
+                    case 'filter':
+                        return { ...state, filter: action.filter };
+                    default:
+                        return state;
+                }
+            }
+
+            function visible({ items, filter }) {
+                if (filter === 'done') {
+                    return items.filter((t) => t.done);
+                }
+                if (filter === 'open') {
+                    return items.filter((t) => !t.done);
+                }
+                return items;
+            }
+
+            function percentDone(items) {
+                if (!items.length) {
+                    return 0;
+                }
+                const done = items.filter((t) => t.done).length;
+                return Math.round((done / items.length) * 100) / 100;
+            }
+
+            function* chunks(list, size) {
+                for (let i = 0; i < list.length; i += size) {
+                    yield list.slice(i, i + size);
+                }
+            }
+
+            async function fetchJson(url, { retries = 3, delay = 100 } = {}) {
+                let attempt = 0;
+                while (true) {
+                    try {
+                        const res = await fetch(url, { headers: { Accept: 'application/json' } });
+                        if (!res.ok) {
+                            throw new Error(`HTTP ${res.status}`);
+                        }
+                        return await res.json();
+                    } catch (e) {
+                        attempt += 1;
+                        if (attempt >= retries) {
+                            throw e;
+                        }
+                        await new Promise((resolve) => setTimeout(resolve, delay * 2 ** attempt));
+                    }
+                }
+            }
+
+            const BYTE_UNITS = ['B', 'KB', 'MB', 'GB', 'TB'];
+
+            function formatBytes(n, digits = 1) {
+                let i = 0;
+                while (n >= 1024 && i < BYTE_UNITS.length - 1) {
+                    n /= 1024;
+                    i++;
+                }
+                return `${n.toFixed(i === 0 ? 0 : digits)} ${BYTE_UNITS[i]}`;
+            }
+
+            const COLOR = /^#?([0-9a-f]{2})([0-9a-f]{2})([0-9a-f]{2})$/i;
+
+            function hexToRgb(hex) {
+                const m = COLOR.exec(hex);
+                return m ? m.slice(1).map((c) => parseInt(c, 16)) : null;
+            }
+
+            function luminance([r, g, b]) {
+                const [lr, lg, lb] = [r, g, b].map((v) => {
+                    v /= 255;
+                    return v <= 0.03928 ? v / 12.92 : ((v + 0.055) / 1.055) ** 2.4;
+                });
+                return 0.2126 * lr + 0.7152 * lg + 0.0722 * lb;
+            }
+
+            function contrast(a, b) {
+                const la = luminance(hexToRgb(a)) + 0.05;
+                const lb = luminance(hexToRgb(b)) + 0.05;
+                return la > lb ? la / lb : lb / la;
+            }
+
+            const app = {
+                EventEmitter,
+                Router,
+                createStore,
+                todos,
+                visible,
+                percentDone,
+                renderMarkdown,
+                escapeHtml,
+                chunks,
+                fetchJson,
+                formatBytes,
+                contrast,
+            };
+
+            if (typeof module !== 'undefined' && module.exports) {
+                module.exports = app;
+            } else {
+                global.app = app;
+            }
+        })(typeof window !== 'undefined' ? window : this);
+    },
+    13: function (module, exports, require) {
+        'use strict';
+        // A tiny debounce helper with a couple of
+        // formatting utilities
+        const DEFAULT_WAIT = 250;
+
+        function debounce(fn, wait = DEFAULT_WAIT) {
+            let timer = null;
+            return function (...args) {
+                if (timer !== null) {
+                    clearTimeout(timer);
+                }
+                timer = setTimeout(() => {
+                    timer = null;
+                    fn.apply(this, args);
+                }, wait);
+            };
+        }
+
+        function pad(n, width) {
+            let s = String(n);
+            while (s.length < width) {
+                s = '0' + s;
+            }
+            return s;
+        }
+
+        /**
+         * Format a date as YYYY-MM-DD HH:MM
+         */
+        function formatDate(d) {
+            const date = [d.getFullYear(), pad(d.getMonth() + 1, 2), pad(d.getDate(), 2)].join('-');
+            const time = `${pad(d.getHours(), 2)}:${pad(d.getMinutes(), 2)}`;
+            return `${date} ${time}`;
+        }
+
+        function slugify(text) {
+            return text
+                .toLowerCase()
+                .replace(/[^a-z0-9]+/g, '-')
+                .replace(/^-+|-+$/g, '');
+        }
+
+        const ratio = 16 / 9, half = ratio / 2;
+
+        module.exports = { debounce, pad, formatDate, slugify, ratio, half };
+    },
+    14: function (module, exports, require) {
+        /*
+         * A small client side application: an event emitter,
+         * a hash router, a markdown renderer and a store.
+         */
+        (function (global) {
+            'use strict';
+
+            class EventEmitter {
+                constructor() {
+                    this.listeners = new Map();
+                }
+
+                on(name, cb) {
+                    if (!this.listeners.has(name)) {
+                        this.listeners.set(name, []);
+                    }
+                    this.listeners.get(name).push(cb);
+                    return () => this.off(name, cb);
+                }
+
+                off(name, cb) {
+                    const list = this.listeners.get(name);
+                    if (!list) {
+                        return false;
+                    }
+                    const idx = list.indexOf(cb);
+                    if (idx > -1) {
+                        list.splice(idx, 1);
+                    }
+                    return idx > -1;
+                }
+
+                once(name, cb) {
+                    const off = this.on(name, (...args) => {
+                        off();
+                        cb(...args);
+                    });
+                    return off;
+                }
+
+                emit(name, ...args) {
+                    const list = this.listeners.get(name) || [];
+                    for (const cb of list.slice()) {
+                        try {
+                            cb.apply(this, args);
+                        } catch (e) {
+                            console.error(`listener for ${name} failed`, e);
+                        }
+                    }
+                    return list.length > 0;
+                }
+            }
+
+            // Routes look like `/users/:id/posts/:post?`
+            const PARAM = /:(\w+)(\?)?/g;
+            const TRAILING = /\/+$/;
+
+            function compileRoute(path) {
+                const keys = [];
+                const source = path
+                    .replace(TRAILING, '')
+                    .replace(/[.+*()]/g, '\\$&')
+                    .replace(PARAM, (_, key, optional) => {
+                        keys.push({ key, optional: !!optional });
+                        return optional ? '(?:/([^/]+))?' : '/([^/]+)';
+                    })
+                    .replace(/\/\(\?:/g, '(?:');
+                return { keys, re: new RegExp('^' + source + '/?$', 'i') };
+            }
+
+            class Router extends EventEmitter {
+                constructor(routes = {}) {
+                    super();
+                    this.routes = Object.keys(routes).map((path) => ({
+                        path,
+                        handler: routes[path],
+                        ...compileRoute(path),
+                    }));
+                    this.current = null;
+                }
+
+                match(url) {
+                    const [path, query = ''] = url.split('?');
+                    for (const route of this.routes) {
+                        const m = route.re.exec(path);
+                        if (m === null) {
+                            continue;
+                        }
+                        const params = {};
+                        route.keys.forEach(({ key }, i) => {
+                            params[key] = m[i + 1] !== undefined ? decodeURIComponent(m[i + 1]) : null;
+                        });
+                        return { route, params, query: parseQuery(query) };
+                    }
+                    return null;
+                }
+
+                async navigate(url) {
+                    const found = this.match(url);
+                    if (!found) {
+                        this.emit('notfound', url);
+                        return false;
+                    }
+                    const prev = this.current;
+                    this.current = found;
+                    this.emit('change', found, prev);
+                    await found.route.handler(found.params, found.query);
+                    return true;
+                }
+            }
+
+            function parseQuery(query) {
+                return query
+                    .split('&')
+                    .filter(Boolean)
+                    .reduce((acc, pair) => {
+                        const [k, v = ''] = pair.split('=').map(decodeURIComponent);
+                        if (k in acc) {
+                            acc[k] = [].concat(acc[k], v);
+                        } else {
+                            acc[k] = v;
+                        }
+                        return acc;
+                    }, {});
+            }
+
+            const ESCAPES = {
+                '&': '&amp;',
+                '<': '&lt;',
+                '>': '&gt;',
+                '"': '&quot;',
+                "'": '&#39;',
+            };
+
+            function escapeHtml(s) {
+                return String(s).replace(/[&<>"']/g, (c) => ESCAPES[c]);
+            }
+
+            const INLINE = [
+                [/\*\*(.+?)\*\*/g, '<strong>$1</strong>'],
+                [/\*(.+?)\*/g, '<em>$1</em>'],
+                [/~~(.+?)~~/g, '<del>$1</del>'],
+                [/\[([^\]]+)\]\(([^)\s]+)\)/g, '<a href="$2">$1</a>'],
+            ];
+
+            function renderInline(text) {
+                let out = escapeHtml(text);
+                for (let i = 0; i < INLINE.length; i++) {
+                    const [re, replacement] = INLINE[i];
+                    out = out.replace(re, replacement);
+                }
+                return out;
+            }
+
+            function renderMarkdown(src) {
+                const lines = src.split(/\r?\n/);
+                const html = [];
+                let list = null;
+                let code = null;
+                for (let i = 0, len = lines.length; i < len; i++) {
+                    const line = lines[i];
+                    if (code !== null) {
+                        if (/^```/.test(line)) {
+                            html.push(`<pre><code>${escapeHtml(code.join('\n'))}</code></pre>`);
+                            code = null;
+                        } else {
+                            code.push(line);
+                        }
+                        continue;
+                    }
+                    if (/^```/.test(line)) {
+                        code = [];
+                        continue;
+                    }
+                    const heading = /^(#{1,6})\s+(.*)$/.exec(line);
+                    if (heading) {
+                        const level = heading[1].length;
+                        html.push(`<h${level}>${renderInline(heading[2])}</h${level}>`);
+                        continue;
+                    }
+                    const item = /^\s*[-*]\s+(.*)$/.exec(line);
+                    if (item) {
+                        if (list === null) {
+                            list = [];
+                        }
+                        list.push(`<li>${renderInline(item[1])}</li>`);
+                        continue;
+                    } else if (list !== null) {
+                        html.push(`<ul>${list.join('')}</ul>`);
+                        list = null;
+                    }
+                    if (line.trim().length) {
+                        html.push('<p>' + renderInline(line) + '</p>');
+                    }
+                }
+                if (list !== null) {
+                    html.push(`<ul>${list.join('')}</ul>`);
+                }
+                return html.join('\n');
+            }
+
+            function createStore(reducer, initial) {
+                let state = initial;
+                let dispatching = false;
+                const events = new EventEmitter();
+                return {
+                    getState: () => state,
+                    subscribe: (cb) => events.on('change', cb),
+                    dispatch(action) {
+                        if (dispatching) {
+                            throw new Error('reducers may not dispatch actions');
+                        }
+                        if (typeof action !== 'object' || action === null || !('type' in action)) {
+                            throw new TypeError(`invalid action ${JSON.stringify(action)}`);
+                        }
+                        dispatching = true;
+                        try {
+                            const next = reducer(state, action);
+                            const changed = next !== state;
+                            state = next;
+                            if (changed) {
+                                events.emit('change', state, action);
+                            }
+                        } finally {
+                            dispatching = false;
+                        }
+                        return action;
+                    },
+                };
+            }
+
+            const initialTodos = { items: [], filter: 'all', nextId: 1 };
+
+            function todos(state = initialTodos, action) {
+                switch (action.type) {
+                    case 'add':
+                        return {
+                            ...state,
+                            items: state.items.concat({ id: state.nextId, text: action.text, done: false }),
+                            nextId: state.nextId + 1,
+                        };
+                    case 'toggle':
+                        return {
+                            ...state,
+                            items: state.items.map((t) => (t.id === action.id ? { ...t, done: !t.done } : t)),
+                        };
+                    case 'remove':
+                        return { ...state, items: state.items.filter((t) => t.id !== action.id) };
+                    case 'filter':
+                        return { ...state, filter: action.filter };
+                    default:
+                        return state;
+                }
+            }
+
+            function visible({ items, filter }) {
+                if (filter === 'done') {
+                    return items.filter((t) => t.done);
+                }
+                if (filter === 'open') {
+                    return items.filter((t) => !t.done);
+                }
+                return items;
+            }
+
+            function percentDone(items) {
+                if (!items.length) {
+                    return 0;
+                }
+                const done = items.filter((t) => t.done).length;
+                return Math.round((done / items.length) * 100) / 100;
+            }
+
+            function* chunks(list, size) {
+                for (let i = 0; i < list.length; i += size) {
+                    yield list.slice(i, i + size);
+                }
+            }
+
+            async function fetchJson(url, { retries = 3, delay = 100 } = {}) {
+                let attempt = 0;
+                while (true) {
+                    try {
+                        const res = await fetch(url, { headers: { Accept: 'application/json' } });
+                        if (!res.ok) {
+                            throw new Error(`HTTP ${res.status}`);
+                        }
+                        return await res.json();
+                    } catch (e) {
+                        attempt += 1;
+                        if (attempt >= retries) {
+                            throw e;
+                        }
+                        await new Promise((resolve) => setTimeout(resolve, delay * 2 ** attempt));
+                    }
+                }
+            }
+
+            const BYTE_UNITS = ['B', 'KB', 'MB', 'GB', 'TB'];
+
+            function formatBytes(n, digits = 1) {
+                let i = 0;
+                while (n >= 1024 && i < BYTE_UNITS.length - 1) {
+                    n /= 1024;
+                    i++;
+                }
+                return `${n.toFixed(i === 0 ? 0 : digits)} ${BYTE_UNITS[i]}`;
+            }
+
+            const COLOR = /^#?([0-9a-f]{2})([0-9a-f]{2})([0-9a-f]{2})$/i;
+
+            function hexToRgb(hex) {
+                const m = COLOR.exec(hex);
+                return m ? m.slice(1).map((c) => parseInt(c, 16)) : null;
+            }
+
+            function luminance([r, g, b]) {
+                const [lr, lg, lb] = [r, g, b].map((v) => {
+                    v /= 255;
+                    return v <= 0.03928 ? v / 12.92 : ((v + 0.055) / 1.055) ** 2.4;
+                });
+                return 0.2126 * lr + 0.7152 * lg + 0.0722 * lb;
+            }
+
+            function contrast(a, b) {
+                const la = luminance(hexToRgb(a)) + 0.05;
+                const lb = luminance(hexToRgb(b)) + 0.05;
+                return la > lb ? la / lb : lb / la;
+            }
+
+            const app = {
+                EventEmitter,
+                Router,
+                createStore,
+                todos,
+                visible,
+                percentDone,
+                renderMarkdown,
+                escapeHtml,
+                chunks,
+                fetchJson,
+                formatBytes,
+                contrast,
+            };
+
+            if (typeof module !== 'undefined' && module.exports) {
+                module.exports = app;
+            } else {
+                global.app = app;
+            }
+        })(typeof window !== 'undefined' ? window : this);
+    },
+    15: function (module, exports, require) {
+        'use strict';
+        // A tiny debounce helper with a couple of
+        // formatting utilities
+        const DEFAULT_WAIT = 250;
+
+        function debounce(fn, wait = DEFAULT_WAIT) {
+            let timer = null;
+            return function (...args) {
+                if (timer !== null) {
+                    clearTimeout(timer);
+                }
+                timer = setTimeout(() => {
+                    timer = null;
+                    fn.apply(this, args);
+                }, wait);
+            };
+        }
+
+        function pad(n, width) {
+            let s = String(n);
+            while (s.length < width) {
+                s = '0' + s;
+            }
+            return s;
+        }
+
+        /**
+         * Format a date as YYYY-MM-DD HH:MM
+         */
+        function formatDate(d) {
+            const date = [d.getFullYear(), pad(d.getMonth() + 1, 2), pad(d.getDate(), 2)].join('-');
+            const time = `${pad(d.getHours(), 2)}:${pad(d.getMinutes(), 2)}`;
+            return `${date} ${time}`;
+        }
+
+        function slugify(text) {
+            return text
+                .toLowerCase()
+                .replace(/[^a-z0-9]+/g, '-')
+                .replace(/^-+|-+$/g, '');
+        }
+
+        const ratio = 16 / 9, half = ratio / 2;
+
+        module.exports = { debounce, pad, formatDate, slugify, ratio, half };
+    },
+    16: function (module, exports, require) {
+        /*
+         * A small client side application: an event emitter,
+         * a hash router, a markdown renderer and a store.
+         */
+        (function (global) {
+            'use strict';
+
+            class EventEmitter {
+                constructor() {
+                    this.listeners = new Map();
+                }
+
+                on(name, cb) {
+                    if (!this.listeners.has(name)) {
+                        this.listeners.set(name, []);
+                    }
+                    this.listeners.get(name).push(cb);
+                    return () => this.off(name, cb);
+                }
+
+                off(name, cb) {
+                    const list = this.listeners.get(name);
+                    if (!list) {
+                        return false;
+                    }
+                    const idx = list.indexOf(cb);
+                    if (idx > -1) {
+                        list.splice(idx, 1);
+                    }
+                    return idx > -1;
+                }
+
+                once(name, cb) {
+                    const off = this.on(name, (...args) => {
+                        off();
+                        cb(...args);
+                    });
+                    return off;
+                }
+
+                emit(name, ...args) {
+                    const list = this.listeners.get(name) || [];
+                    for (const cb of list.slice()) {
+                        try {
+                            cb.apply(this, args);
+                        } catch (e) {
+                            console.error(`listener for ${name} failed`, e);
+                        }
+                    }
+                    return list.length > 0;
+                }
+            }
+
+            // Routes look like `/users/:id/posts/:post?`
+            const PARAM = /:(\w+)(\?)?/g;
+            const TRAILING = /\/+$/;
+
+            function compileRoute(path) {
+                const keys = [];
+                const source = path
+                    .replace(TRAILING, '')
+                    .replace(/[.+*()]/g, '\\$&')
+                    .replace(PARAM, (_, key, optional) => {
+                        keys.push({ key, optional: !!optional });
+                        return optional ? '(?:/([^/]+))?' : '/([^/]+)';
+                    })
+                    .replace(/\/\(\?:/g, '(?:');
+                return { keys, re: new RegExp('^' + source + '/?$', 'i') };
+            }
+
+            class Router extends EventEmitter {
+                constructor(routes = {}) {
+                    super();
+                    this.routes = Object.keys(routes).map((path) => ({
+                        path,
+                        handler: routes[path],
+                        ...compileRoute(path),
+                    }));
+                    this.current = null;
+                }
+
+                match(url) {
+                    const [path, query = ''] = url.split('?');
+                    for (const route of this.routes) {
+                        const m = route.re.exec(path);
+                        if (m === null) {
+                            continue;
+                        }
+                        const params = {};
+                        route.keys.forEach(({ key }, i) => {
+                            params[key] = m[i + 1] !== undefined ? decodeURIComponent(m[i + 1]) : null;
+                        });
+                        return { route, params, query: parseQuery(query) };
+                    }
+                    return null;
+                }
+
+                async navigate(url) {
+                    const found = this.match(url);
+                    if (!found) {
+                        this.emit('notfound', url);
+                        return false;
+                    }
+                    const prev = this.current;
+                    this.current = found;
+                    this.emit('change', found, prev);
+                    await found.route.handler(found.params, found.query);
+                    return true;
+                }
+            }
+
+            function parseQuery(query) {
+                return query
+                    .split('&')
+                    .filter(Boolean)
+                    .reduce((acc, pair) => {
+                        const [k, v = ''] = pair.split('=').map(decodeURIComponent);
+                        if (k in acc) {
+                            acc[k] = [].concat(acc[k], v);
+                        } else {
+                            acc[k] = v;
+                        }
+                        return acc;
+                    }, {});
+            }
+
+            const ESCAPES = {
+                '&': '&amp;',
+                '<': '&lt;',
+                '>': '&gt;',
+                '"': '&quot;',
+                "'": '&#39;',
+            };
+
+            function escapeHtml(s) {
+                return String(s).replace(/[&<>"']/g, (c) => ESCAPES[c]);
+            }
+
+            const INLINE = [
+                [/\*\*(.+?)\*\*/g, '<strong>$1</strong>'],
+                [/\*(.+?)\*/g, '<em>$1</em>'],
+                [/~~(.+?)~~/g, '<del>$1</del>'],
+                [/\[([^\]]+)\]\(([^)\s]+)\)/g, '<a href="$2">$1</a>'],
+            ];
+
+            function renderInline(text) {
+                let out = escapeHtml(text);
+                for (let i = 0; i < INLINE.length; i++) {
+                    const [re, replacement] = INLINE[i];
+                    out = out.replace(re, replacement);
+                }
+                return out;
+            }
+
+            function renderMarkdown(src) {
+                const lines = src.split(/\r?\n/);
+                const html = [];
+                let list = null;
+                let code = null;
+                for (let i = 0, len = lines.length; i < len; i++) {
+                    const line = lines[i];
+                    if (code !== null) {
+                        if (/^```/.test(line)) {
+                            html.push(`<pre><code>${escapeHtml(code.join('\n'))}</code></pre>`);
+                            code = null;
+                        } else {
+                            code.push(line);
+                        }
+                        continue;
+                    }
+                    if (/^```/.test(line)) {
+                        code = [];
+                        continue;
+                    }
+                    const heading = /^(#{1,6})\s+(.*)$/.exec(line);
+                    if (heading) {
+                        const level = heading[1].length;
+                        html.push(`<h${level}>${renderInline(heading[2])}</h${level}>`);
+                        continue;
+                    }
+                    const item = /^\s*[-*]\s+(.*)$/.exec(line);
+                    if (item) {
+                        if (list === null) {
+                            list = [];
+                        }
+                        list.push(`<li>${renderInline(item[1])}</li>`);
+                        continue;
+                    } else if (list !== null) {
+                        html.push(`<ul>${list.join('')}</ul>`);
+                        list = null;
+                    }
+                    if (line.trim().length) {
+                        html.push('<p>' + renderInline(line) + '</p>');
+                    }
+                }
+                if (list !== null) {
+                    html.push(`<ul>${list.join('')}</ul>`);
+                }
+                return html.join('\n');
+            }
+
+            function createStore(reducer, initial) {
+                let state = initial;
+                let dispatching = false;
+                const events = new EventEmitter();
+                return {
+                    getState: () => state,
+                    subscribe: (cb) => events.on('change', cb),
+                    dispatch(action) {
+                        if (dispatching) {
+                            throw new Error('reducers may not dispatch actions');
+                        }
+                        if (typeof action !== 'object' || action === null || !('type' in action)) {
+                            throw new TypeError(`invalid action ${JSON.stringify(action)}`);
+                        }
+                        dispatching = true;
+                        try {
+                            const next = reducer(state, action);
+                            const changed = next !== state;
+                            state = next;
+                            if (changed) {
+                                events.emit('change', state, action);
+                            }
+                        } finally {
+                            dispatching = false;
+                        }
+                        return action;
+                    },
+                };
+            }
+
+            const initialTodos = { items: [], filter: 'all', nextId: 1 };
+
+            function todos(state = initialTodos, action) {
+                switch (action.type) {
+                    case 'add':
+                        return {
+                            ...state,
+                            items: state.items.concat({ id: state.nextId, text: action.text, done: false }),
+                            nextId: state.nextId + 1,
+                        };
+                    case 'toggle':
+                        return {
+                            ...state,
+                            items: state.items.map((t) => (t.id === action.id ? { ...t, done: !t.done } : t)),
+                        };
+                    case 'remove':
+                        return { ...state, items: state.items.filter((t) => t.id !== action.id) };
+                    case 'filter':
+                        return { ...state, filter: action.filter };
+                    default:
+                        return state;
+                }
+            }
+
+            function visible({ items, filter }) {
+                if (filter === 'done') {
+                    return items.filter((t) => t.done);
+                }
+                if (filter === 'open') {
+                    return items.filter((t) => !t.done);
+                }
+                return items;
+            }
+
+            function percentDone(items) {
+                if (!items.length) {
+                    return 0;
+                }
+                const done = items.filter((t) => t.done).length;
+                return Math.round((done / items.length) * 100) / 100;
+            }
+
+            function* chunks(list, size) {
+                for (let i = 0; i < list.length; i += size) {
+                    yield list.slice(i, i + size);
+                }
+            }
+
+            async function fetchJson(url, { retries = 3, delay = 100 } = {}) {
+                let attempt = 0;
+                while (true) {
+                    try {
+                        const res = await fetch(url, { headers: { Accept: 'application/json' } });
+                        if (!res.ok) {
+                            throw new Error(`HTTP ${res.status}`);
+                        }
+                        return await res.json();
+                    } catch (e) {
+                        attempt += 1;
+                        if (attempt >= retries) {
+                            throw e;
+                        }
+                        await new Promise((resolve) => setTimeout(resolve, delay * 2 ** attempt));
+                    }
+                }
+            }
+
+            const BYTE_UNITS = ['B', 'KB', 'MB', 'GB', 'TB'];
+
+            function formatBytes(n, digits = 1) {
+                let i = 0;
+                while (n >= 1024 && i < BYTE_UNITS.length - 1) {
+                    n /= 1024;
+                    i++;
+                }
+                return `${n.toFixed(i === 0 ? 0 : digits)} ${BYTE_UNITS[i]}`;
+            }
+
+            const COLOR = /^#?([0-9a-f]{2})([0-9a-f]{2})([0-9a-f]{2})$/i;
+
+            function hexToRgb(hex) {
+                const m = COLOR.exec(hex);
+                return m ? m.slice(1).map((c) => parseInt(c, 16)) : null;
+            }
+
+            function luminance([r, g, b]) {
+                const [lr, lg, lb] = [r, g, b].map((v) => {
+                    v /= 255;
+                    return v <= 0.03928 ? v / 12.92 : ((v + 0.055) / 1.055) ** 2.4;
+                });
+                return 0.2126 * lr + 0.7152 * lg + 0.0722 * lb;
+            }
+
+            function contrast(a, b) {
+                const la = luminance(hexToRgb(a)) + 0.05;
+                const lb = luminance(hexToRgb(b)) + 0.05;
+                return la > lb ? la / lb : lb / la;
+            }
+
+            const app = {
+                EventEmitter,
+                Router,
+                createStore,
+                todos,
+                visible,
+                percentDone,
+                renderMarkdown,
+                escapeHtml,
+                chunks,
+                fetchJson,
+                formatBytes,
+                contrast,
+            };
+
+            if (typeof module !== 'undefined' && module.exports) {
+                module.exports = app;
+            } else {
+                global.app = app;
+            }
+        })(typeof window !== 'undefined' ? window : this);
+    },
+    17: function (module, exports, require) {
+        'use strict';
+        // A tiny debounce helper with a couple of
+        // formatting utilities
+        const DEFAULT_WAIT = 250;
+
+        function debounce(fn, wait = DEFAULT_WAIT) {
+            let timer = null;
+            return function (...args) {
+                if (timer !== null) {
+                    clearTimeout(timer);
+                }
+                timer = setTimeout(() => {
+                    timer = null;
+                    fn.apply(this, args);
+                }, wait);
+            };
+        }
+
+        function pad(n, width) {
+            let s = String(n);
+            while (s.length < width) {
+                s = '0' + s;
+            }
+            return s;
+        }
+
+        /**
+         * Format a date as YYYY-MM-DD HH:MM
+         */
+        function formatDate(d) {
+            const date = [d.getFullYear(), pad(d.getMonth() + 1, 2), pad(d.getDate(), 2)].join('-');
+            const time = `${pad(d.getHours(), 2)}:${pad(d.getMinutes(), 2)}`;
+            return `${date} ${time}`;
+        }
+
+        function slugify(text) {
+            return text
+                .toLowerCase()
+                .replace(/[^a-z0-9]+/g, '-')
+                .replace(/^-+|-+$/g, '');
+        }
+
+        const ratio = 16 / 9, half = ratio / 2;
+
+        module.exports = { debounce, pad, formatDate, slugify, ratio, half };
+    },
+    18: function (module, exports, require) {
+        /*
+         * A small client side application: an event emitter,
+         * a hash router, a markdown renderer and a store.
+         */
+        (function (global) {
+            'use strict';
+
+            class EventEmitter {
+                constructor() {
+                    this.listeners = new Map();
+                }
+
+                on(name, cb) {
+                    if (!this.listeners.has(name)) {
+                        this.listeners.set(name, []);
+                    }
+                    this.listeners.get(name).push(cb);
+                    return () => this.off(name, cb);
+                }
+
+                off(name, cb) {
+                    const list = this.listeners.get(name);
+                    if (!list) {
+                        return false;
+                    }
+                    const idx = list.indexOf(cb);
+                    if (idx > -1) {
+                        list.splice(idx, 1);
+                    }
+                    return idx > -1;
+                }
+
+                once(name, cb) {
+                    const off = this.on(name, (...args) => {
+                        off();
+                        cb(...args);
+                    });
+                    return off;
+                }
+
+                emit(name, ...args) {
+                    const list = this.listeners.get(name) || [];
+                    for (const cb of list.slice()) {
+                        try {
+                            cb.apply(this, args);
+                        } catch (e) {
+                            console.error(`listener for ${name} failed`, e);
+                        }
+                    }
+                    return list.length > 0;
+                }
+            }
+
+            // Routes look like `/users/:id/posts/:post?`
+            const PARAM = /:(\w+)(\?)?/g;
+            const TRAILING = /\/+$/;
+
+            function compileRoute(path) {
+                const keys = [];
+                const source = path
+                    .replace(TRAILING, '')
+                    .replace(/[.+*()]/g, '\\$&')
+                    .replace(PARAM, (_, key, optional) => {
+                        keys.push({ key, optional: !!optional });
+                        return optional ? '(?:/([^/]+))?' : '/([^/]+)';
+                    })
+                    .replace(/\/\(\?:/g, '(?:');
+                return { keys, re: new RegExp('^' + source + '/?$', 'i') };
+            }
+
+            class Router extends EventEmitter {
+                constructor(routes = {}) {
+                    super();
+                    this.routes = Object.keys(routes).map((path) => ({
+                        path,
+                        handler: routes[path],
+                        ...compileRoute(path),
+                    }));
+                    this.current = null;
+                }
+
+                match(url) {
+                    const [path, query = ''] = url.split('?');
+                    for (const route of this.routes) {
+                        const m = route.re.exec(path);
+                        if (m === null) {
+                            continue;
+                        }
+                        const params = {};
+                        route.keys.forEach(({ key }, i) => {
+                            params[key] = m[i + 1] !== undefined ? decodeURIComponent(m[i + 1]) : null;
+                        });
+                        return { route, params, query: parseQuery(query) };
+                    }
+                    return null;
+                }
+
+                async navigate(url) {
+                    const found = this.match(url);
+                    if (!found) {
+                        this.emit('notfound', url);
+                        return false;
+                    }
+                    const prev = this.current;
+                    this.current = found;
+                    this.emit('change', found, prev);
+                    await found.route.handler(found.params, found.query);
+                    return true;
+                }
+            }
+
+            function parseQuery(query) {
+                return query
+                    .split('&')
+                    .filter(Boolean)
+                    .reduce((acc, pair) => {
+                        const [k, v = ''] = pair.split('=').map(decodeURIComponent);
+                        if (k in acc) {
+                            acc[k] = [].concat(acc[k], v);
+                        } else {
+                            acc[k] = v;
+                        }
+                        return acc;
+                    }, {});
+            }
+
+            const ESCAPES = {
+                '&': '&amp;',
+                '<': '&lt;',
+                '>': '&gt;',
+                '"': '&quot;',
+                "'": '&#39;',
+            };
+
+            function escapeHtml(s) {
+                return String(s).replace(/[&<>"']/g, (c) => ESCAPES[c]);
+            }
+
+            const INLINE = [
+                [/\*\*(.+?)\*\*/g, '<strong>$1</strong>'],
+                [/\*(.+?)\*/g, '<em>$1</em>'],
+                [/~~(.+?)~~/g, '<del>$1</del>'],
+                [/\[([^\]]+)\]\(([^)\s]+)\)/g, '<a href="$2">$1</a>'],
+            ];
+
+            function renderInline(text) {
+                let out = escapeHtml(text);
+                for (let i = 0; i < INLINE.length; i++) {
+                    const [re, replacement] = INLINE[i];
+                    out = out.replace(re, replacement);
+                }
+                return out;
+            }
+
+            function renderMarkdown(src) {
+                const lines = src.split(/\r?\n/);
+                const html = [];
+                let list = null;
+                let code = null;
+                for (let i = 0, len = lines.length; i < len; i++) {
+                    const line = lines[i];
+                    if (code !== null) {
+                        if (/^```/.test(line)) {
+                            html.push(`<pre><code>${escapeHtml(code.join('\n'))}</code></pre>`);
+                            code = null;
+                        } else {
+                            code.push(line);
+                        }
+                        continue;
+                    }
+                    if (/^```/.test(line)) {
+                        code = [];
+                        continue;
+                    }
+                    const heading = /^(#{1,6})\s+(.*)$/.exec(line);
+                    if (heading) {
+                        const level = heading[1].length;
+                        html.push(`<h${level}>${renderInline(heading[2])}</h${level}>`);
+                        continue;
+                    }
+                    const item = /^\s*[-*]\s+(.*)$/.exec(line);
+                    if (item) {
+                        if (list === null) {
+                            list = [];
+                        }
+                        list.push(`<li>${renderInline(item[1])}</li>`);
+                        continue;
+                    } else if (list !== null) {
+                        html.push(`<ul>${list.join('')}</ul>`);
+                        list = null;
+                    }
+                    if (line.trim().length) {
+                        html.push('<p>' + renderInline(line) + '</p>');
+                    }
+                }
+                if (list !== null) {
+                    html.push(`<ul>${list.join('')}</ul>`);
+                }
+                return html.join('\n');
+            }
+
+            function createStore(reducer, initial) {
+                let state = initial;
+                let dispatching = false;
+                const events = new EventEmitter();
+                return {
+                    getState: () => state,
+                    subscribe: (cb) => events.on('change', cb),
+                    dispatch(action) {
+                        if (dispatching) {
+                            throw new Error('reducers may not dispatch actions');
+                        }
+                        if (typeof action !== 'object' || action === null || !('type' in action)) {
+                            throw new TypeError(`invalid action ${JSON.stringify(action)}`);
+                        }
+                        dispatching = true;
+                        try {
+                            const next = reducer(state, action);
+                            const changed = next !== state;
+                            state = next;
+                            if (changed) {
+                                events.emit('change', state, action);
+                            }
+                        } finally {
+                            dispatching = false;
+                        }
+                        return action;
+                    },
+                };
+            }
+
+            const initialTodos = { items: [], filter: 'all', nextId: 1 };
+
+            function todos(state = initialTodos, action) {
+                switch (action.type) {
+                    case 'add':
+                        return {
+                            ...state,
+                            items: state.items.concat({ id: state.nextId, text: action.text, done: false }),
+                            nextId: state.nextId + 1,
+                        };
+                    case 'toggle':
+                        return {
+                            ...state,
+                            items: state.items.map((t) => (t.id === action.id ? { ...t, done: !t.done } : t)),
+                        };
+                    case 'remove':
+                        return { ...state, items: state.items.filter((t) => t.id !== action.id) };
+                    case 'filter':
+                        return { ...state, filter: action.filter };
+                    default:
+                        return state;
+                }
+            }
+
+            function visible({ items, filter }) {
+                if (filter === 'done') {
+                    return items.filter((t) => t.done);
+                }
+                if (filter === 'open') {
+                    return items.filter((t) => !t.done);
+                }
+                return items;
+            }
+
+            function percentDone(items) {
+                if (!items.length) {
+                    return 0;
+                }
+                const done = items.filter((t) => t.done).length;
+                return Math.round((done / items.length) * 100) / 100;
+            }
+
+            function* chunks(list, size) {
+                for (let i = 0; i < list.length; i += size) {
+                    yield list.slice(i, i + size);
+                }
+            }
+
+            async function fetchJson(url, { retries = 3, delay = 100 } = {}) {
+                let attempt = 0;
+                while (true) {
+                    try {
+                        const res = await fetch(url, { headers: { Accept: 'application/json' } });
+                        if (!res.ok) {
+                            throw new Error(`HTTP ${res.status}`);
+                        }
+                        return await res.json();
+                    } catch (e) {
+                        attempt += 1;
+                        if (attempt >= retries) {
+                            throw e;
+                        }
+                        await new Promise((resolve) => setTimeout(resolve, delay * 2 ** attempt));
+                    }
+                }
+            }
+
+            const BYTE_UNITS = ['B', 'KB', 'MB', 'GB', 'TB'];
+
+            function formatBytes(n, digits = 1) {
+                let i = 0;
+                while (n >= 1024 && i < BYTE_UNITS.length - 1) {
+                    n /= 1024;
+                    i++;
+                }
+                return `${n.toFixed(i === 0 ? 0 : digits)} ${BYTE_UNITS[i]}`;
+            }
+
+            const COLOR = /^#?([0-9a-f]{2})([0-9a-f]{2})([0-9a-f]{2})$/i;
+
+            function hexToRgb(hex) {
+                const m = COLOR.exec(hex);
+                return m ? m.slice(1).map((c) => parseInt(c, 16)) : null;
+            }
+
+            function luminance([r, g, b]) {
+                const [lr, lg, lb] = [r, g, b].map((v) => {
+                    v /= 255;
+                    return v <= 0.03928 ? v / 12.92 : ((v + 0.055) / 1.055) ** 2.4;
+                });
+                return 0.2126 * lr + 0.7152 * lg + 0.0722 * lb;
+            }
+
+            function contrast(a, b) {
+                const la = luminance(hexToRgb(a)) + 0.05;
+                const lb = luminance(hexToRgb(b)) + 0.05;
+                return la > lb ? la / lb : lb / la;
+            }
+
+            const app = {
+                EventEmitter,
+                Router,
+                createStore,
+                todos,
+                visible,
+                percentDone,
+                renderMarkdown,
+                escapeHtml,
+                chunks,
+                fetchJson,
+                formatBytes,
+                contrast,
+            };
+
+            if (typeof module !== 'undefined' && module.exports) {
+                module.exports = app;
+            } else {
+                global.app = app;
+            }
+        })(typeof window !== 'undefined' ? window : this);
+    },
+    19: function (module, exports, require) {
+        'use strict';
+        // A tiny debounce helper with a couple of
+        // formatting utilities
+        const DEFAULT_WAIT = 250;
+
+        function debounce(fn, wait = DEFAULT_WAIT) {
+            let timer = null;
+            return function (...args) {
+                if (timer !== null) {
+                    clearTimeout(timer);
+                }
+                timer = setTimeout(() => {
+                    timer = null;
+                    fn.apply(this, args);
+                }, wait);
+            };
+        }
+
+        function pad(n, width) {
+            let s = String(n);
+            while (s.length < width) {
+                s = '0' + s;
+            }
+            return s;
+        }
+
+        /**
+         * Format a date as YYYY-MM-DD HH:MM
+         */
+        function formatDate(d) {
+            const date = [d.getFullYear(), pad(d.getMonth() + 1, 2), pad(d.getDate(), 2)].join('-');
+            const time = `${pad(d.getHours(), 2)}:${pad(d.getMinutes(), 2)}`;
+            return `${date} ${time}`;
+        }
+
+        function slugify(text) {
+            return text
+                .toLowerCase()
+                .replace(/[^a-z0-9]+/g, '-')
+                .replace(/^-+|-+$/g, '');
+        }
+
+        const ratio = 16 / 9, half = ratio / 2;
+
+        module.exports = { debounce, pad, formatDate, slugify, ratio, half };
+    }
+});
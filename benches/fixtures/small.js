@@ -0,0 +1,45 @@
+'use strict';
+// A tiny debounce helper with a couple of
+// formatting utilities
+const DEFAULT_WAIT = 250;
+
+function debounce(fn, wait = DEFAULT_WAIT) {
+    let timer = null;
+    return function (...args) {
+        if (timer !== null) {
+            clearTimeout(timer);
+        }
+        timer = setTimeout(() => {
+            timer = null;
+            fn.apply(this, args);
+        }, wait);
+    };
+}
+
+function pad(n, width) {
+    let s = String(n);
+    while (s.length < width) {
+        s = '0' + s;
+    }
+    return s;
+}
+
+/**
+ * Format a date as YYYY-MM-DD HH:MM
+ */
+function formatDate(d) {
+    const date = [d.getFullYear(), pad(d.getMonth() + 1, 2), pad(d.getDate(), 2)].join('-');
+    const time = `${pad(d.getHours(), 2)}:${pad(d.getMinutes(), 2)}`;
+    return `${date} ${time}`;
+}
+
+function slugify(text) {
+    return text
+        .toLowerCase()
+        .replace(/[^a-z0-9]+/g, '-')
+        .replace(/^-+|-+$/g, '');
+}
+
+const ratio = 16 / 9, half = ratio / 2;
+
+module.exports = { debounce, pad, formatDate, slugify, ratio, half };
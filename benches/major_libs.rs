@@ -112,12 +112,12 @@ impl Lib {
     }
 
     pub fn min_path(&self) -> String {
-        match self {
-            &Lib::Jquery => "node_modules/jquery/dist/jquery.min.js".into(),
-            &Lib::Angular => "node_modules/angular/angular.min.js".into(),
-            &Lib::React => "node_modules/react/umd/react.production.min.js".into(),
-            &Lib::ReactDom => "node_modules/react-dom/umd/react-dom.production.min.js".into(),
-            &Lib::Vue => "node_modules/vue/dist/vue.min.js".into(),
+        match *self {
+            Lib::Jquery => "node_modules/jquery/dist/jquery.min.js".into(),
+            Lib::Angular => "node_modules/angular/angular.min.js".into(),
+            Lib::React => "node_modules/react/umd/react.production.min.js".into(),
+            Lib::ReactDom => "node_modules/react-dom/umd/react-dom.production.min.js".into(),
+            Lib::Vue => "node_modules/vue/dist/vue.min.js".into(),
             _ => String::new(),
         }
     }
@@ -155,7 +155,7 @@ fn run_bench_(c: &mut Criterion, js: &str, name: &str) {
     group.throughput(criterion::Throughput::Bytes(js.len() as u64));
     group.bench_function(name, |b| {
         b.iter(|| {
-            for i in Scanner::new(&js) {
+            for i in Scanner::new(js) {
                 black_box(i.unwrap());
             }
         })
@@ -170,15 +170,15 @@ static NULL: &[&str] = &["null"];
 
 lazy_static! {
     static ref TOKENS: Vec<&'static str> = COMMENTS
-        .into_iter()
-        .chain(KEYWORDS.into_iter())
-        .chain(NUMBERS.into_iter())
-        .chain(PUNCTS.into_iter())
-        .chain(IDENTS.into_iter())
-        .chain(BOOLS.into_iter())
-        .chain(NULL.into_iter())
-        .chain(TEMPLATE_STARTS.into_iter())
-        .map(|s| *s)
+        .iter()
+        .chain(KEYWORDS.iter())
+        .chain(NUMBERS.iter())
+        .chain(PUNCTS.iter())
+        .chain(IDENTS.iter())
+        .chain(BOOLS.iter())
+        .chain(NULL.iter())
+        .chain(TEMPLATE_STARTS.iter())
+        .copied()
         .collect();
     static ref JS: String = TOKENS.join("\n");
 }
@@ -247,7 +247,7 @@ fn templates(c: &mut Criterion) {
     c.bench_function("TEMPLATE_CONTINUATIONS", |b| {
         b.iter(|| {
             for s in TEMPLATE_CONTINUATIONS {
-                let mut t = Tokenizer::new(&s);
+                let mut t = Tokenizer::new(s);
                 let _ = t.next(true).unwrap();
                 black_box(t.next(true).unwrap());
             }
@@ -54,6 +54,8 @@ fn token_type_str(tok: &Token<&str>) -> &'static str {
         Token::Template(_) => "template",
         Token::Comment(_) => "comment",
         Token::EoF => "eof",
+        Token::Invalid(_) => "invalid",
+        Token::Jsx(_) => "jsx",
     }
 }
 
@@ -70,5 +72,6 @@ fn get_initial_counts() -> HashMap<&'static str, usize> {
     counts.insert("comment", 0);
     counts.insert("null", 0);
     counts.insert("eof", 0);
+    counts.insert("invalid", 0);
     counts
 }
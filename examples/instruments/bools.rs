@@ -1,6 +1,7 @@
 // This example exists to allow for profiling
 // applications to provide details about
 // the criterion benchmarks
+#![allow(clippy::forget_non_drop)]
 use ress::Tokenizer;
 
 fn main() {
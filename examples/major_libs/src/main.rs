@@ -12,6 +12,7 @@ use std::{
     time::{Duration, SystemTime},
 };
 
+#[derive(Default)]
 struct Args {
     pub angular: bool,
     pub jquery: bool,
@@ -22,20 +23,6 @@ struct Args {
     pub dexie: bool,
 }
 
-impl ::std::default::Default for Args {
-    fn default() -> Args {
-        Args {
-            angular: false,
-            jquery: false,
-            react: false,
-            react_dom: false,
-            vue: false,
-            moment: false,
-            dexie: false,
-        }
-    }
-}
-
 impl Args {
     fn pristine(&self) -> bool {
         !self.angular
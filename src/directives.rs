@@ -0,0 +1,118 @@
+//! Finding the directive prologues (`'use strict'`)
+//! of a program or function body
+use std::collections::VecDeque;
+
+use crate::{
+    tokens::{Punct, Token},
+    Item, Res, Scanner,
+};
+
+/// An iterator over the items of a `Scanner` along with
+/// a flag for each one that is `true` when it is a
+/// directive, see `Scanner::with_directives`
+pub struct WithDirectives<'a> {
+    scanner: Scanner<'a>,
+    /// Items already pulled from the scanner to see
+    /// what follows a string
+    pending: VecDeque<Res<Item<&'a str>>>,
+    in_prologue: bool,
+    last_was_directive: bool,
+}
+
+impl<'a> WithDirectives<'a> {
+    pub(crate) fn new(scanner: Scanner<'a>) -> Self {
+        Self {
+            scanner,
+            pending: VecDeque::new(),
+            in_prologue: true,
+            last_was_directive: false,
+        }
+    }
+    /// Check if the statement ends after `item`, the next
+    /// significant token is a `;` or `}`, is on a later line
+    /// or there is nothing left
+    fn ends_statement(&mut self, item: &Item<&'a str>) -> bool {
+        let next = loop {
+            if let Some(next) = self.pending.iter().find(|next| match next {
+                Ok(next) => !next.token.is_comment(),
+                Err(_) => true,
+            }) {
+                break next;
+            }
+            match self.scanner.next() {
+                Some(next) => self.pending.push_back(next),
+                None => return true,
+            }
+        };
+        match next {
+            Ok(next) => {
+                next.token
+                    .matches_any_punct(&[Punct::SemiColon, Punct::CloseBrace])
+                    || next.token.is_eof()
+                    || next.location.start.line > item.location.end.line
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+impl<'a> Iterator for WithDirectives<'a> {
+    type Item = Res<(Item<&'a str>, bool)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = match self.pending.pop_front().or_else(|| self.scanner.next())? {
+            Ok(item) => item,
+            Err(e) => return Some(Err(e)),
+        };
+        if item.token.is_comment() {
+            return Some(Ok((item, false)));
+        }
+        let is_directive = self.in_prologue && item.is_string() && self.ends_statement(&item);
+        self.in_prologue = match &item.token {
+            Token::Punct(Punct::OpenBrace) => true,
+            Token::Punct(Punct::SemiColon) => self.last_was_directive,
+            _ => is_directive,
+        };
+        self.last_was_directive = is_directive;
+        Some(Ok((item, is_directive)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn directives(js: &str) -> Vec<&str> {
+        Scanner::new(js)
+            .with_directives()
+            .map(Result::unwrap)
+            .filter(|(_, is_directive)| *is_directive)
+            .map(|(item, _)| &js[item.span.range()])
+            .collect()
+    }
+
+    #[test]
+    fn program_prologue() {
+        assert_eq!(directives("'use strict';\nlet a;"), vec!["'use strict'"]);
+        assert_eq!(directives("\"use strict\""), vec!["\"use strict\""]);
+        assert_eq!(
+            directives("#!/usr/bin/env node\n/* c */ 'a'; 'b'\n'c'"),
+            vec!["'a'", "'b'", "'c'"]
+        );
+        assert_eq!(directives("x;\n'use strict';"), Vec::<&str>::new());
+        assert_eq!(directives("'a' + b;"), Vec::<&str>::new());
+        assert_eq!(directives("'a'; x; 'b';"), vec!["'a'"]);
+    }
+
+    #[test]
+    fn function_prologue() {
+        assert_eq!(
+            directives("function f() {\n  'use asm'\n  return 'not';\n}"),
+            vec!["'use asm'"]
+        );
+        assert_eq!(directives("let o = { 'a': 1 };"), Vec::<&str>::new());
+        assert_eq!(
+            directives("x = () => { 'use strict' }"),
+            vec!["'use strict'"]
+        );
+    }
+}
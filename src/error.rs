@@ -1,5 +1,38 @@
+/// The kind of problem an `Error` describes,
+/// the position is always the `idx`, `line` and
+/// `column` of the error itself
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ErrorKind {
+    /// A character that cannot start any token
+    UnexpectedChar(char),
+    /// A string literal without its closing quote
+    UnterminatedString,
+    /// A template literal without its closing back tick
+    UnterminatedTemplate,
+    /// A multi-line or HTML comment without its
+    /// closing `*/` or `-->`
+    UnterminatedComment,
+    /// A regular expression literal without
+    /// its closing `/` on the same line
+    UnterminatedRegex,
+    /// A malformed escape sequence in a string,
+    /// template or identifier
+    InvalidEscape,
+    /// A malformed number literal like `0x`, `1e`
+    /// or `3in`
+    InvalidNumber,
+    /// One of the `ScannerLimits` was reached
+    LimitExceeded,
+    /// A token that needs a newer `EcmaVersion`
+    /// than the one the `Scanner` was created with
+    UnsupportedFeature,
+    /// Anything else, `msg` has the details
+    Other,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Error {
+    pub kind: ErrorKind,
     pub line: usize,
     pub column: usize,
     pub msg: String,
@@ -16,6 +49,7 @@ impl ::std::fmt::Display for Error {
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct RawError {
+    pub kind: ErrorKind,
     pub idx: usize,
     pub msg: String,
 }
@@ -27,3 +61,16 @@ impl ::std::fmt::Display for RawError {
         write!(f, "{} at {}", self.msg, self.idx)
     }
 }
+
+/// The error from parsing a `Punct` or `Keyword`
+/// out of text that isn't one
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnknownToken(pub String);
+
+impl ::std::error::Error for UnknownToken {}
+
+impl ::std::fmt::Display for UnknownToken {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{:?} is not a known token", self.0)
+    }
+}
@@ -0,0 +1,302 @@
+//! Re-tokenizing a source text after an edit
+//! without scanning the whole file again
+use std::ops::Range;
+
+use crate::{
+    look_behind::MetaToken,
+    tokens::{Keyword, Punct, Token},
+    Error, Item, Position, Res, Scanner, SourceLocation, Span,
+};
+
+/// Re-tokenize `text` after an edit, reusing as much
+/// of `old` as possible.
+///
+/// `old` should be the complete output of a `Scanner`
+/// (including the `EoF` item) for the text before the edit
+/// and `edit` is the byte range of that original text that
+/// was replaced. Any tokens that are entirely before the edit
+/// are kept as is, the tokens after the edit are kept with their
+/// spans shifted by the change in length and only the region
+/// between is scanned again.
+///
+/// Since a `/` or `}` can mean very different things depending
+/// on what came before it, scanning only resumes/resyncs on a
+/// line that starts directly after a top level `;`. When no
+/// such point exists the whole text is scanned.
+///
+/// This scans with the default `Scanner` options, use `relex_with`
+/// if `old` was produced by a `Scanner` with any options set.
+pub fn relex<'a>(
+    old: &[Item<&'a str>],
+    text: &'a str,
+    edit: Range<usize>,
+) -> Res<Vec<Item<&'a str>>> {
+    relex_with(old, text, edit, |_| ())
+}
+
+/// The same as `relex` but each `Scanner` that is created
+/// is passed to `configure` first, so it can be given the
+/// same options as the one that produced `old`.
+///
+/// Only the re-scanned text counts towards any `ScannerLimits`
+/// since the scan doesn't start at the top of the text.
+///
+/// ```
+/// # use ress::{relex_with, Scanner, SourceType};
+/// let configure = |s: &mut Scanner| {
+///     s.set_source_type(SourceType::Module);
+/// };
+/// let before = "a;\nb <!-- c;\n";
+/// let mut scanner = Scanner::new(before);
+/// configure(&mut scanner);
+/// let old: Vec<_> = scanner.map(Result::unwrap).collect();
+/// let after = "a;\nbb <!-- c;\n";
+/// let relexed = relex_with(&old, after, 3..4, configure).unwrap();
+/// assert_eq!(relexed[2].token.to_string(), "bb");
+/// assert_eq!(relexed[3].token.to_string(), "<");
+/// ```
+pub fn relex_with<'a, F>(
+    old: &[Item<&'a str>],
+    text: &'a str,
+    edit: Range<usize>,
+    configure: F,
+) -> Res<Vec<Item<&'a str>>>
+where
+    F: Fn(&mut Scanner<'a>),
+{
+    let old_len = match old.last() {
+        Some(item) if item.is_eof() => item.span.end,
+        _ => return full_scan(text, &configure),
+    };
+    if edit.start > edit.end || edit.end > old_len || old_len - edit.len() > text.len() {
+        return full_scan(text, &configure);
+    }
+    let delta = text.len() as isize - old_len as isize;
+    let new_edit_end = (edit.end as isize + delta) as usize;
+
+    let mut tracker = Tracker::default();
+    let old_points: Vec<bool> = old.iter().map(|item| tracker.step(item)).collect();
+    let start = old_points
+        .iter()
+        .enumerate()
+        .rev()
+        .find(|(i, point)| **point && old[*i].span.start <= edit.start)
+        .map(|(i, _)| i);
+    let (start, base) = match start {
+        Some(i) => {
+            let prefix = &text[..old[i].span.start];
+            let base = prefix
+                .char_indices()
+                .rev()
+                .find(|(_, c)| crate::is_line_term(*c))
+                .map(|(idx, c)| idx + c.len_utf8())
+                .unwrap_or(0);
+            (i, base)
+        }
+        None => (0, 0),
+    };
+    let mut ret = old[..start].to_vec();
+    let line_offset = old
+        .get(start)
+        .map(|item| item.location.start.line - 1)
+        .unwrap_or(0);
+    let mut scanner = Scanner::new(&text[base..]);
+    configure(&mut scanner);
+    if start > 0 {
+        // the scanner would have just seen the `;`
+        scanner.last_three.push(MetaToken::Punct(Punct::SemiColon));
+    }
+    let mut tracker = Tracker::default();
+    let mut prev_start = 0;
+    for item in scanner {
+        let item = item.map_err(|e| Error {
+            line: e.line + line_offset,
+            idx: e.idx + base,
+            ..e
+        })?;
+        // scanning starts at the beginning of a line
+        // so the columns are already correct
+        let item = shift(&item, base as isize, line_offset as isize);
+        let is_point = tracker.step(&item);
+        if is_point && prev_start >= new_edit_end {
+            let target = item.span.start as isize - delta;
+            if let Ok(j) = old.binary_search_by_key(&target, |o| o.span.start as isize) {
+                if old_points[j] {
+                    let lines =
+                        item.location.start.line as isize - old[j].location.start.line as isize;
+                    ret.extend(old[j..].iter().map(|o| shift(o, delta, lines)));
+                    return Ok(ret);
+                }
+            }
+        }
+        prev_start = item.span.start;
+        ret.push(item);
+    }
+    Ok(ret)
+}
+
+fn full_scan<'a>(text: &'a str, configure: impl Fn(&mut Scanner<'a>)) -> Res<Vec<Item<&'a str>>> {
+    let mut scanner = Scanner::new(text);
+    configure(&mut scanner);
+    scanner.collect()
+}
+
+/// Move an item forward or back by `bytes` and `lines`
+fn shift<'a>(item: &Item<&'a str>, bytes: isize, lines: isize) -> Item<&'a str> {
+    let position = |p: Position| Position::new((p.line as isize + lines) as usize, p.column);
+    Item::new(
+        item.token.clone(),
+        Span::new(
+            (item.span.start as isize + bytes) as usize,
+            (item.span.end as isize + bytes) as usize,
+        ),
+        SourceLocation::new(position(item.location.start), position(item.location.end)),
+    )
+}
+
+#[derive(Default)]
+/// Tracks the nesting depth of a token stream
+/// to find the places that a fresh `Scanner` would
+/// produce the same tokens as one that started at
+/// the top of the file
+struct Tracker {
+    depth: isize,
+    /// The line of the last token if it was
+    /// a `;` at the top level
+    after_semi: Option<usize>,
+    last_was_function: bool,
+}
+
+impl Tracker {
+    /// Check if `item` is a valid resync point and
+    /// then update the state to include it
+    fn step(&mut self, item: &Item<&str>) -> bool {
+        let is_point = matches!(self.after_semi, Some(line) if item.location.start.line > line);
+        self.after_semi = if item.token.matches_punct(Punct::SemiColon)
+            && self.depth == 0
+            && !self.last_was_function
+        {
+            Some(item.location.end.line)
+        } else {
+            None
+        };
+        self.depth += match &item.token {
+            Token::Punct(Punct::OpenParen)
+            | Token::Punct(Punct::OpenBrace)
+            | Token::Punct(Punct::OpenBracket) => 1,
+            Token::Punct(Punct::CloseParen)
+            | Token::Punct(Punct::CloseBrace)
+            | Token::Punct(Punct::CloseBracket) => -1,
+            Token::Template(t) if t.is_head() => 1,
+            Token::Template(t) if t.is_tail() => -1,
+            _ => 0,
+        };
+        if !item.token.is_comment() {
+            self.last_was_function = item.token.matches_keyword(Keyword::Function(()));
+        }
+        is_point
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static JS: &str = "let a = 'string';
+let re = /a+b/g;
+function thing(x) {
+    return x / 2;
+}
+let b = `t${a}l`;
+let c = 1;
+";
+
+    fn check_edit(before: &str, edit: Range<usize>, replacement: &str) {
+        let old: Vec<_> = Scanner::new(before).map(Result::unwrap).collect();
+        let mut after = before.to_string();
+        after.replace_range(edit.clone(), replacement);
+        let expected: Vec<_> = Scanner::new(&after).map(Result::unwrap).collect();
+        let relexed = relex(&old, &after, edit).unwrap();
+        assert_eq!(relexed, expected, "{}", after);
+    }
+
+    #[test]
+    fn edit_string() {
+        let start = JS.find("string").unwrap();
+        check_edit(JS, start..start + 6, "a\\\nlonger string");
+    }
+
+    #[test]
+    fn edit_regex() {
+        let start = JS.find("a+b").unwrap();
+        check_edit(JS, start..start + 3, "[/]");
+        check_edit(JS, start..start + 3, "\\d");
+    }
+
+    #[test]
+    fn edit_code() {
+        let start = JS.find("x / 2").unwrap();
+        check_edit(JS, start..start + 5, "(x\n\n) / 3");
+        let start = JS.find("let c").unwrap();
+        check_edit(JS, start..start, "let d = {};\n");
+        check_edit(JS, 0..0, "\n\n");
+        check_edit(JS, JS.len()..JS.len(), "x;");
+    }
+
+    #[test]
+    fn edit_indentation() {
+        let js = "a;\nb;\nc;\n";
+        check_edit(js, 3..3, "  ");
+        let start = JS.find("let c").unwrap();
+        check_edit(JS, start..start, "    ");
+        check_edit(JS, start + 4..start + 5, "cc");
+    }
+
+    #[test]
+    fn edit_unbalances() {
+        let start = JS.find("x) {").unwrap();
+        check_edit(JS, start..start + 4, "x) { {");
+    }
+
+    #[test]
+    fn scanner_options_are_kept() {
+        let options = |s: &mut Scanner| {
+            s.allow_html_comments(false).recover_errors(true);
+        };
+        let scan = |js| {
+            let mut s = Scanner::new(js);
+            options(&mut s);
+            s.map(Result::unwrap).collect::<Vec<_>>()
+        };
+        let before = "a;\nb <!-- c;\nd ¬ e;\n";
+        let old = scan(before);
+        let start = before.find('b').unwrap();
+        let after = before.replacen('b', "bb", 1);
+        let relexed = relex_with(&old, &after, start..start + 1, options).unwrap();
+        assert_eq!(relexed, scan(&after));
+        assert!(relexed.iter().any(|item| item.token.is_invalid()));
+        assert!(relexed.iter().all(|item| !item.token.is_comment()));
+        // with the default options the `¬` is an error
+        let relexed = relex(&old, &after, start..start + 1);
+        assert!(relexed.is_err());
+    }
+
+    #[test]
+    fn spans_outside_edit_are_kept() {
+        let mut old: Vec<_> = Scanner::new(JS).map(Result::unwrap).collect();
+        // tag the first and last real tokens so we can
+        // tell they were reused and not scanned again
+        let last = old.len() - 2;
+        old[0].token = Token::Ident("first".into());
+        old[last].token = Token::Ident("last".into());
+        let start = JS.find("x / 2").unwrap();
+        let mut after = JS.to_string();
+        after.replace_range(start..start + 1, "xyz");
+        let relexed = relex(&old, &after, start..start + 1).unwrap();
+        assert_eq!(relexed[0], old[0]);
+        let shifted = &relexed[relexed.len() - 2];
+        assert_eq!(shifted.token, Token::Ident("last".into()));
+        assert_eq!(shifted.span.start, old[last].span.start + 2);
+        assert_eq!(shifted.location, old[last].location);
+    }
+}
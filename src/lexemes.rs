@@ -0,0 +1,32 @@
+//! Pairing every item with the exact text it was
+//! scanned from
+use crate::{Item, Res, Scanner};
+
+/// An iterator over the items of a `Scanner` along with
+/// the slice of the source each one covers, see
+/// `Scanner::with_lexemes`
+pub struct WithLexemes<'a> {
+    scanner: Scanner<'a>,
+    text: &'a str,
+}
+
+impl<'a> WithLexemes<'a> {
+    pub(crate) fn new(scanner: Scanner<'a>) -> Self {
+        Self {
+            text: scanner.source(),
+            scanner,
+        }
+    }
+}
+
+impl<'a> Iterator for WithLexemes<'a> {
+    type Item = Res<(Item<&'a str>, &'a str)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = match self.scanner.next()? {
+            Ok(item) => item,
+            Err(e) => return Some(Err(e)),
+        };
+        let raw = &self.text[item.span.range()];
+        Some(Ok((item, raw)))
+    }
+}
@@ -21,26 +21,42 @@
 #[macro_use]
 extern crate log;
 
+mod directives;
 pub mod error;
+mod incremental;
+mod lexemes;
+mod lossless;
 mod manual_scanner;
+mod positions;
+mod stream;
 mod tokenizer;
 pub mod tokens;
 pub use crate::tokenizer::{JSBuffer, Tokenizer};
+pub use directives::WithDirectives;
+pub use incremental::{relex, relex_with};
+pub use lexemes::WithLexemes;
+pub use lossless::Lossless;
+pub use positions::WithPositions;
+pub use stream::TokenStream;
 
 pub mod prelude {
     pub use super::{
-        tokenize, tokens::prelude::*, Item, OpenCurlyKind, Position, Scanner, ScannerState,
-        SourceLocation,
+        tokenize, tokens::prelude::*, EcmaVersion, Item, OpenCurlyKind, Position, Scanner,
+        ScannerLimits, ScannerState, SourceLocation, SourceType, TokenStream,
     };
 }
 use crate::{tokenizer::RawKeyword, tokens::prelude::*};
-use error::{Error, RawError};
+use error::{Error, ErrorKind, RawError};
 pub use manual_scanner::{ManualScanner, ScannerState as ManualState};
 
 type Res<T> = Result<T, Error>;
 mod look_behind;
 
 use look_behind::{Brace, LookBehind, MetaToken, Paren};
+use std::ops::{ControlFlow, Range};
+
+/// The number of tokens `Scanner::recent_tokens` can look back
+const RECENT_TOKENS: usize = 16;
 
 /// a convince function for collecting a scanner into
 /// a `Vec<Token>`
@@ -53,6 +69,188 @@ pub fn tokenize(text: &str) -> Res<Vec<Token<&str>>> {
         .collect()
 }
 
+/// a convince function for collecting a scanner into
+/// a `Vec<Token>` that never fails, scanning stops at
+/// the first error and the tokens before it are returned.
+/// Without an error the last token will be `EoF`, use
+/// `tokenize` to find out what went wrong
+pub fn tokenize_lenient(text: &str) -> Vec<Token<&str>> {
+    Scanner::new(text)
+        .map_while(|i| i.ok().map(|i| i.token))
+        .collect()
+}
+
+/// a convince function for collecting a scanner into
+/// a `Vec<Item>`, keeping the span and location of
+/// each token
+pub fn tokenize_with_spans(text: &str) -> Res<Vec<Item<&str>>> {
+    Scanner::new(text).collect()
+}
+
+/// Run `tokenize_with_spans` over each of the `sources`,
+/// the results are in the same order as the input.
+///
+/// With the `parallel` feature enabled the sources are
+/// split across rayon's thread pool, otherwise they are
+/// tokenized one after another.
+pub fn tokenize_many<'a>(sources: &[&'a str]) -> Vec<Res<Vec<Item<&'a str>>>> {
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        sources.par_iter().map(|s| tokenize_with_spans(s)).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        sources.iter().map(|s| tokenize_with_spans(s)).collect()
+    }
+}
+
+/// Like `tokenize_with_spans` but also checks that the
+/// whole text was consumed, the last item will always be
+/// an `EoF` that ends at the end of `text`
+pub fn tokenize_complete(text: &str) -> Res<Vec<Item<&str>>> {
+    let mut scanner = Scanner::new(text);
+    let mut ret = Vec::new();
+    for item in scanner.by_ref() {
+        let item = item?;
+        if item.is_eof() {
+            if item.span.end != text.len() {
+                break;
+            }
+            ret.push(item);
+            return Ok(ret);
+        }
+        ret.push(item);
+    }
+    let idx = ret.last().map(|item| item.span.end).unwrap_or(0);
+    scanner.error(RawError {
+        kind: ErrorKind::Other,
+        idx,
+        msg: "tokenizing stopped before the end of the text".to_string(),
+    })
+}
+
+/// Scan `text`, passing each item to `f` as it is produced
+/// instead of collecting them. Scanning stops early when `f`
+/// returns `ControlFlow::Break` or at the first error.
+///
+/// ```
+/// # use std::ops::ControlFlow;
+/// let mut idents = 0;
+/// ress::for_each_token("a + b + 'c", |item| {
+///     if item.token.is_ident() {
+///         idents += 1;
+///     }
+///     if idents == 2 {
+///         ControlFlow::Break(())
+///     } else {
+///         ControlFlow::Continue(())
+///     }
+/// })
+/// .unwrap();
+/// assert_eq!(idents, 2);
+/// ```
+pub fn for_each_token<'a, F>(text: &'a str, mut f: F) -> Res<()>
+where
+    F: FnMut(Item<&'a str>) -> ControlFlow<()>,
+{
+    for item in Scanner::new(text) {
+        if f(item?).is_break() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Find the item covering the byte at `offset` in a list of
+/// items in source order, like the output of
+/// `tokenize_with_spans`.
+///
+/// An offset on the boundary between two tokens belongs to
+/// the one that starts there. An offset in the white space
+/// between tokens, or past the end, is `None`, as is the
+/// `EoF` item since it covers no bytes.
+///
+/// ```
+/// # use ress::{token_at, tokenize_with_spans};
+/// let js = "let a = 1;";
+/// let items = tokenize_with_spans(js).unwrap();
+/// assert_eq!(token_at(&items, 1).unwrap().token.to_string(), "let");
+/// assert_eq!(token_at(&items, 9).unwrap().token.to_string(), ";");
+/// assert!(token_at(&items, 3).is_none());
+/// ```
+pub fn token_at<T>(items: &[Item<T>], offset: usize) -> Option<&Item<T>> {
+    let idx = items.partition_point(|item| item.span.end <= offset);
+    items.get(idx).filter(|item| item.span.contains(offset))
+}
+
+/// Check if a semicolon would be inserted between `prev` and
+/// `next` because of one of the restricted productions, `source`
+/// should be the text both items were scanned from.
+///
+/// That is the case when a line terminator, which may be part of
+/// a comment, separates them and either `prev` is `return`,
+/// `throw`, `break`, `continue` or `yield` or `next` is a `++`
+/// or `--` following something that could be an operand. The
+/// rule that inserts a semicolon before a token the grammar
+/// doesn't allow needs a parser, so it isn't covered here.
+///
+/// ```
+/// # use ress::{needs_asi_between, tokenize_with_spans};
+/// let js = "return\n5";
+/// let items = tokenize_with_spans(js).unwrap();
+/// assert!(needs_asi_between(&items[0], &items[1], js));
+/// let js = "return 5";
+/// let items = tokenize_with_spans(js).unwrap();
+/// assert!(!needs_asi_between(&items[0], &items[1], js));
+/// ```
+pub fn needs_asi_between<T>(prev: &Item<T>, next: &Item<T>, source: &str) -> bool
+where
+    T: AsRef<str>,
+{
+    let between = source
+        .get(prev.span.end..next.span.start)
+        .unwrap_or_default();
+    if !between.contains(is_line_term) {
+        return false;
+    }
+    let restricted = match &prev.token {
+        Token::Keyword(k) => matches!(
+            k,
+            Keyword::Return(_)
+                | Keyword::Throw(_)
+                | Keyword::Break(_)
+                | Keyword::Continue(_)
+                | Keyword::Yield(_)
+        ),
+        _ => false,
+    };
+    restricted
+        || (next.token.to_punct().is_some_and(Punct::is_update_operator)
+            && ends_operand(&prev.token))
+}
+
+/// Check if a token can be the last token of an
+/// operand, meaning a `++` or `--` after it would
+/// be a postfix operator if they were on the same line
+fn ends_operand<T>(token: &Token<T>) -> bool {
+    match token {
+        Token::Ident(_)
+        | Token::Number(_)
+        | Token::String(_)
+        | Token::Boolean(_)
+        | Token::Null
+        | Token::RegEx(_) => true,
+        Token::Template(t) => t.is_tail() || t.is_no_sub(),
+        Token::Keyword(k) => matches!(k, Keyword::This(_) | Keyword::Super(_)),
+        Token::Punct(p) => matches!(
+            p,
+            Punct::CloseParen | Punct::CloseBracket | Punct::CloseBrace
+        ),
+        _ => false,
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 /// The start and end position of a token
 /// including the line/column number
@@ -112,17 +310,41 @@ pub struct Span {
     pub end: usize,
 }
 
-#[allow(clippy::len_without_is_empty)]
 impl Span {
     /// Create a new Span from its parts
     #[inline]
     pub const fn new(start: usize, end: usize) -> Self {
         Self { start, end }
     }
+    /// The number of bytes this span covers
     #[inline]
     pub const fn len(self) -> usize {
         self.end - self.start
     }
+    /// Check if this span covers no bytes, like
+    /// the span of the `EoF` token
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.start == self.end
+    }
+    /// The byte range of this span, for slicing
+    /// the original text
+    #[inline]
+    pub const fn range(self) -> Range<usize> {
+        self.start..self.end
+    }
+    /// Check if the byte at `offset` is inside of this
+    /// span, an empty span contains nothing
+    #[inline]
+    pub const fn contains(self, offset: usize) -> bool {
+        self.start <= offset && offset < self.end
+    }
+    /// Check if this span and `other` share at least
+    /// one byte, an empty span overlaps nothing
+    #[inline]
+    pub const fn overlaps(self, other: &Span) -> bool {
+        !self.is_empty() && !other.is_empty() && self.start < other.end && other.start < self.end
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -159,6 +381,23 @@ impl<T> Item<T> {
             ),
         }
     }
+    /// The `TokenKind` of this item's token, paired
+    /// with the span this is enough to tell what part
+    /// of the text is code and what is a comment
+    #[inline]
+    pub fn kind(&self) -> TokenKind {
+        self.token.kind()
+    }
+    /// The number of bytes this item's token covers
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.span.len()
+    }
+    /// Check if this item's token covers no bytes
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.span.is_empty()
+    }
     pub fn is_string(&self) -> bool {
         matches!(self.token, Token::String(_))
     }
@@ -172,6 +411,39 @@ impl<T> Item<T> {
     }
 }
 
+impl<T> Item<T>
+where
+    T: AsRef<str>,
+{
+    /// If this is a comment, get the span of just its
+    /// content, excluding the `//`, `/* */`, `<!-- -->`
+    /// or `#!` delimiters. For a `-->` comment without an
+    /// opening `<!--` this is the empty span at its start
+    pub fn content_span(&self) -> Option<Span> {
+        let comment = match &self.token {
+            Token::Comment(comment) => comment,
+            _ => return None,
+        };
+        let content = comment.content.as_ref().len();
+        let open = match comment.kind {
+            tokens::CommentKind::Html => {
+                let tail = comment
+                    .tail_content
+                    .as_ref()
+                    .map_or(0, |t| t.as_ref().len());
+                if self.span.end - self.span.start == "<!---->".len() + content + tail {
+                    "<!--".len()
+                } else {
+                    0
+                }
+            }
+            _ => 2,
+        };
+        let start = self.span.start + open;
+        Some(Span::new(start, start + content))
+    }
+}
+
 /// The primary interface of this crate used
 /// to tokenize any JS text into a stream of
 /// `Item`s.
@@ -180,8 +452,26 @@ pub struct Scanner<'a> {
     original: &'a str,
     errored: bool,
     last_three: LookBehind,
+    /// The latest tokens that weren't comments, oldest
+    /// first, there can be up to twice `RECENT_TOKENS`
+    recent: Vec<Token<&'a str>>,
     brace_stack: Vec<Brace>,
     paren_stack: Vec<Paren>,
+    on_line: Option<Box<dyn FnMut(usize) + 'a>>,
+    last_reported_line: usize,
+    limits: ScannerLimits,
+    token_count: usize,
+    /// `None` unless a source type was picked, then
+    /// `await` is always a keyword
+    source_type: Option<SourceType>,
+    ecma_version: EcmaVersion,
+    recover: bool,
+    accessor_keywords: bool,
+    strict: bool,
+    jsx: bool,
+    /// If an `async` has been seen at the top level
+    /// since the last top level statement ended
+    async_in_statement: bool,
 }
 
 impl<'a> Scanner<'a> {
@@ -193,10 +483,125 @@ impl<'a> Scanner<'a> {
             original: text,
             errored: false,
             last_three: LookBehind::new(),
+            recent: Vec::new(),
             paren_stack: Vec::new(),
             brace_stack: Vec::new(),
+            on_line: None,
+            last_reported_line: 1,
+            limits: ScannerLimits::default(),
+            token_count: 0,
+            source_type: None,
+            ecma_version: EcmaVersion::default(),
+            recover: false,
+            accessor_keywords: false,
+            strict: false,
+            jsx: false,
+            async_in_statement: false,
         }
     }
+    /// Create a new `Scanner` that will stop with a
+    /// `LimitExceeded` error as soon as the text goes
+    /// past any of the `limits`
+    pub fn with_limits(text: &'a str, limits: ScannerLimits) -> Self {
+        let mut ret = Self::new(text);
+        ret.set_limits(limits);
+        ret
+    }
+    /// Create a new `Scanner` that picks up where the
+    /// one that produced `state` left off.
+    ///
+    /// `text` must be the same as the original text up to
+    /// the `state`'s cursor, anything after that can be
+    /// different. Since the state includes the last few
+    /// tokens and all of the open parens, braces and
+    /// template literals this will produce the same tokens
+    /// a `Scanner` starting at the top of `text` would.
+    pub fn resume_at(text: &'a str, state: ScannerState) -> Self {
+        let mut ret = Self::new(text);
+        ret.last_reported_line = state.manual_state.new_line_count + 1;
+        ret.set_state(state);
+        ret
+    }
+    /// Create a new `Scanner` that only accepts the tokens
+    /// available in `version`, anything newer (like a `123n`
+    /// before ES2020) is an `UnsupportedFeature` error. Words
+    /// that weren't reserved yet, `let` and `yield` before
+    /// ES2015 and `await` in a script before ES2017, are
+    /// identifiers
+    pub fn with_ecma_version(text: &'a str, version: EcmaVersion) -> Self {
+        let mut ret = Self::new(text);
+        ret.set_ecma_version(version);
+        ret
+    }
+    /// Create a new `Scanner` for either a script or a
+    /// module. In a module HTML-like comments are disabled
+    /// and `await` is always a keyword, in a script a top
+    /// level `await` is an identifier unless it could be
+    /// inside of an `async` arrow function
+    pub fn with_source_type(text: &'a str, source_type: SourceType) -> Self {
+        let mut ret = Self::new(text);
+        ret.set_source_type(source_type);
+        ret
+    }
+    /// Get the tokens of `text` whose spans overlap `range`,
+    /// like the lines visible in an editor.
+    ///
+    /// Whether a `/` starts a regex or a `}` ends a template
+    /// depends on everything before it, so scanning has to
+    /// start from a point where that is known. Any state from
+    /// `get_state` is one, since it includes the last few tokens
+    /// and everything still open. Scanning resumes from the
+    /// checkpoint with the last cursor at or before
+    /// `range.start`, or from the top of the text when there
+    /// isn't one, and stops after the last token in `range`.
+    /// Saving a state every so often during a full scan
+    /// keeps the cost of each call to about the size of the
+    /// range. The text before a checkpoint's cursor must not
+    /// have changed since it was saved.
+    ///
+    /// The `EoF` token covers no bytes so it is never included.
+    ///
+    /// ```
+    /// # use ress::Scanner;
+    /// let js = "let a = 1;\nlet b = a / 2 / 3;\nlet c = /re/;";
+    /// let mut scanner = Scanner::new(js);
+    /// let mut checkpoints = Vec::new();
+    /// while let Some(item) = scanner.next() {
+    ///     if item.unwrap().token.matches_punct_str(";") {
+    ///         checkpoints.push(scanner.get_state());
+    ///     }
+    /// }
+    /// let items = Scanner::tokens_in(js, 15..26, &checkpoints).unwrap();
+    /// let text: Vec<_> = items.iter().map(|i| &js[i.span.range()]).collect();
+    /// assert_eq!(text, ["b", "=", "a", "/", "2", "/"]);
+    /// ```
+    pub fn tokens_in(
+        text: &'a str,
+        range: Range<usize>,
+        checkpoints: &[ScannerState],
+    ) -> Res<Vec<Item<&'a str>>> {
+        let mut ret = Vec::new();
+        if range.is_empty() {
+            return Ok(ret);
+        }
+        let scanner = checkpoints
+            .iter()
+            .filter(|state| state.manual_state.cursor <= range.start)
+            .max_by_key(|state| state.manual_state.cursor)
+            .map(|state| Self::resume_at(text, state.clone()))
+            .unwrap_or_else(|| Self::new(text));
+        let range = Span::new(range.start, range.end);
+        for item in scanner {
+            let item = item?;
+            if item.span.start >= range.end {
+                break;
+            }
+            if item.span.overlaps(&range) {
+                ret.push(item);
+            }
+        }
+        Ok(ret)
+    }
 }
 
 impl<'a> Iterator for Scanner<'a> {
@@ -216,20 +621,208 @@ impl<'b> Scanner<'b> {
     pub fn look_ahead(&mut self) -> Option<Res<Item<&'b str>>> {
         self.get_next_token(false)
     }
+    /// Register a callback to be invoked with the new
+    /// line number each time scanning crosses a line
+    /// terminator. Each line is only reported once, even
+    /// if the scanner is rewound with `set_state`.
+    pub fn on_line<F: FnMut(usize) + 'b>(&mut self, f: F) -> &mut Self {
+        self.on_line = Some(Box::new(f));
+        self
+    }
+    /// Enable or disable the sloppy mode HTML-like
+    /// comments (`<!--` and `-->`). These are enabled
+    /// by default, strict/module parsers should
+    /// disable them
+    pub fn allow_html_comments(&mut self, allow: bool) -> &mut Self {
+        self.manual_scanner.allow_html_comments(allow);
+        self
+    }
+    /// Produce `get` and `set` as `Keyword`s when they start
+    /// an accessor (`get x() {}`) in an object or class, for
+    /// example to highlight them. This is a guess based on
+    /// the tokens around them, by default they are always
+    /// identifiers.
+    pub fn accessor_keywords(&mut self, enabled: bool) -> &mut Self {
+        self.accessor_keywords = enabled;
+        self
+    }
+    /// Scan JSX elements (`<a b="c">{d}</a>`) as `Token::Jsx`
+    /// parts. A `<` starts an element anywhere an expression
+    /// could start, using the same guess as a `/` starting a
+    /// regex. This is off by default.
+    pub fn jsx(&mut self, enabled: bool) -> &mut Self {
+        self.jsx = enabled;
+        self
+    }
+    /// Scan the text as strict mode code, which makes legacy
+    /// octal literals (`0777`), numbers with a leading zero
+    /// (`08`) and octal escapes in strings (`"\12"`) errors.
+    /// This is off by default so older code is still accepted,
+    /// picking the `Module` source type turns it on.
+    pub fn strict_mode(&mut self, strict: bool) -> &mut Self {
+        self.strict = strict;
+        self
+    }
+    /// Stop with a `LimitExceeded` error as soon as
+    /// the text goes past any of the `limits`, see
+    /// `Scanner::with_limits`
+    pub fn set_limits(&mut self, limits: ScannerLimits) -> &mut Self {
+        self.limits = limits;
+        self
+    }
+    /// Only accept the tokens available in `version`,
+    /// see `Scanner::with_ecma_version`
+    pub fn set_ecma_version(&mut self, version: EcmaVersion) -> &mut Self {
+        self.ecma_version = version;
+        self
+    }
+    /// Scan the text as a script or a module, picking
+    /// `Module` also disables HTML-like comments and turns
+    /// on `strict_mode`, see `Scanner::with_source_type`
+    pub fn set_source_type(&mut self, source_type: SourceType) -> &mut Self {
+        self.source_type = Some(source_type);
+        if source_type == SourceType::Module {
+            self.allow_html_comments(false);
+            self.strict_mode(true);
+        }
+        self
+    }
     /// Skip any upcoming comments to get the
     /// next valid js token
     pub fn skip_comments(&mut self) -> Res<()> {
         debug!(target: "ress", "skipping comments");
         self.manual_scanner.skip_comments()
     }
+    /// Consume this scanner, producing only the significant
+    /// tokens. Comments are dropped and the remaining
+    /// items keep their original spans and locations.
+    pub fn without_comments(self) -> impl Iterator<Item = Res<Item<&'b str>>> {
+        self.filter(|item| !matches!(item, Ok(item) if item.token.is_comment()))
+    }
+    /// Consume this scanner, producing only the comments and
+    /// their spans. Since the whole text is still scanned,
+    /// anything that only looks like a comment, for example
+    /// inside of a string or regex, is skipped.
+    pub fn comments(self) -> impl Iterator<Item = Res<(Comment<&'b str>, Span)>> {
+        self.filter_map(|item| match item {
+            Ok(Item {
+                token: Token::Comment(comment),
+                span,
+                ..
+            }) => Some(Ok((comment, span))),
+            Ok(_) => None,
+            Err(e) => Some(Err(e)),
+        })
+    }
+    /// Consume this scanner, producing each token with its start
+    /// and end `Position`. Unlike an `Item`'s `location`, columns
+    /// are counted in UTF-16 code units to match what browser dev
+    /// tools report.
+    pub fn with_positions(self) -> WithPositions<'b> {
+        WithPositions::new(self)
+    }
+    /// Consume this scanner, producing each item with a flag
+    /// that is `true` when it is a directive like `'use strict'`.
+    ///
+    /// Without parsing this is a guess, a string is a directive
+    /// when it comes before anything else in the program or
+    /// after a `{`, or follows another directive, and is
+    /// followed by a `;`, a `}` or a new line. So a string
+    /// at the start of any block counts and a string continued
+    /// on the next line (`'a'\n + b`) is still treated as a
+    /// directive.
+    pub fn with_directives(self) -> WithDirectives<'b> {
+        WithDirectives::new(self)
+    }
+    /// Pair each item with the white space directly before
+    /// it, the end of the file gets any trailing white space.
+    ///
+    /// Joining the white space and the text of each item's
+    /// span rebuilds the original text exactly. The token's
+    /// `to_source` gives the same text for everything but
+    /// an HTML comment that starts with `-->`, since a `Comment`
+    /// doesn't keep track of a missing `<!--`
+    pub fn lossless(self) -> Lossless<'b> {
+        Lossless::new(self)
+    }
+    /// Consume this scanner, producing each item along with
+    /// the exact text it was scanned from.
+    ///
+    /// The token only keeps what is needed to tell it apart, a
+    /// `Punct` has no text at all and an escaped keyword is
+    /// stored by name, so this is the way to get at what was
+    /// actually written without holding on to the source and
+    /// slicing it with each span yourself.
+    /// ```
+    /// # use ress::Scanner;
+    /// let mut items = Scanner::new("0x1 +/a/g").with_lexemes();
+    /// let (_, raw) = items.nth(1).unwrap().unwrap();
+    /// assert_eq!(raw, "+");
+    /// ```
+    pub fn with_lexemes(self) -> WithLexemes<'b> {
+        WithLexemes::new(self)
+    }
     /// Get a copy of the scanner's current state
     pub fn get_state(&self) -> ScannerState {
         ScannerState {
             manual_state: self.manual_scanner.get_state(),
             last_three: self.last_three.clone(),
             paren_stack: self.paren_stack.clone(),
+            brace_stack: self.brace_stack.clone(),
+            token_count: self.token_count,
+            async_in_statement: self.async_in_statement,
+        }
+    }
+    /// Get a snapshot of the state that decides how the
+    /// next token is read, for tracking down a `/` or `}`
+    /// that was scanned as the wrong thing. Only available
+    /// with the `debug` feature.
+    #[cfg(any(test, feature = "debug"))]
+    pub fn debug_state(&self) -> ScannerDebug {
+        let ManualState {
+            cursor,
+            curly_stack,
+            ..
+        } = self.manual_scanner.get_state();
+        ScannerDebug {
+            cursor,
+            curly_stack,
+            paren_depth: self.paren_stack.len(),
+            brace_depth: self.brace_stack.len(),
+            last_token: self.last_three.one().map(|t| format!("{:?}", t)),
+            regex_allowed: self.is_regex_start(),
         }
     }
+    /// Get up to the last `n` tokens this scanner has produced,
+    /// oldest first. Comments are skipped and at most the last
+    /// 16 are kept. Since the history isn't part of a
+    /// `ScannerState` it is cleared by `set_state`.
+    ///
+    /// ```
+    /// # use ress::prelude::*;
+    /// let mut s = Scanner::new("a = b /* c */ + 1");
+    /// s.by_ref().take(5).for_each(drop);
+    /// assert_eq!(
+    ///     s.recent_tokens(2),
+    ///     &[Token::Ident("b".into()), Token::Punct(Punct::Plus)]
+    /// );
+    /// ```
+    pub fn recent_tokens(&self, n: usize) -> &[Token<&'b str>] {
+        let len = self.recent.len();
+        &self.recent[len - n.min(len).min(RECENT_TOKENS)..]
+    }
+    /// Add a token to the history for `recent_tokens`, the
+    /// oldest half is dropped once it gets to twice the size
+    /// that is kept so the rest is always contiguous
+    fn remember(&mut self, token: &Token<&'b str>) {
+        if token.is_comment() {
+            return;
+        }
+        if self.recent.len() == RECENT_TOKENS * 2 {
+            self.recent.drain(..RECENT_TOKENS);
+        }
+        self.recent.push(token.clone());
+    }
     /// Set the scanner's current state to the state provided
     #[inline]
     pub fn set_state(&mut self, state: ScannerState) {
@@ -237,9 +830,16 @@ impl<'b> Scanner<'b> {
             manual_state,
             last_three,
             paren_stack,
+            brace_stack,
+            token_count,
+            async_in_statement,
         } = state;
         self.last_three = last_three;
+        self.recent.clear();
         self.paren_stack = paren_stack;
+        self.brace_stack = brace_stack;
+        self.token_count = token_count;
+        self.async_in_statement = async_in_statement;
         self.manual_scanner.set_state(manual_state);
     }
     #[inline]
@@ -255,30 +855,412 @@ impl<'b> Scanner<'b> {
             return None;
         };
         let state = self.manual_scanner.get_state();
-        let next = match self.manual_scanner.next_token()? {
+        let next = if self.jsx_element_next(&state) {
+            self.manual_scanner.next_jsx_tag()?
+        } else {
+            self.manual_scanner.next_token()?
+        };
+        let next = match next {
             Ok(n) => n,
+            Err(e) if self.recover => self.skip_error(&state, &e),
             Err(e) => {
                 self.errored = true;
                 return Some(Err(e));
             }
         };
+        // the white space after a token is skipped when it is
+        // produced, so the next one starts exactly at the cursor
+        debug_assert_eq!(
+            next.span.start, state.cursor,
+            "token does not start at the cursor"
+        );
 
         let ret = if next.token.is_div_punct() && self.is_regex_start() {
-            self.manual_scanner.next_regex(next.span.len())?
+            let after_div = self.manual_scanner.get_state();
+            match self.manual_scanner.next_regex(next.span.len())? {
+                // the guess that this starts a regex might be wrong,
+                // when the division reading is possible use that
+                // instead of reporting a regex that was never there
+                Err(_) if self.division_possible() => {
+                    self.manual_scanner.set_state(after_div);
+                    Ok(next)
+                }
+                Err(e) if self.recover => Ok(self.skip_error(&state, &e)),
+                ret => ret,
+            }
         } else {
             Ok(next)
         };
-        if advance_cursor {
-            if let Ok(i) = &ret {
-                if let Err(e) = self.keep_books(&i) {
+        let mut ret = ret.map(|item| {
+            self.unreserved_to_ident(self.await_to_ident(self.keyword_to_member(item)))
+        });
+        if self.accessor_keywords {
+            ret = ret.map(|item| self.accessor_to_keyword(item));
+        }
+        if let Ok(item) = &ret {
+            if let Err(e) = self.check_progress(item) {
+                // move past the next character so the
+                // following call can't get stuck on it too
+                let start = item.span.start;
+                let next = self.original[start..]
+                    .chars()
+                    .next()
+                    .map_or(0, char::len_utf8);
+                self.manual_scanner.set_state(state.clone());
+                let skipped = self.manual_scanner.skip_invalid(start + next);
+                if !self.recover {
+                    if !advance_cursor {
+                        self.manual_scanner.set_state(state);
+                    }
                     return Some(Err(e));
                 }
+                ret = if skipped.span.is_empty() {
+                    // the end of the text is all that is left
+                    self.manual_scanner.next_token()?
+                } else {
+                    Ok(skipped)
+                };
+            }
+        }
+        if let Ok(item) = &ret {
+            if let Err(e) = self
+                .check_limits(item)
+                .and_then(|_| self.check_version(item))
+                .and_then(|_| self.check_strict(item))
+            {
+                if advance_cursor {
+                    self.errored = true;
+                } else {
+                    self.manual_scanner.set_state(state);
+                }
+                return Some(Err(e));
+            }
+        }
+        if advance_cursor {
+            if let Ok(i) = &mut ret {
+                if let Err(e) = self.keep_books(i) {
+                    if !self.recover {
+                        return Some(Err(e));
+                    }
+                    // an unmatched `)` or `}` is still
+                    // skipped over like any other error
+                    self.errored = false;
+                    i.token = Token::Invalid(&self.original[i.span.range()]);
+                    self.last_three
+                        .push((&i.token, self.manual_scanner.new_line_count as u32).into());
+                }
+                self.report_lines(i.location.end.line);
+                self.remember(&i.token);
+                self.token_count += 1;
             }
         } else {
             self.manual_scanner.set_state(state);
         }
         Some(ret)
     }
+    /// Check if the next token is the `<` that starts
+    /// a JSX element, when `jsx` is enabled
+    fn jsx_element_next(&self, state: &ManualState) -> bool {
+        if !self.jsx {
+            return false;
+        }
+        let in_jsx = matches!(state.curly_stack.last(), Some(OpenCurlyKind::Jsx(_)));
+        let rest = &self.original[state.cursor..];
+        !in_jsx && rest.starts_with('<') && !rest.starts_with("<!--") && self.is_regex_start()
+    }
+    /// When recovering from errors, skip the text that
+    /// caused `error`, starting at the cursor in `state`,
+    /// and produce it as an `Invalid` item
+    fn skip_error(&mut self, state: &ManualState, error: &Error) -> Item<&'b str> {
+        let start = state.cursor;
+        let rest = &self.original[start..];
+        let len = match error.kind {
+            ErrorKind::UnterminatedComment | ErrorKind::UnterminatedTemplate => rest.len(),
+            ErrorKind::UnterminatedString
+            | ErrorKind::UnterminatedRegex
+            | ErrorKind::InvalidEscape => rest.find(is_line_term).unwrap_or(rest.len()),
+            ErrorKind::InvalidNumber => rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.' || c == '$'))
+                .unwrap_or(rest.len()),
+            _ => 0,
+        };
+        // always skip at least one character so
+        // the next token can't hit the same error
+        let len = len.max(rest.chars().next().map_or(0, char::len_utf8));
+        self.manual_scanner.set_state(state.clone());
+        self.manual_scanner.skip_invalid(start + len)
+    }
+    /// Every token other than the end of the file
+    /// has to consume some text, otherwise the next
+    /// call would produce the same token forever. When
+    /// this fails the scanner skips a character, producing
+    /// this error or, when recovering, an `Invalid` item
+    fn check_progress(&self, item: &Item<&'b str>) -> Res<()> {
+        if item.span.is_empty() && !item.is_eof() {
+            return self.error(RawError {
+                kind: ErrorKind::Other,
+                idx: item.span.start,
+                msg: format!("{:?} did not consume any text", item.token),
+            });
+        }
+        Ok(())
+    }
+    /// Check that producing `item` doesn't go
+    /// past any of the `ScannerLimits`
+    fn check_limits(&self, item: &Item<&'b str>) -> Res<()> {
+        let limits = &self.limits;
+        let msg = if item.span.len() > limits.max_token_len {
+            format!("token is longer than {} bytes", limits.max_token_len)
+        } else if !item.is_eof() && self.token_count >= limits.max_total_tokens {
+            format!("more than {} tokens", limits.max_total_tokens)
+        } else if self.manual_scanner.stream.template_depth() > limits.max_template_depth {
+            format!(
+                "template literals nested more than {} deep",
+                limits.max_template_depth
+            )
+        } else {
+            return Ok(());
+        };
+        self.error(RawError {
+            kind: ErrorKind::LimitExceeded,
+            idx: item.span.start,
+            msg,
+        })
+    }
+    /// Check that `item` was already part of the
+    /// language in the `EcmaVersion` being scanned
+    fn check_version(&self, item: &Item<&'b str>) -> Res<()> {
+        let rest = &self.original[item.span.end..];
+        let (version, feature) = match Self::introduced_in(&item.token, rest) {
+            Some(found) => found,
+            None => return Ok(()),
+        };
+        if version <= self.ecma_version {
+            return Ok(());
+        }
+        self.error(RawError {
+            kind: ErrorKind::UnsupportedFeature,
+            idx: item.span.start,
+            msg: format!(
+                "{:?} is needed for {} but the target is {:?}",
+                version, feature, self.ecma_version
+            ),
+        })
+    }
+    /// Check that `item` is allowed in strict mode
+    /// code, when `strict_mode` is enabled
+    fn check_strict(&self, item: &Item<&'b str>) -> Res<()> {
+        if !self.strict {
+            return Ok(());
+        }
+        let (kind, msg) = match &item.token {
+            Token::Number(n) if n.is_legacy() => (
+                ErrorKind::InvalidNumber,
+                "numbers with a leading zero are not allowed in strict mode",
+            ),
+            Token::String(s) if s.has_octal_escape() => (
+                ErrorKind::InvalidEscape,
+                "octal escape sequences are not allowed in strict mode",
+            ),
+            _ => return Ok(()),
+        };
+        self.error(RawError {
+            kind,
+            idx: item.span.start,
+            msg: msg.to_string(),
+        })
+    }
+    /// The version that added `token` to the language
+    /// and a short description of what it is, `None` for
+    /// anything that was already in ES5. When a token uses
+    /// more than one newer feature the newest is checked first.
+    ///
+    /// `??` and `??=` are scanned as separate `?` and `=`
+    /// tokens so those are found by looking at the `rest`
+    /// of the text directly after the `?`
+    fn introduced_in(token: &Token<&str>, rest: &str) -> Option<(EcmaVersion, &'static str)> {
+        use EcmaVersion::*;
+        let found = match token {
+            Token::Punct(Punct::QuestionMark) => {
+                if rest.starts_with("?=") {
+                    (Es2021, "logical assignment operators")
+                } else if rest.starts_with('?') {
+                    (Es2020, "nullish coalescing")
+                } else {
+                    return None;
+                }
+            }
+            Token::Punct(Punct::QuestionMarkDot) => (Es2020, "optional chaining"),
+            Token::Template(_) => (Es2015, "template literals"),
+            Token::Punct(Punct::EqualGreaterThan) => (Es2015, "arrow functions"),
+            Token::Punct(Punct::Ellipsis) => (Es2015, "spread and rest elements"),
+            Token::Punct(Punct::DoubleAsterisk) | Token::Punct(Punct::DoubleAsteriskEqual) => {
+                (Es2016, "exponent operators")
+            }
+            Token::Punct(Punct::DoubleAmpersandEqual) | Token::Punct(Punct::DoublePipeEqual) => {
+                (Es2021, "logical assignment operators")
+            }
+            Token::Punct(Punct::Hash) => (Es2022, "private names"),
+            Token::Number(n) => {
+                let text = n.as_ref();
+                if text.contains('_') {
+                    (Es2021, "numeric separators")
+                } else if text.ends_with('n') {
+                    (Es2020, "BigInt literals")
+                } else if matches!(n.kind(), tokens::NumberKind::Bin | tokens::NumberKind::Oct) {
+                    (Es2015, "binary and octal literals")
+                } else {
+                    return None;
+                }
+            }
+            Token::RegEx(re) => {
+                if re.unicode_sets() {
+                    (Es2024, "the regex v flag")
+                } else if re.has_indices() {
+                    (Es2022, "the regex d flag")
+                } else if re.dot_all() {
+                    (Es2018, "the regex s flag")
+                } else if re.unicode() || re.sticky() {
+                    (Es2015, "the regex u and y flags")
+                } else {
+                    return None;
+                }
+            }
+            Token::Comment(c) if c.kind == tokens::CommentKind::Hashbang => (Es2023, "hashbangs"),
+            _ => return None,
+        };
+        Some(found)
+    }
+    /// Before a keyword was reserved it could be used as
+    /// a name, so for older `EcmaVersion`s these become
+    /// identifiers
+    fn unreserved_to_ident(&self, item: Item<&'b str>) -> Item<&'b str> {
+        let unreserved = match item.token {
+            Token::Keyword(Keyword::Let(_)) | Token::Keyword(Keyword::Yield(_)) => {
+                self.ecma_version < EcmaVersion::Es2015
+            }
+            Token::Keyword(Keyword::Await(_)) => {
+                self.ecma_version < EcmaVersion::Es2017
+                    && self.source_type != Some(SourceType::Module)
+            }
+            _ => false,
+        };
+        if unreserved {
+            let name = &self.original[item.span.range()];
+            Item {
+                token: Token::Ident(name.into()),
+                ..item
+            }
+        } else {
+            item
+        }
+    }
+    /// With `accessor_keywords`, a `get` or `set` at the start
+    /// of an object or class member that is followed by a
+    /// property name becomes a keyword
+    fn accessor_to_keyword(&mut self, item: Item<&'b str>) -> Item<&'b str> {
+        let keyword = if item.token.matches_ident_str("get") {
+            Keyword::Get(())
+        } else if item.token.matches_ident_str("set") {
+            Keyword::Set(())
+        } else {
+            return item;
+        };
+        let member_start = matches!(
+            self.last_three.one(),
+            Some(MetaToken::OpenBrace(_, _))
+                | Some(MetaToken::CloseBrace(_))
+                | Some(MetaToken::Punct(Punct::Comma))
+                | Some(MetaToken::Punct(Punct::SemiColon))
+                | Some(MetaToken::Keyword(RawKeyword::Static, _))
+        );
+        if !member_start || !self.property_name_next(item.location.end.line) {
+            return item;
+        }
+        Item {
+            token: Token::Keyword(keyword.with(&self.original[item.span.range()])),
+            ..item
+        }
+    }
+    /// Check if the token after the cursor, skipping comments,
+    /// could start a property name on `line`. A new line could
+    /// mean the name was the end of a statement
+    fn property_name_next(&mut self, line: usize) -> bool {
+        let state = self.manual_scanner.get_state();
+        let next = loop {
+            match self.manual_scanner.next_token() {
+                Some(Ok(item)) if item.token.is_comment() => continue,
+                next => break next,
+            }
+        };
+        self.manual_scanner.set_state(state);
+        let next = match next {
+            Some(Ok(item)) if item.location.start.line == line => item,
+            _ => return false,
+        };
+        matches!(
+            next,
+            Item {
+                token: Token::Ident(_)
+                    | Token::Keyword(_)
+                    | Token::String(_)
+                    | Token::Number(_)
+                    | Token::Boolean(_)
+                    | Token::Null
+                    | Token::Punct(Punct::OpenBracket)
+                    | Token::Punct(Punct::Hash),
+                ..
+            }
+        )
+    }
+    /// A keyword directly after a `.` or `?.` is a property
+    /// name (`obj.class`) so it becomes an identifier
+    fn keyword_to_member(&self, item: Item<&'b str>) -> Item<&'b str> {
+        if item.token.is_keyword()
+            && matches!(
+                self.last_three.one(),
+                Some(MetaToken::Punct(Punct::Period))
+                    | Some(MetaToken::Punct(Punct::QuestionMarkDot))
+            )
+        {
+            let name = &self.original[item.span.range()];
+            Item {
+                token: Token::Ident(name.into()),
+                ..item
+            }
+        } else {
+            item
+        }
+    }
+    /// Outside of an `async` function `await` is only
+    /// reserved in modules, so a top level `await` in a
+    /// script becomes an identifier. Without a source type
+    /// it is left as a keyword
+    fn await_to_ident(&self, item: Item<&'b str>) -> Item<&'b str> {
+        if self.source_type == Some(SourceType::Script)
+            && matches!(item.token, Token::Keyword(Keyword::Await(_)))
+            && self.brace_stack.is_empty()
+            && !self.async_in_statement
+        {
+            let name = &self.original[item.span.range()];
+            Item {
+                token: Token::Ident(name.into()),
+                ..item
+            }
+        } else {
+            item
+        }
+    }
+    /// Invoke the `on_line` callback for every line
+    /// up to and including `line` not yet reported
+    fn report_lines(&mut self, line: usize) {
+        if let Some(f) = self.on_line.as_mut() {
+            while self.last_reported_line < line {
+                self.last_reported_line += 1;
+                f(self.last_reported_line);
+            }
+        }
+    }
     #[inline]
     /// Evaluate the token for possible regex
     /// start and handle updating the
@@ -294,12 +1276,37 @@ impl<'b> Scanner<'b> {
                     .last_three
                     .push((&item.token, self.manual_scanner.new_line_count as u32).into()),
             }
+        } else if item.token.matches_ident_str("of") && self.of_is_operator() {
+            self.last_three.push(MetaToken::Of);
         } else if !item.token.is_comment() {
             self.last_three
                 .push((&item.token, self.manual_scanner.new_line_count as u32).into());
         }
+        if self.brace_stack.is_empty() {
+            if item.token.matches_ident_str("async") {
+                self.async_in_statement = true;
+            } else if item
+                .token
+                .matches_any_punct(&[Punct::SemiColon, Punct::CloseBrace])
+            {
+                self.async_in_statement = false;
+            }
+        }
         Ok(())
     }
+    /// Check if an `of` is the operator in a `for` loop head,
+    /// this needs to be directly inside of the parens after a
+    /// `for` and follow the name or pattern being bound
+    fn of_is_operator(&self) -> bool {
+        let in_loop_head = matches!(self.paren_stack.last(), Some(p) if p.conditional);
+        in_loop_head
+            && matches!(
+                self.last_three.one(),
+                Some(MetaToken::Ident)
+                    | Some(MetaToken::Punct(Punct::CloseBracket))
+                    | Some(MetaToken::CloseBrace(_))
+            )
+    }
     #[inline]
     /// Handle the book keeping when we find
     /// an `(`
@@ -307,11 +1314,15 @@ impl<'b> Scanner<'b> {
         let func_expr = if let Some(MetaToken::Keyword(RawKeyword::Function, _)) =
             self.last_three.one()
         {
-            if let Some(tok) = self.last_three.two() {
-                !Self::check_for_expression(*tok)
-            } else {
-                false
-            }
+            // an anonymous function can only be a declaration
+            // at the start of a statement or after `export default`
+            !matches!(
+                self.last_three.two(),
+                None | Some(MetaToken::Punct(Punct::SemiColon))
+                    | Some(MetaToken::CloseBrace(_))
+                    | Some(MetaToken::OpenBrace(_, _))
+                    | Some(MetaToken::Keyword(RawKeyword::Default, _))
+            )
         } else if let Some(MetaToken::Keyword(RawKeyword::Function, _)) = self.last_three.two() {
             if let Some(tok) = self.last_three.three() {
                 Self::check_for_expression(*tok)
@@ -321,10 +1332,13 @@ impl<'b> Scanner<'b> {
         } else {
             false
         };
-        let conditional = if let Some(tok) = self.last_three.one() {
-            Self::check_token_for_conditional(*tok)
-        } else {
-            false
+        let conditional = match (self.last_three.one(), self.last_three.two()) {
+            // for await (x of y)
+            (Some(MetaToken::Keyword(RawKeyword::Await, _)), Some(tok)) => {
+                matches!(tok, MetaToken::Keyword(RawKeyword::For, _))
+            }
+            (Some(tok), _) => Self::check_token_for_conditional(*tok),
+            _ => false,
         };
         let paren = Paren {
             func_expr,
@@ -343,7 +1357,8 @@ impl<'b> Scanner<'b> {
                 MetaToken::Punct(Punct::OpenParen)
                 | MetaToken::Punct(Punct::OpenBracket)
                 | MetaToken::OpenParen(_)
-                | MetaToken::OpenBrace(_, _) => false,
+                | MetaToken::OpenBrace(_, _)
+                | MetaToken::TemplateOpen => false,
                 MetaToken::Punct(Punct::Colon) => {
                     if let Some(parent) = self.brace_stack.last() {
                         parent.is_block
@@ -387,6 +1402,7 @@ impl<'b> Scanner<'b> {
         } else {
             self.errored = true;
             return self.error(RawError {
+                kind: ErrorKind::Other,
                 idx: start,
                 msg: "Unmatched open close paren".to_string(),
             });
@@ -403,6 +1419,7 @@ impl<'b> Scanner<'b> {
             Ok(())
         } else {
             self.error(RawError {
+                kind: ErrorKind::Other,
                 idx: start,
                 msg: "unmatched close brace".to_string(),
             })
@@ -415,60 +1432,72 @@ impl<'b> Scanner<'b> {
     fn is_regex_start(&self) -> bool {
         if let Some(ref last_token) = self.last_three.one() {
             match last_token {
-                MetaToken::Keyword(k, _) => match k {
-                    RawKeyword::This => false,
-                    _ => true,
-                },
-                MetaToken::Punct(p) => match p {
-                    Punct::CloseBracket => false,
-                    _ => true,
-                },
+                MetaToken::Keyword(k, _) => !matches!(k, RawKeyword::This),
+                MetaToken::Punct(p) => !matches!(p, Punct::CloseBracket),
                 MetaToken::CloseParen(open) => open.conditional,
-                MetaToken::CloseBrace(close) => {
-                    if close.is_block {
-                        if let Some(open) = &close.paren {
-                            !open.func_expr
-                        } else {
-                            true
-                        }
+                MetaToken::CloseBrace(close) if close.is_block => {
+                    if let Some(open) = &close.paren {
+                        !open.func_expr
                     } else {
-                        false
+                        true
                     }
                 }
-                MetaToken::OpenParen(_) | MetaToken::OpenBrace(_, _) => true,
+                MetaToken::OpenParen(_)
+                | MetaToken::OpenBrace(_, _)
+                | MetaToken::TemplateOpen
+                | MetaToken::Of => true,
                 _ => false,
             }
         } else {
             true
         }
     }
+    /// When `is_regex_start` says a `/` starts a regex, is
+    /// it still possible for it to be division? This is the
+    /// case after a keyword that can also be an identifier
+    /// (`yield / 2` outside of a generator) or a `}` that
+    /// was taken to close a block but might close an object
+    fn division_possible(&self) -> bool {
+        matches!(
+            self.last_three.one(),
+            Some(MetaToken::Keyword(
+                RawKeyword::Await
+                    | RawKeyword::Let
+                    | RawKeyword::Yield
+                    | RawKeyword::Static
+                    | RawKeyword::Implements
+                    | RawKeyword::Interface
+                    | RawKeyword::Package
+                    | RawKeyword::Private
+                    | RawKeyword::Protected
+                    | RawKeyword::Public,
+                _
+            )) | Some(MetaToken::CloseBrace(_))
+        )
+    }
     /// Check a token for the conditional keywords
     ///
     /// > used in determining if we are at a regex or not
     fn check_token_for_conditional(tok: MetaToken) -> bool {
-        if let MetaToken::Keyword(k, _) = tok {
-            match k {
-                RawKeyword::If | RawKeyword::For | RawKeyword::While | RawKeyword::With => true,
-                _ => false,
-            }
-        } else {
-            false
-        }
+        matches!(
+            tok,
+            MetaToken::Keyword(RawKeyword::If, _)
+                | MetaToken::Keyword(RawKeyword::For, _)
+                | MetaToken::Keyword(RawKeyword::While, _)
+                | MetaToken::Keyword(RawKeyword::With, _)
+        )
     }
     /// Check if a token indicates beginning of a
     /// function expression
     ///
     /// > used in determining if we are at a regex or not
     fn check_for_expression(token: MetaToken) -> bool {
-        if Self::is_op(token) {
-            true
-        } else {
-            match token {
-                MetaToken::Keyword(RawKeyword::Return, _)
-                | MetaToken::Keyword(RawKeyword::Case, _) => true,
+        Self::is_op(token)
+            || match token {
+                MetaToken::Keyword(k, _) => [RawKeyword::Return, RawKeyword::Case].contains(&k),
+                MetaToken::Punct(p) => [Punct::EqualGreaterThan, Punct::Ellipsis].contains(&p),
                 _ => false,
             }
-        }
     }
     /// Determine if a token is a punctuation or keyword
     /// that indicates an operation
@@ -476,101 +1505,79 @@ impl<'b> Scanner<'b> {
     /// > used in determining if we are at a regex or not
     fn is_op(tok: MetaToken) -> bool {
         match tok {
-            MetaToken::Punct(ref p) => matches!(
-                p,
-                Punct::Equal
-                    | Punct::PlusEqual
-                    | Punct::DashEqual
-                    | Punct::AsteriskEqual
-                    | Punct::ForwardSlashEqual
-                    | Punct::PercentEqual
-                    | Punct::DoubleLessThanEqual
-                    | Punct::DoubleGreaterThanEqual
-                    | Punct::TripleGreaterThanEqual
-                    | Punct::AmpersandEqual
-                    | Punct::PipeEqual
-                    | Punct::CaretEqual
-                    | Punct::Comma
-                    | Punct::Plus
-                    | Punct::Dash
-                    | Punct::Asterisk
-                    | Punct::ForwardSlash
-                    | Punct::Percent
-                    | Punct::DoubleLessThan
-                    | Punct::DoubleGreaterThan
-                    | Punct::TripleGreaterThan
-                    | Punct::Ampersand
-                    | Punct::Pipe
-                    | Punct::Caret
-                    | Punct::DoubleAmpersand
-                    | Punct::DoublePipe
-                    | Punct::QuestionMark
-                    | Punct::Colon
-                    | Punct::TripleEqual
-                    | Punct::DoubleEqual
-                    | Punct::GreaterThanEqual
-                    | Punct::LessThanEqual
-                    | Punct::LessThan
-                    | Punct::GreaterThan
-                    | Punct::BangEqual
-                    | Punct::BangDoubleEqual
-                    | Punct::DoublePlus
-                    | Punct::DoubleDash
-                    | Punct::Tilde
-                    | Punct::Bang
-            ),
-            MetaToken::Keyword(k, _) => matches!(
-                k,
-                RawKeyword::InstanceOf
-                    | RawKeyword::In
-                    | RawKeyword::Delete
-                    | RawKeyword::Void
-                    | RawKeyword::TypeOf
-                    | RawKeyword::Throw
-                    | RawKeyword::New
-            ),
+            MetaToken::Punct(p) => {
+                p.is_assignment()
+                    || p.is_binary_operator()
+                    || p.is_unary_operator()
+                    || p.is_update_operator()
+                    || [Punct::Comma, Punct::QuestionMark, Punct::Colon].contains(&p)
+            }
+            MetaToken::Keyword(k, _) => OPERATOR_KEYWORDS.contains(&k),
             _ => false,
         }
     }
+    /// Keep going after a token can't be scanned, the text
+    /// that caused the error is produced as a `Token::Invalid`
+    /// instead of an `Err`. An unterminated string or regex
+    /// skips to the end of its line, an unterminated comment or
+    /// template skips to the end of the text, a bad number skips
+    /// the rest of the number and anything else skips a single
+    /// character.
+    pub fn recover_errors(&mut self, recover: bool) -> &mut Self {
+        self.recover = recover;
+        self
+    }
+    /// Get the full text this scanner was created with
+    pub fn source(&self) -> &'b str {
+        self.original
+    }
+    /// Get the byte range of the white space skipped between two
+    /// spans, `a` being the span of a token and `b` the span of the
+    /// token directly after it. Together with the token spans these
+    /// cover every byte of the source.
+    pub fn trivia_between(&self, a: &Span, b: &Span) -> Range<usize> {
+        debug_assert!(
+            self.original
+                .get(a.end..b.start)
+                .map(|s| s.chars().all(is_whitespace))
+                .unwrap_or(false),
+            "{:?} and {:?} are not separated by only white space",
+            a,
+            b
+        );
+        a.end..b.start
+    }
     /// Get a string for any given span
     pub fn string_for(&self, span: &Span) -> Option<String> {
         self.manual_scanner.string_for(span)
     }
-    /// Get a &str for any given span
+    /// Get a &str for any given span, this will be `None`
+    /// if the span is out of bounds or does not fall on
+    /// a char boundary
     pub fn str_for(&self, span: &Span) -> Option<&'b str> {
         self.manual_scanner.str_for(span)
     }
-    /// Get the line/column pair for any given byte index
+    /// Get the line and column for any given byte index,
+    /// these start at 1 and count characters the same way
+    /// as an `Item`'s `location` so an error can point at
+    /// the same place as the token it is about
     pub fn position_for(&self, idx: usize) -> (usize, usize) {
-        let mut line_ct = 1;
-        // This is the byte position, not the character
-        // position to account for multi byte chars
-        let mut byte_position = 0;
-        // loop over the characters
-        for (i, c) in self.original.chars().enumerate() {
-            if i >= idx {
-                return (line_ct, byte_position);
-            }
-            match c {
-                '\r' => {
-                    // look ahead 1 char to see if it is a newline pair
-                    // if so, don't include it, it will get included in the next
-                    // iteration
-                    if let Some(next) = self.original.get(byte_position..byte_position + 2) {
-                        if next != "\r\n" {
-                            line_ct += 1;
-                            byte_position = 0;
-                        }
-                    }
-                }
-                '\n' | '\u{2028}' | '\u{2029}' => {
-                    line_ct += 1;
-                    byte_position = 0;
-                }
-                _ => byte_position += c.len_utf8(),
-            };
-        }
-        (line_ct, byte_position)
+        self.manual_scanner.position_for(idx)
+    }
+
+    /// Check if the next token is a `:` without consuming
+    /// it. Called after an identifier this tells a
+    /// label (`outer: for`) or an object key (`{ a: 1 }`)
+    /// apart from any other use of the name. A comment
+    /// between the two counts as the next token.
+    pub fn followed_by_colon(&mut self) -> bool {
+        matches!(
+            self.look_ahead(),
+            Some(Ok(Item {
+                token: Token::Punct(Punct::Colon),
+                ..
+            }))
+        )
     }
 
     pub fn has_pending_new_line(&self) -> bool {
@@ -579,9 +1586,10 @@ impl<'b> Scanner<'b> {
 
     /// Helper to handle the error cases
     fn error<T>(&self, raw_error: RawError) -> Res<T> {
-        let RawError { idx, msg } = &raw_error;
+        let RawError { kind, idx, msg } = &raw_error;
         let (line, column) = self.position_for(*idx);
         Err(Error {
+            kind: *kind,
             line,
             column,
             msg: msg.clone(),
@@ -595,12 +1603,102 @@ fn is_line_term(c: char) -> bool {
     c == '\n' || c == '\r' || c == '\u{2028}' || c == '\u{2029}'
 }
 
+/// The keywords that are operators, a `/` after
+/// any of these starts a regex
+const OPERATOR_KEYWORDS: &[RawKeyword] = &[
+    RawKeyword::InstanceOf,
+    RawKeyword::In,
+    RawKeyword::Delete,
+    RawKeyword::Void,
+    RawKeyword::TypeOf,
+    RawKeyword::Throw,
+    RawKeyword::New,
+    RawKeyword::Yield,
+    RawKeyword::Await,
+];
+
+/// Count the line terminators in `text`, a `\r\n` counts
+/// as one, along with how far the text moves the column.
+/// When there is a line terminator that is the number of
+/// characters after the last one plus one, otherwise it is
+/// the number of characters in `text`
+fn line_extent(text: &str) -> (usize, usize) {
+    let mut new_lines = 0;
+    let mut len = 0;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\r' && chars.peek() == Some(&'\n') {
+            continue;
+        }
+        if is_line_term(c) {
+            new_lines += 1;
+            len = 0;
+        }
+        len += 1;
+    }
+    (new_lines, len)
+}
+
+/// Check if a char is white space or a line terminator
+/// according to the spec, this is the same set that is
+/// skipped between tokens
+fn is_whitespace(c: char) -> bool {
+    matches!(
+        c,
+        '\t' | '\u{000b}' | '\u{000c}' | ' ' | '\u{00a0}' | '\u{feff}'
+    ) || matches!(c, '\u{1680}' | '\u{202f}' | '\u{205f}' | '\u{3000}')
+        || ('\u{2000}'..='\u{200a}').contains(&c)
+        || is_line_term(c)
+}
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 /// For keeping track of the nested-ness of
-/// templates and blocks
+/// templates, blocks and JSX elements
 pub enum OpenCurlyKind {
     Template,
     Block,
+    /// A JSX element, while this is the innermost
+    /// entry the text is scanned as JSX
+    Jsx(JsxContext),
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+/// Where the tokenizer is inside of a JSX element
+pub enum JsxContext {
+    /// Inside of an opening tag (`<a b="c"`)
+    OpeningTag,
+    /// Between the opening and closing tags
+    Children,
+    /// Inside of a closing tag (`</a`)
+    ClosingTag,
+}
+
+#[cfg(any(test, feature = "debug"))]
+#[derive(Clone, Debug, PartialEq)]
+/// The result of `Scanner::debug_state`
+pub struct ScannerDebug {
+    /// The byte index of the next token
+    pub cursor: usize,
+    /// Every open `{`, `${` and JSX element, innermost last
+    pub curly_stack: Vec<OpenCurlyKind>,
+    /// The number of open `(`
+    pub paren_depth: usize,
+    /// The number of open `{`, not counting `${`
+    pub brace_depth: usize,
+    /// The last token as the regex check sees it,
+    /// in its `Debug` format
+    pub last_token: Option<String>,
+    /// If a `/` here would start a regex
+    pub regex_allowed: bool,
+}
+
+#[cfg(any(test, feature = "debug"))]
+impl ScannerDebug {
+    /// Check if the next token is inside of
+    /// a template substitution (`${ }`)
+    pub fn in_template(&self) -> bool {
+        self.curly_stack.contains(&OpenCurlyKind::Template)
+    }
 }
 
 #[derive(Clone)]
@@ -611,6 +1709,61 @@ pub struct ScannerState {
     pub manual_state: ManualState,
     pub last_three: LookBehind,
     pub paren_stack: Vec<Paren>,
+    pub brace_stack: Vec<Brace>,
+    pub token_count: usize,
+    pub async_in_statement: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Upper bounds for a `Scanner`, useful when scanning
+/// untrusted text. The default has no limits.
+pub struct ScannerLimits {
+    /// How many template literals can be nested
+    /// inside of each other's substitutions
+    pub max_template_depth: usize,
+    /// The longest a single token can be in bytes, this
+    /// is checked once the token has been scanned so the
+    /// tokenizer still reads the whole token first
+    pub max_token_len: usize,
+    /// How many tokens can be produced, not
+    /// including the end of file
+    pub max_total_tokens: usize,
+}
+
+impl Default for ScannerLimits {
+    fn default() -> Self {
+        Self {
+            max_template_depth: usize::MAX,
+            max_token_len: usize::MAX,
+            max_total_tokens: usize::MAX,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// The goal symbol the text will be parsed with,
+/// see `Scanner::with_source_type`
+pub enum SourceType {
+    Script,
+    Module,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+/// The edition of the ECMAScript specification
+/// the text targets, see `Scanner::with_ecma_version`
+pub enum EcmaVersion {
+    Es5,
+    Es2015,
+    Es2016,
+    Es2017,
+    Es2018,
+    Es2019,
+    Es2020,
+    Es2021,
+    Es2022,
+    Es2023,
+    #[default]
+    Es2024,
 }
 
 #[cfg(test)]
@@ -632,12 +1785,12 @@ function thing() {
             }),
             Token::String(StringLit::single("use strict", false)),
             Token::Punct(Punct::SemiColon),
-            Token::Keyword(Keyword::Function("function".into())),
+            Token::Keyword(Keyword::Function("function")),
             Token::Ident("thing".into()),
             Token::Punct(Punct::OpenParen),
             Token::Punct(Punct::CloseParen),
             Token::Punct(Punct::OpenBrace),
-            Token::Keyword(Keyword::Let("let".into())),
+            Token::Keyword(Keyword::Let("let")),
             Token::Ident("x".into()),
             Token::Punct(Punct::Equal),
             Token::Number("0".into()),
@@ -652,7 +1805,7 @@ function thing() {
             Token::Punct(Punct::CloseBrace),
             Token::EoF,
         ];
-        for (lhs, rhs) in Scanner::new(js).zip(expectation.into_iter()) {
+        for (lhs, rhs) in Scanner::new(js).zip(expectation) {
             let lhs = lhs.unwrap();
             assert_eq!(lhs.token, rhs);
         }
@@ -715,7 +1868,7 @@ this.y = 0;
     }
 
     fn validate(s: Scanner, expected: Vec<Token<&str>>) {
-        for (i, (lhs, rhs)) in s.zip(expected.into_iter()).enumerate() {
+        for (i, (lhs, rhs)) in s.zip(expected).enumerate() {
             let lhs = lhs.unwrap();
             println!("{:?}, {:?}", lhs.token, rhs);
             assert_eq!((i, lhs.token), (i, rhs));
@@ -764,7 +1917,7 @@ this.y = 0;
     }
     #[test]
     fn regex_replace() {
-        let expect = vec![
+        let expect = [
             Token::Ident("ident".into()),
             Token::Punct(Punct::Period),
             Token::Ident("replace".into()),
@@ -781,6 +1934,15 @@ this.y = 0;
         }
     }
 
+    #[test]
+    fn complete_and_truncated() {
+        let items = tokenize_complete("a.b(c, 'd') / 2").unwrap();
+        assert_eq!(items.len(), 11);
+        assert!(items.last().unwrap().is_eof());
+        for js in &["a + 'b", "`a${b", "`a${b}", "/* a", "x = /a"] {
+            assert!(tokenize_complete(js).is_err(), "{}", js);
+        }
+    }
     #[test]
     fn error() {
         let js = "
@@ -793,12 +1955,649 @@ this.y = 0;
                 Ok(_) => (),
                 Err(e) => {
                     assert_eq!(e.line, 3);
-                    assert_eq!(e.column, 17);
+                    assert_eq!(e.column, 13);
                 }
             }
         }
     }
 
+    #[test]
+    fn error_kinds() {
+        fn first_error(js: &str) -> Error {
+            Scanner::new(js)
+                .find_map(Result::err)
+                .unwrap_or_else(|| panic!("expected an error for {:?}", js))
+        }
+        let e = first_error("let x = 'abc");
+        assert_eq!(e.kind, ErrorKind::UnterminatedString);
+        assert_eq!((e.line, e.idx), (1, 8));
+        let e = first_error("let x = \"abc");
+        assert_eq!(e.kind, ErrorKind::UnterminatedString);
+        assert_eq!((e.line, e.idx), (1, 8));
+        let e = first_error("let x = \"abc\n\";");
+        assert_eq!(e.kind, ErrorKind::UnterminatedString);
+        assert_eq!((e.line, e.idx), (1, 8));
+        let e = first_error("x; `abc");
+        assert_eq!(e.kind, ErrorKind::UnterminatedTemplate);
+        assert_eq!(e.idx, 3);
+        let e = first_error("x; `abc${x");
+        assert_eq!(e.kind, ErrorKind::UnterminatedTemplate);
+        assert_eq!(e.idx, 3);
+        let e = first_error("x; `abc${x}def");
+        assert_eq!(e.kind, ErrorKind::UnterminatedTemplate);
+        assert_eq!(e.idx, 3);
+        let e = first_error("`a${ `b${c}d` }e");
+        assert_eq!(e.kind, ErrorKind::UnterminatedTemplate);
+        assert_eq!(e.idx, 0);
+        let e = first_error("a /* abc");
+        assert_eq!(e.kind, ErrorKind::UnterminatedComment);
+        assert_eq!(e.idx, 2);
+        let e = first_error("a = /abc\n/");
+        assert_eq!(e.kind, ErrorKind::UnterminatedRegex);
+        let e = first_error("'\\u{zz}'");
+        assert_eq!(e.kind, ErrorKind::InvalidEscape);
+        let e = first_error("a \u{20ac} b");
+        assert_eq!(e.kind, ErrorKind::UnexpectedChar('\u{20ac}'));
+        assert_eq!(e.idx, 2);
+        let e = first_error("0x");
+        assert_eq!(e.kind, ErrorKind::InvalidNumber);
+        let e = first_error("a = 1);");
+        assert_eq!(e.kind, ErrorKind::Other);
+    }
+
+    #[test]
+    fn ecma_versions() {
+        let scan = |js, version| -> Res<Vec<Token<&str>>> {
+            Scanner::with_ecma_version(js, version)
+                .map(|item| item.map(|item| item.token))
+                .collect()
+        };
+        let err = scan("x = 123n", EcmaVersion::Es2018).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::UnsupportedFeature);
+        assert_eq!(err.idx, 4);
+        for version in &[EcmaVersion::Es2020, EcmaVersion::Es2024] {
+            assert!(scan("x = 123n", *version).is_ok());
+        }
+        assert!(scan("x = 123n", EcmaVersion::default()).is_ok());
+        for (js, min) in &[
+            ("`a`", EcmaVersion::Es2015),
+            ("(a) => a", EcmaVersion::Es2015),
+            ("f(...a)", EcmaVersion::Es2015),
+            ("0b1", EcmaVersion::Es2015),
+            ("/a/u", EcmaVersion::Es2015),
+            ("a ** 2", EcmaVersion::Es2016),
+            ("/a/s", EcmaVersion::Es2018),
+            ("0x1n", EcmaVersion::Es2020),
+            ("1_000n", EcmaVersion::Es2021),
+            ("a ||= b", EcmaVersion::Es2021),
+            ("a?.b", EcmaVersion::Es2020),
+            ("a?.[0]", EcmaVersion::Es2020),
+            ("a ?? b", EcmaVersion::Es2020),
+            ("a ??= b", EcmaVersion::Es2021),
+            ("class A { #a }", EcmaVersion::Es2022),
+            ("/a/dg", EcmaVersion::Es2022),
+            ("#!/usr/bin/env node", EcmaVersion::Es2023),
+            ("/[a--b]/v", EcmaVersion::Es2024),
+        ] {
+            assert!(scan(js, *min).is_ok(), "{} {:?}", js, min);
+            assert!(scan(js, EcmaVersion::Es5).is_err(), "{}", js);
+        }
+        assert!(scan("var a = 017 / 2; /a/gim", EcmaVersion::Es5).is_ok());
+        assert!(scan("a ?.5 : b ? .5 : c", EcmaVersion::Es5).is_ok());
+        assert!(scan("a ??= b", EcmaVersion::Es2020).is_err());
+        // the options can be combined
+        let mut scanner = Scanner::new("<!-- a\nawait 123n");
+        scanner
+            .set_ecma_version(EcmaVersion::Es2018)
+            .set_source_type(SourceType::Module)
+            .set_limits(ScannerLimits {
+                max_total_tokens: 1,
+                ..ScannerLimits::default()
+            });
+        // without HTML comments `<!--` is 3 tokens so the
+        // limit is hit before the version can be checked
+        let err = scanner.find_map(Result::err).unwrap();
+        assert_eq!((err.kind, err.idx), (ErrorKind::LimitExceeded, 1));
+        let mut scanner = Scanner::new("await 123n");
+        scanner
+            .set_ecma_version(EcmaVersion::Es2018)
+            .set_source_type(SourceType::Module);
+        let tokens: Vec<_> = scanner.collect();
+        assert_eq!(
+            tokens[0].as_ref().unwrap().token,
+            Token::Keyword(Keyword::Await("await"))
+        );
+        assert_eq!(
+            tokens[1].as_ref().unwrap_err().kind,
+            ErrorKind::UnsupportedFeature
+        );
+        assert_eq!(
+            scan("var let = yield", EcmaVersion::Es5).unwrap(),
+            vec![
+                Token::Keyword(Keyword::Var("var")),
+                Token::Ident("let".into()),
+                Token::Punct(Punct::Equal),
+                Token::Ident("yield".into()),
+                Token::EoF,
+            ]
+        );
+        assert_eq!(
+            scan("f(await)", EcmaVersion::Es2016).unwrap()[2],
+            Token::Ident("await".into())
+        );
+    }
+    #[test]
+    fn recover_errors() {
+        let js = "a = 'unterminated\nb + 0x;\nc ¬ d) / 2";
+        let mut scanner = Scanner::new(js);
+        scanner.recover_errors(true);
+        let items: Vec<_> = scanner.map(Result::unwrap).collect();
+        let tokens: Vec<_> = items.iter().map(|item| item.token.clone()).collect();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Ident("a".into()),
+                Token::Punct(Punct::Equal),
+                Token::Invalid("'unterminated"),
+                Token::Ident("b".into()),
+                Token::Punct(Punct::Plus),
+                Token::Invalid("0x"),
+                Token::Punct(Punct::SemiColon),
+                Token::Ident("c".into()),
+                Token::Invalid("¬"),
+                Token::Ident("d".into()),
+                Token::Invalid(")"),
+                Token::Punct(Punct::ForwardSlash),
+                Token::Number("2".into()),
+                Token::EoF,
+            ]
+        );
+        assert_eq!(items[2].span, Span::new(4, 17));
+        assert_eq!(items[3].location.start, Position::new(2, 1));
+        assert_eq!(items[9].location.start, Position::new(3, 5));
+        // an unterminated template takes the rest of the text
+        let mut scanner = Scanner::new("a + `b${c} d");
+        scanner.recover_errors(true);
+        let tokens: Vec<_> = scanner.map(|item| item.unwrap().token).collect();
+        assert_eq!(
+            tokens[2],
+            Token::Template(Template::template_head("b", false, false, false))
+        );
+        assert_eq!(tokens[4], Token::Invalid("} d"));
+        assert_eq!(tokens.last(), Some(&Token::EoF));
+        // without recovery the first error still ends the scan
+        assert!(Scanner::new(js).any(|item| item.is_err()));
+        // an unmatched `)` is looked behind like any other invalid text
+        let kinds = |js| {
+            let mut scanner = Scanner::new(js);
+            scanner.recover_errors(true);
+            scanner.map(|item| item.unwrap().kind()).collect::<Vec<_>>()
+        };
+        assert_eq!(kinds("a ) /x/g"), kinds("a ¬ /x/g"));
+        // recovered items still count towards the limits
+        let limits = ScannerLimits {
+            max_total_tokens: 10,
+            ..ScannerLimits::default()
+        };
+        let js = "¬".repeat(1_000);
+        let mut scanner = Scanner::with_limits(&js, limits);
+        scanner.recover_errors(true);
+        let items: Vec<_> = scanner.collect();
+        assert_eq!(items.len(), 11);
+        let e = items.last().unwrap().as_ref().unwrap_err();
+        assert_eq!((e.kind, e.idx), (ErrorKind::LimitExceeded, 20));
+    }
+    #[test]
+    fn kinds_with_spans() {
+        let js = "a /* b */ c // d\n";
+        let kinds: Vec<_> = Scanner::new(js)
+            .map(|item| {
+                let item = item.unwrap();
+                (item.kind(), item.span)
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (TokenKind::Ident, Span::new(0, 1)),
+                (TokenKind::Comment, Span::new(2, 9)),
+                (TokenKind::Ident, Span::new(10, 11)),
+                (TokenKind::Comment, Span::new(12, 16)),
+                (TokenKind::EoF, Span::new(17, 17)),
+            ]
+        );
+        let comments: Vec<_> = kinds
+            .iter()
+            .filter(|(kind, _)| *kind == TokenKind::Comment)
+            .map(|(_, span)| &js[span.range()])
+            .collect();
+        assert_eq!(comments, vec!["/* b */", "// d"]);
+    }
+    #[test]
+    fn comment_content_spans() {
+        let js = "/** doc\n *  text */\n// line\n<!-- a --> b\n--> c\nx";
+        let contents: Vec<_> = Scanner::new(js)
+            .map(Result::unwrap)
+            .filter_map(|item| item.content_span())
+            .map(|span| &js[span.range()])
+            .collect();
+        assert_eq!(contents, vec!["* doc\n *  text ", " line", " a ", ""]);
+        let js = "#!/usr/bin/env node\nx";
+        let item = Scanner::new(js).next().unwrap().unwrap();
+        assert_eq!(item.content_span(), Some(Span::new(2, 19)));
+        let item = Scanner::new(js).nth(1).unwrap().unwrap();
+        assert_eq!(item.content_span(), None);
+    }
+
+    #[test]
+    fn empty_and_whitespace_only() {
+        for (js, line, column) in &[
+            ("", 1, 1),
+            ("   ", 1, 4),
+            ("\n\n", 3, 1),
+            ("\r\n", 2, 1),
+            (" \r\n\t", 2, 2),
+            ("\u{feff}", 1, 1),
+        ] {
+            let items: Vec<_> = Scanner::new(js).map(Result::unwrap).collect();
+            assert_eq!(items.len(), 1, "{:?}", js);
+            let eof = &items[0];
+            assert!(eof.is_eof());
+            assert_eq!(eof.span, Span::new(js.len(), js.len()));
+            assert_eq!(
+                eof.location.start,
+                Position::new(*line, *column),
+                "{:?}",
+                js
+            );
+        }
+    }
+
+    #[test]
+    fn eof_span_at_end() {
+        for js in &[
+            "x",
+            "x  ",
+            "x;\n",
+            "x // c",
+            "x /* c */",
+            "x\r\n\r\n",
+            "`a${b}c`",
+            "/a/g",
+            "#!node",
+            "\u{feff}x\u{2028}",
+            "a = '\u{1F600}'",
+        ] {
+            let mut scanner = Scanner::new(js);
+            let items: Vec<_> = scanner.by_ref().map(Result::unwrap).collect();
+            let eof = items.last().unwrap();
+            assert!(eof.is_eof(), "{:?}", js);
+            assert_eq!(eof.span, Span::new(js.len(), js.len()), "{:?}", js);
+            assert_eq!(eof.location.start, eof.location.end, "{:?}", js);
+            assert!(scanner.next().is_none());
+        }
+        // a look ahead and a recovered error still end there
+        let mut scanner = Scanner::new("x ");
+        scanner.next();
+        let eof = scanner.look_ahead().unwrap().unwrap();
+        assert_eq!(eof.span, Span::new(2, 2));
+        let js = "a = 1 } b";
+        let mut scanner = Scanner::new(js);
+        scanner.recover_errors(true);
+        let eof = scanner.last().unwrap().unwrap();
+        assert!(eof.is_eof());
+        assert_eq!(eof.span, Span::new(js.len(), js.len()));
+    }
+
+    #[test]
+    fn source_types() {
+        fn tokens(js: &str, source_type: SourceType) -> Vec<Token<&str>> {
+            Scanner::with_source_type(js, source_type)
+                .map(|item| item.unwrap().token)
+                .collect()
+        }
+        let script = tokens("await(x); await / 2 / 1", SourceType::Script);
+        assert_eq!(script[0], Token::Ident("await".into()));
+        assert_eq!(script[5], Token::Ident("await".into()));
+        assert_eq!(script[6], Token::Punct(Punct::ForwardSlash));
+        let module = tokens("await(x); await / 2 / 1", SourceType::Module);
+        assert_eq!(module[0], Token::Keyword(Keyword::Await("await")));
+        assert_eq!(module[5], Token::Keyword(Keyword::Await("await")));
+        assert_eq!(module[6], Token::RegEx(RegEx::from_parts(" 2 ", None)));
+        // an async function or arrow can still use it
+        let script = tokens(
+            "async function f() { await x } async x => await x; await",
+            SourceType::Script,
+        );
+        assert_eq!(script[6], Token::Keyword(Keyword::Await("await")));
+        assert_eq!(script[12], Token::Keyword(Keyword::Await("await")));
+        assert_eq!(script[15], Token::Ident("await".into()));
+        // without a source type `await` is always a keyword
+        let unset = crate::tokenize("await(x); await / 2 / 1").unwrap();
+        assert_eq!(&unset[..7], &module[..7]);
+        assert!(tokens("x <!-- y\n", SourceType::Script)[1].is_comment());
+        assert!(Scanner::with_source_type("x <!-- y\n", SourceType::Module)
+            .map(|item| item.unwrap().token)
+            .all(|t| !t.is_comment()));
+    }
+
+    #[test]
+    fn token_stream() {
+        let js = "// a comment
+/* a block
+   comment */
+function f(a, b) {
+    return a.b + /* c */ d; <!-- e
+}";
+        let significant: Vec<_> = TokenStream::new(js)
+            .significant()
+            .map(|item| item.unwrap().token)
+            .collect();
+        assert!(significant.iter().all(|t| !t.is_comment()));
+        assert_eq!(significant.len(), 17);
+        let names: Vec<_> = TokenStream::new(js)
+            .idents()
+            .map(|item| item.unwrap().0.to_string())
+            .collect();
+        assert_eq!(names, vec!["f", "a", "b", "a", "b", "d"]);
+        let puncts: Vec<_> = TokenStream::new("a?.b + c")
+            .puncts()
+            .map(|item| item.unwrap().0)
+            .collect();
+        assert_eq!(puncts, vec![Punct::QuestionMarkDot, Punct::Plus]);
+        let kinds: Vec<_> = TokenStream::from(Scanner::new("x = 1"))
+            .map_tokens(|t| t.kind())
+            .collect::<Res<_>>()
+            .unwrap();
+        assert_eq!(
+            kinds,
+            vec![
+                TokenKind::Ident,
+                TokenKind::Punct,
+                TokenKind::Number,
+                TokenKind::EoF
+            ]
+        );
+        let errors: Vec<_> = TokenStream::new("a 'b").idents().collect();
+        assert!(errors[0].is_ok() && errors[1].is_err(), "{:?}", errors);
+    }
+
+    #[test]
+    fn with_lexemes() {
+        let js = "let x = 1_000e1_0 / /a[/]b/gu";
+        let items: Vec<_> = Scanner::new(js).with_lexemes().collect::<Res<_>>().unwrap();
+        for (item, raw) in &items {
+            assert_eq!(*raw, &js[item.span.range()]);
+        }
+        let (number, raw) = &items[3];
+        assert!(number.token.is_number());
+        assert_eq!(*raw, "1_000e1_0");
+        let (regex, raw) = &items[5];
+        assert!(regex.token.is_regex());
+        assert_eq!(*raw, "/a[/]b/gu");
+        assert!(items.last().unwrap().0.token.is_eof());
+        assert_eq!(items.last().unwrap().1, "");
+    }
+
+    #[test]
+    fn jsx() {
+        fn jsx(js: &str) -> Vec<Item<&str>> {
+            let mut scanner = Scanner::new(js);
+            scanner.jsx(true);
+            scanner.map(Result::unwrap).collect()
+        }
+        let js = "const a = <A.b data-x='1\n2' {...p}>\n  hi {name}!<br/>{c && <b>x</b>}\n</A.b>;\nlet c = a < b > c;";
+        let items = jsx(js);
+        let parts: Vec<_> = items.iter().map(|i| &js[i.span.range()]).collect();
+        assert_eq!(
+            parts,
+            vec![
+                "const", "a", "=", "<", "A", ".", "b", "data-x", "=", "'1\n2'", "{", "...", "p",
+                "}", ">", "\n  hi ", "{", "name", "}", "!", "<", "br", "/>", "{", "c", "&&", "<",
+                "b", ">", "x", "</", "b", ">", "}", "\n", "</", "A", ".", "b", ">", ";", "let",
+                "c", "=", "a", "<", "b", ">", "c", ";", "",
+            ]
+        );
+        assert_eq!(items[7].token, Token::Jsx(Jsx::Name("data-x")));
+        assert_eq!(items[15].token, Token::Jsx(Jsx::Text("\n  hi ")));
+        assert_eq!(items[16].location.start, Position::new(3, 6));
+        assert_eq!(items[35].location.start, Position::new(4, 1));
+        assert!(items[41..].iter().all(|i| !i.token.is_jsx()));
+        // the text between tags keeps its white space
+        let mut scanner = Scanner::new(js);
+        scanner.jsx(true);
+        let rebuilt: String = scanner
+            .lossless()
+            .map(|r| {
+                let (trivia, item) = r.unwrap();
+                format!("{}{}", trivia, item.token.to_source())
+            })
+            .collect();
+        assert_eq!(rebuilt, js);
+
+        let tokens: Vec<_> = jsx("return <></>").into_iter().map(|i| i.token).collect();
+        assert_eq!(
+            tokens[1..],
+            [
+                Token::Jsx(Jsx::TagStart),
+                Token::Jsx(Jsx::TagEnd),
+                Token::Jsx(Jsx::ClosingTagStart),
+                Token::Jsx(Jsx::TagEnd),
+                Token::EoF,
+            ]
+        );
+        let tokens: Vec<_> = jsx("<a>{/* c */}</a> / 2")
+            .into_iter()
+            .map(|i| i.token)
+            .collect();
+        assert!(tokens[4].is_comment());
+        assert_eq!(tokens[9], Token::Punct(Punct::ForwardSlash));
+
+        // a look ahead doesn't lose the element
+        let mut scanner = Scanner::new("<a>b</a>");
+        scanner.jsx(true);
+        scanner.by_ref().take(3).for_each(drop);
+        let ahead = scanner.look_ahead().unwrap().unwrap();
+        assert_eq!(ahead.token, Token::Jsx(Jsx::Text("b")));
+        assert_eq!(scanner.next().unwrap().unwrap(), ahead);
+
+        let mut scanner = Scanner::new("<div>");
+        scanner.jsx(true);
+        assert!(scanner.find_map(Result::err).is_some());
+    }
+
+    #[test]
+    fn strict_mode() {
+        fn strict(js: &str) -> Res<Vec<Token<&str>>> {
+            let mut scanner = Scanner::new(js);
+            scanner.strict_mode(true);
+            scanner.map(|item| item.map(|i| i.token)).collect()
+        }
+        for js in &[
+            "0777",
+            "x = 08",
+            "009.5",
+            r#""\12""#,
+            r#"'a\0\1'"#,
+            r#""\8""#,
+        ] {
+            assert!(crate::tokenize(js).is_ok(), "{}", js);
+            assert!(strict(js).is_err(), "{}", js);
+        }
+        let e = strict("x = 0777").unwrap_err();
+        assert_eq!(e.kind, ErrorKind::InvalidNumber);
+        assert_eq!((e.line, e.column), (1, 5));
+        let e = strict(r#"x = "\12""#).unwrap_err();
+        assert_eq!(e.kind, ErrorKind::InvalidEscape);
+        for js in &[
+            "0", "0.5", "0e1", "0x17", "0o17", "0b1", "0n", r#""\0""#, "`\0`",
+        ] {
+            assert!(strict(js).is_ok(), "{}", js);
+        }
+        assert!(Scanner::with_source_type("0777", SourceType::Module)
+            .next()
+            .unwrap()
+            .is_err());
+        assert!(Scanner::with_source_type("0777", SourceType::Script)
+            .next()
+            .unwrap()
+            .is_ok());
+    }
+
+    #[test]
+    fn span_helpers() {
+        let js = "let a = `${b}`;";
+        let items: Vec<_> = Scanner::new(js).map(Result::unwrap).collect();
+        assert_eq!(&js[items[3].span.range()], "`${");
+        assert_eq!(items[3].len(), 3);
+        let span = Span::new(2, 5);
+        assert_eq!(span.len(), 3);
+        assert!(!span.is_empty());
+        assert!(!span.contains(1));
+        assert!(span.contains(2));
+        assert!(span.contains(4));
+        assert!(!span.contains(5));
+        assert!(span.overlaps(&Span::new(4, 8)));
+        assert!(span.overlaps(&Span::new(0, 3)));
+        assert!(span.overlaps(&Span::new(3, 4)));
+        assert!(!span.overlaps(&Span::new(5, 8)));
+        assert!(!span.overlaps(&Span::new(0, 2)));
+
+        let eof = items.last().unwrap();
+        assert!(eof.is_eof());
+        assert!(eof.is_empty());
+        assert_eq!(eof.span.range(), js.len()..js.len());
+        assert_eq!(&js[eof.span.range()], "");
+        let empty = Span::new(3, 3);
+        assert_eq!(empty.len(), 0);
+        assert!(!empty.contains(3));
+        assert!(!empty.overlaps(&span));
+        assert!(!span.overlaps(&empty));
+        assert!(!empty.overlaps(&empty));
+    }
+
+    #[test]
+    fn resume_at_matches_full_scan() {
+        let js = "let a = `x${ {b: `y${c}`} }z`;
+if (a) /re/.test(a) / 2
+function f() { return /[/]/g }
+x = `${`${d}`}` / e / f;
+";
+        let mut scanner = Scanner::new(js);
+        let mut states = Vec::new();
+        let mut items = Vec::new();
+        loop {
+            states.push(scanner.get_state());
+            match scanner.next() {
+                Some(item) => items.push(item.unwrap()),
+                None => break,
+            }
+        }
+        for (i, state) in states.into_iter().enumerate() {
+            let resumed: Vec<_> = Scanner::resume_at(js, state).map(Result::unwrap).collect();
+            assert_eq!(resumed, items[i..], "resumed at token {}", i);
+        }
+        // the text after the state's cursor can change
+        let mut scanner = Scanner::new(js);
+        let target = js.find("/re/").unwrap();
+        while scanner.get_state().manual_state.cursor < target {
+            scanner.next().unwrap().unwrap();
+        }
+        let edited = js.replace("/re/.test(a)", "/[re]/");
+        let resumed: Vec<_> = Scanner::resume_at(&edited, scanner.get_state())
+            .map(|item| item.unwrap().token)
+            .collect();
+        assert_eq!(resumed[0], Token::RegEx(RegEx::from_parts("[re]", None)));
+    }
+
+    #[test]
+    fn zero_width_tokens_are_skipped() {
+        let mut s = Scanner::new("a b");
+        let a = s.next().unwrap().unwrap();
+        assert!(s.check_progress(&a).is_ok());
+        let eof = Item::new(
+            Token::EoF,
+            Span::new(3, 3),
+            SourceLocation::new(Position::new(1, 4), Position::new(1, 4)),
+        );
+        assert!(s.check_progress(&eof).is_ok());
+        let stuck = Item::new(Token::Ident("".into()), Span::new(2, 2), a.location);
+        let e = s.check_progress(&stuck).unwrap_err();
+        assert_eq!((e.kind, e.idx), (ErrorKind::Other, 2));
+        // recovering from a template that is still open at the very
+        // end of the text produces an empty item, which is dropped
+        for js in &["`${", "`${a", "a + `${b}${"] {
+            let mut s = Scanner::new(js);
+            s.recover_errors(true);
+            let items: Vec<_> = s.by_ref().take(10).map(Result::unwrap).collect();
+            assert!(items.last().unwrap().is_eof(), "{}", js);
+            assert!(
+                items
+                    .iter()
+                    .all(|item| item.is_eof() || !item.span.is_empty()),
+                "{}: {:?}",
+                js,
+                items
+            );
+            assert!(s.next().is_none());
+        }
+    }
+
+    #[test]
+    fn limits() {
+        let js = "`${".repeat(10_000);
+        let limits = ScannerLimits {
+            max_template_depth: 100,
+            ..ScannerLimits::default()
+        };
+        let mut s = Scanner::with_limits(&js, limits);
+        for _ in 0..100 {
+            assert!(s.next().unwrap().unwrap().token.is_template_head());
+        }
+        let e = s.next().unwrap().unwrap_err();
+        assert_eq!(e.kind, ErrorKind::LimitExceeded);
+        assert_eq!(e.idx, 300);
+        assert!(s.next().is_none());
+
+        let limits = ScannerLimits {
+            max_token_len: 5,
+            ..ScannerLimits::default()
+        };
+        let e = Scanner::with_limits("let a = 'abcde'", limits)
+            .find_map(Result::err)
+            .unwrap();
+        assert_eq!((e.kind, e.idx), (ErrorKind::LimitExceeded, 8));
+
+        let limits = ScannerLimits {
+            max_total_tokens: 3,
+            ..ScannerLimits::default()
+        };
+        assert!(Scanner::with_limits("a b c", limits).all(|r| r.is_ok()));
+        let mut s = Scanner::with_limits("a b c d", limits);
+        s.next().unwrap().unwrap();
+        let state = s.get_state();
+        s.next().unwrap().unwrap();
+        s.next().unwrap().unwrap();
+        s.set_state(state);
+        s.next().unwrap().unwrap();
+        s.next().unwrap().unwrap();
+        let e = s.next().unwrap().unwrap_err();
+        assert_eq!((e.kind, e.idx), (ErrorKind::LimitExceeded, 6));
+
+        // looking ahead at a token past the limit doesn't end the scanner
+        let limits = ScannerLimits {
+            max_token_len: 3,
+            ..ScannerLimits::default()
+        };
+        let mut s = Scanner::with_limits("a long", limits);
+        s.next().unwrap().unwrap();
+        let e = s.look_ahead().unwrap().unwrap_err();
+        assert_eq!((e.kind, e.idx), (ErrorKind::LimitExceeded, 2));
+        let e = s.next().unwrap().unwrap_err();
+        assert_eq!((e.kind, e.idx), (ErrorKind::LimitExceeded, 2));
+        assert!(s.next().is_none());
+    }
+
     #[test]
     fn locations() {
         let js = r"(function() {
@@ -934,10 +2733,610 @@ ley z = 9;";
         let _one_hundred = s.next().unwrap().unwrap();
     }
     #[test]
+    fn labels_and_keys() {
+        let mut s = Scanner::new("outer: for (;;) { x = { class: 1, b } }");
+        let mut labels = Vec::new();
+        while let Some(item) = s.next() {
+            let item = item.unwrap();
+            if let Token::Ident(ident) = &item.token {
+                if s.followed_by_colon() {
+                    labels.push(ident.to_string());
+                }
+            }
+        }
+        assert_eq!(labels, vec!["outer"]);
+        let names: Vec<_> = Scanner::new("obj.class; a.new; b.c")
+            .filter_map(|item| match item.unwrap().token {
+                Token::Ident(ident) => Some((ident.to_string(), ident.is_reserved_word())),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("obj".to_string(), false),
+                ("class".to_string(), true),
+                ("a".to_string(), false),
+                ("new".to_string(), true),
+                ("b".to_string(), false),
+                ("c".to_string(), false),
+            ]
+        );
+    }
+    #[test]
+    fn debug_state_in_template() {
+        let mut s = Scanner::new("`a${ {b: (c) } / 2 }`");
+        fn next<'a>(s: &mut Scanner<'a>) -> Token<&'a str> {
+            s.next().unwrap().unwrap().token
+        }
+        assert!(next(&mut s).is_template_head());
+        let state = s.debug_state();
+        assert_eq!(state.cursor, 5);
+        assert!(state.in_template());
+        assert!(state.regex_allowed);
+        next(&mut s);
+        next(&mut s);
+        next(&mut s);
+        next(&mut s);
+        let state = s.debug_state();
+        assert_eq!(
+            state.curly_stack,
+            vec![OpenCurlyKind::Template, OpenCurlyKind::Block]
+        );
+        assert_eq!((state.paren_depth, state.brace_depth), (1, 1));
+        next(&mut s);
+        next(&mut s);
+        next(&mut s);
+        let state = s.debug_state();
+        assert_eq!(state.curly_stack, vec![OpenCurlyKind::Template]);
+        assert_eq!((state.paren_depth, state.brace_depth), (0, 0));
+        assert!(!state.regex_allowed, "{:?}", state);
+        assert!(next(&mut s).matches_punct(Punct::ForwardSlash));
+        next(&mut s);
+        assert!(next(&mut s).is_template_tail());
+        let state = s.debug_state();
+        assert!(!state.in_template());
+        assert_eq!(state.cursor, 21);
+    }
+    #[test]
+    fn accessor_keywords() {
+        fn scan(js: &str, enabled: bool) -> Vec<Token<&str>> {
+            let mut s = Scanner::new(js);
+            s.accessor_keywords(enabled);
+            s.map(|i| i.unwrap().token).collect()
+        }
+        let js = "({ get x() {}, set [y](v) {}, get, set: 1, get() {} })";
+        let highlighted = scan(js, true);
+        assert_eq!(highlighted[2], Token::Keyword(Keyword::Get("get")));
+        assert_eq!(highlighted[9], Token::Keyword(Keyword::Set("set")));
+        let accessors = highlighted.iter().filter(|t| t.is_keyword()).count();
+        assert_eq!(accessors, 2, "{:?}", highlighted);
+        assert!(scan(js, false).iter().all(|t| !t.is_keyword()));
+
+        let js = "class A { static get #x() {} a() {} set /* c */ 'b'(v) {} }";
+        let accessors: Vec<_> = scan(js, true)
+            .into_iter()
+            .filter_map(|t| match t {
+                Token::Keyword(k) if k.is_contextual() => Some(k),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            accessors,
+            vec![
+                Keyword::Static("static"),
+                Keyword::Get("get"),
+                Keyword::Set("set")
+            ]
+        );
+
+        let js = "const set = 2; let get = set / get; get\nx";
+        assert_eq!(scan(js, true), scan(js, false));
+        assert!(scan(js, false).iter().all(|t| !matches!(
+            t,
+            Token::Keyword(Keyword::Get(_)) | Token::Keyword(Keyword::Set(_))
+        )));
+    }
+    #[test]
+    fn recent_tokens() {
+        let js = "let a = { b: [1, 2] }; // done\n".repeat(10);
+        let mut s = Scanner::new(&js);
+        assert!(s.recent_tokens(3).is_empty());
+        s.next().unwrap().unwrap();
+        assert_eq!(s.recent_tokens(3), &[Token::Keyword(Keyword::Let("let"))]);
+        for _ in 0..14 {
+            s.next().unwrap().unwrap();
+        }
+        assert_eq!(
+            s.recent_tokens(3),
+            &[
+                Token::Punct(Punct::CloseBrace),
+                Token::Punct(Punct::SemiColon),
+                Token::Keyword(Keyword::Let("let")),
+            ]
+        );
+        let expected: Vec<_> = Scanner::new(&js)
+            .map(|i| i.unwrap().token)
+            .filter(|t| !t.is_comment())
+            .collect();
+        let mut seen: usize = 14;
+        while let Some(item) = s.next() {
+            if !item.unwrap().token.is_comment() {
+                seen += 1;
+            }
+            let start = seen.saturating_sub(RECENT_TOKENS);
+            assert_eq!(s.recent_tokens(100), &expected[start..seen]);
+        }
+        let state = s.get_state();
+        s.set_state(state);
+        assert!(s.recent_tokens(1).is_empty());
+    }
+    #[test]
+    fn on_line_callback() {
+        use std::{cell::RefCell, rc::Rc};
+        let lines = Rc::new(RefCell::new(Vec::new()));
+        let cb_lines = lines.clone();
+        let mut s = Scanner::new("let a = 1;\nlet b = 2;\nlet c = 3;");
+        s.on_line(move |l| cb_lines.borrow_mut().push(l));
+        let _ = s.look_ahead();
+        for item in s {
+            item.unwrap();
+        }
+        assert_eq!(*lines.borrow(), vec![2, 3]);
+    }
+    #[test]
+    fn without_comments() {
+        let js = "// line
+let a = /* block */ 1; // trailing
+/**
+ * doc
+ */
+function /**/ b() {}
+";
+        let all: Vec<_> = Scanner::new(js)
+            .map(Result::unwrap)
+            .filter(|i| !i.token.is_comment())
+            .collect();
+        let significant: Vec<_> = Scanner::new(js)
+            .without_comments()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(significant, all);
+        assert_eq!(significant.len(), 12);
+        assert_eq!(
+            js.get(significant[2].span.start..significant[2].span.end),
+            Some("=")
+        );
+        assert_eq!(significant[3].token, Token::Number("1".into()));
+        assert_eq!(significant[3].location.start, Position::new(2, 21));
+    }
+    #[test]
+    fn comments() {
+        let js = r#"let a = "// not a comment"; // real
+b = `/* nope ${'//'} */` / /\/\*x/ /* also real */"#;
+        let comments: Vec<_> = Scanner::new(js).comments().map(Result::unwrap).collect();
+        assert_eq!(comments.len(), 2);
+        assert_eq!(comments[0].0, Comment::new_single_line(" real"));
+        assert_eq!(&js[comments[0].1.range()], "// real");
+        assert_eq!(comments[1].0, Comment::new_multi_line(" also real "));
+        assert_eq!(&js[comments[1].1.range()], "/* also real */");
+        let mut errored = Scanner::new("// a\n'b").comments();
+        assert!(errored.next().unwrap().is_ok());
+        assert!(errored.next().unwrap().is_err());
+    }
+    #[test]
+    fn source_slices() {
+        let js = "let x = 0x1_F + 1e3 /* c */ ; /a\\/b/gi, `t${'\u{1F600}'}`, 'ü'";
+        let scanner = Scanner::new(js);
+        assert_eq!(scanner.source(), js);
+        let texts: Vec<_> = Scanner::new(js)
+            .map(|item| {
+                let item = item.unwrap();
+                let text = scanner.str_for(&item.span).unwrap();
+                if !item.is_eof() {
+                    assert_eq!(text, item.token.to_string());
+                }
+                text
+            })
+            .collect();
+        assert_eq!(
+            texts,
+            vec![
+                "let",
+                "x",
+                "=",
+                "0x1_F",
+                "+",
+                "1e3",
+                "/* c */",
+                ";",
+                "/a\\/b/gi",
+                ",",
+                "`t${",
+                "'\u{1F600}'",
+                "}`",
+                ",",
+                "'ü'",
+                ""
+            ]
+        );
+        let emoji = js.find('\u{1F600}').unwrap();
+        assert_eq!(scanner.str_for(&Span::new(emoji, emoji + 1)), None);
+        assert_eq!(scanner.str_for(&Span::new(0, js.len() + 1)), None);
+    }
+    #[test]
+    fn tokens_in_matches_full_scan() {
+        let js = "let a = `x${1}y`;\n/* c */ b = a / 2;\nc = /re/g.test(b) ? {} : []";
+        let all = tokenize_with_spans(js).unwrap();
+        for range in [0..4, 5..15, 16..30, 30..31, 48..js.len(), 3..3] {
+            let expected: Vec<_> = all
+                .iter()
+                .filter(|i| i.span.start < range.end && range.start < i.span.end)
+                .cloned()
+                .collect();
+            assert_eq!(
+                Scanner::tokens_in(js, range.clone(), &[]).unwrap(),
+                expected,
+                "{:?}",
+                range
+            );
+        }
+        let re = js.find("/re/").unwrap();
+        let tail = Scanner::tokens_in(js, re..js.len(), &[]).unwrap();
+        assert!(tail[0].token.is_regex(), "{:?}", tail[0]);
+        assert!(Scanner::tokens_in("a = 'b", 0..1, &[]).unwrap().len() == 1);
+        assert!(Scanner::tokens_in("a = 'b", 4..5, &[]).is_err());
+    }
+    #[test]
+    fn tokens_in_from_checkpoint() {
+        let js = "let a = `x${1}y`;\n/* c */ b = a / 2;\nc = /re/g.test(b) ? {\n  d: `${\n  e / f\n}`,\n} : [];\ng = h / i;\n";
+        let all = tokenize_with_spans(js).unwrap();
+        let mut scanner = Scanner::new(js);
+        let mut checkpoints = Vec::new();
+        while let Some(item) = scanner.next() {
+            item.unwrap();
+            checkpoints.push(scanner.get_state());
+        }
+        for start in 0..js.len() {
+            if !js.is_char_boundary(start) {
+                continue;
+            }
+            let range = start..(start + 12).min(js.len());
+            let expected: Vec<_> = all
+                .iter()
+                .filter(|i| i.span.start < range.end && range.start < i.span.end)
+                .cloned()
+                .collect();
+            let items = Scanner::tokens_in(js, range.clone(), &checkpoints).unwrap();
+            assert_eq!(items, expected, "{:?}", range);
+        }
+        // the text before the checkpoint isn't scanned again, so
+        // replacing it with an unterminated string doesn't matter
+        let mid = js.find("d:").unwrap();
+        let state = checkpoints
+            .iter()
+            .rfind(|state| state.manual_state.cursor <= mid)
+            .unwrap();
+        let cursor = state.manual_state.cursor;
+        let mangled = format!("'{}{}", " ".repeat(cursor - 1), &js[cursor..]);
+        let range = mid..js.len();
+        assert!(Scanner::tokens_in(&mangled, range.clone(), &[]).is_err());
+        assert_eq!(
+            Scanner::tokens_in(&mangled, range.clone(), std::slice::from_ref(state)).unwrap(),
+            Scanner::tokens_in(js, range, &[]).unwrap(),
+        );
+    }
+    #[test]
+    fn error_position_after_non_ascii() {
+        for (js, line, column) in [
+            ("é = 'x", 1, 5),
+            ("日本 + '😀' + 'x", 1, 12),
+            ("é\n\"x", 2, 1),
+            ("/* ü */ a.ß = `\n` + 'x", 2, 5),
+            ("'é'\r\nx = 'y\r\n", 2, 5),
+        ] {
+            let e = Scanner::new(js).find_map(Result::err).unwrap();
+            assert_eq!(e.kind, ErrorKind::UnterminatedString, "{:?}", js);
+            assert_eq!((e.line, e.column), (line, column), "{:?}", js);
+            assert!(e.to_string().ends_with(&format!("at {}:{}", line, column)));
+        }
+        let scanner = Scanner::new("é\r\n日本 x");
+        assert_eq!(scanner.position_for(0), (1, 1));
+        assert_eq!(scanner.position_for(2), (1, 2));
+        // both halves of the `\r\n` are the end of line 1
+        assert_eq!(scanner.position_for(3), (1, 2));
+        assert_eq!(scanner.position_for(4), (2, 1));
+        assert_eq!(scanner.position_for(11), (2, 4));
+        let x = Scanner::new("é\r\n日本 x").nth(2).unwrap().unwrap();
+        assert_eq!(x.location.start, Position::new(2, 4));
+    }
+    #[test]
+    fn tokenize_results() {
+        let tokens = tokenize("a + 'b'").unwrap();
+        assert_eq!(tokens, tokenize_lenient("a + 'b'"));
+        assert_eq!(tokens.last(), Some(&Token::EoF));
+        let err = tokenize("a + 'b").unwrap_err();
+        assert_eq!(err.kind, ErrorKind::UnterminatedString);
+        assert_eq!((err.line, err.column), (1, 5));
+        assert_eq!(
+            tokenize_lenient("a + 'b"),
+            vec![Token::Ident("a".into()), Token::Punct(Punct::Plus)]
+        );
+        assert!(tokenize_lenient("'").is_empty());
+    }
+    #[test]
+    fn for_each_token_stops_early() {
+        let mut calls = 0;
+        for_each_token("let a = [1, 2, 3];", |_| {
+            calls += 1;
+            if calls == 5 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .unwrap();
+        assert_eq!(calls, 5);
+        let mut all = Vec::new();
+        for_each_token("a /re/", |item| {
+            all.push(item);
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+        assert_eq!(all, tokenize_with_spans("a /re/").unwrap());
+        let mut seen = Vec::new();
+        let err = for_each_token("a 'b", |item| {
+            seen.push(item.token);
+            ControlFlow::Continue(())
+        })
+        .unwrap_err();
+        assert_eq!(err.kind, ErrorKind::UnterminatedString);
+        assert_eq!(seen, vec![Token::Ident("a".into())]);
+    }
+    #[test]
+    fn token_at_offsets() {
+        let js = "let abc = `x${ y }`; // c\n";
+        let items = tokenize_with_spans(js).unwrap();
+        let at = |offset| token_at(&items, offset).map(|i| &js[i.span.range()]);
+        assert_eq!(at(0), Some("let"));
+        assert_eq!(at(5), Some("abc"));
+        assert_eq!(at(6), Some("abc"));
+        assert_eq!(at(3), None);
+        assert_eq!(at(7), None);
+        assert_eq!(at(8), Some("="));
+        assert_eq!(at(10), Some("`x${"));
+        assert_eq!(at(15), Some("y"));
+        assert_eq!(at(17), Some("}`"));
+        assert_eq!(at(19), Some(";"));
+        assert_eq!(at(21), Some("// c"));
+        assert_eq!(at(25), None);
+        assert_eq!(at(js.len()), None);
+        assert_eq!(at(1000), None);
+        for offset in 0..js.len() {
+            let expected = items.iter().find(|i| i.span.contains(offset));
+            assert_eq!(token_at(&items, offset), expected, "{}", offset);
+        }
+        assert!(token_at::<&str>(&[], 0).is_none());
+    }
+    #[test]
+    fn tokenize_many_matches_sequential() {
+        let sources = [
+            include_str!("../node_modules/jquery/dist/jquery.js"),
+            include_str!("../node_modules/esprima/dist/esprima.js"),
+            "let a = `${b}` / 2;",
+            "",
+            "let a = '",
+            "x = /[/]/g.test(y)",
+        ];
+        let sequential: Vec<_> = sources.iter().map(|s| tokenize_with_spans(s)).collect();
+        let many = tokenize_many(&sources);
+        assert_eq!(many.len(), sources.len());
+        assert!(many[4].is_err());
+        assert_eq!(many, sequential);
+    }
+    #[test]
+    fn asi_between() {
+        fn check(js: &str, at: usize) -> bool {
+            let items: Vec<_> = tokenize_with_spans(js)
+                .unwrap()
+                .into_iter()
+                .filter(|i| !i.token.is_comment())
+                .collect();
+            needs_asi_between(&items[at], &items[at + 1], js)
+        }
+        assert!(check("return\n5", 0));
+        assert!(!check("return 5", 0));
+        assert!(check("return /* a\n */ 5", 0));
+        assert!(!check("return /* a */ 5", 0));
+        assert!(check("return // a\n5", 0));
+        assert!(check("throw\nx", 0));
+        assert!(check("break\nouter", 0));
+        assert!(check("continue\r\nouter", 0));
+        assert!(check("function *g() { yield\nx }", 6));
+        assert!(check("a\n++b", 0));
+        assert!(check("a\n--b", 0));
+        assert!(!check("a++\nb", 0));
+        assert!(check("a[0]\n++b", 3));
+        assert!(check("f()\n--b", 2));
+        assert!(!check("a =\n++b", 1));
+        assert!(!check("a\n+b", 0));
+        assert!(!check("let\nx", 0));
+    }
+    #[test]
+    fn tokenize_with_spans_covers_input() {
+        let js = "function add(a, b) {\n    return a + b; // sum\n}\nadd(1, 2);\n";
+        let items = tokenize_with_spans(js).unwrap();
+        let tokens = tokenize(js).unwrap();
+        assert_eq!(
+            items.iter().map(|i| i.token.clone()).collect::<Vec<_>>(),
+            tokens
+        );
+        let mut end = 0;
+        for item in &items {
+            assert!(js[end..item.span.start].trim().is_empty());
+            end = item.span.end;
+        }
+        assert_eq!(end, js.len());
+        assert!(items.last().unwrap().is_eof());
+    }
+    /// Assert that the token spans and the trivia
+    /// between them cover all of `js` with no overlap
+    #[track_caller]
+    fn verify_spans(js: &str) {
+        let scanner = Scanner::new(js);
+        let mut covered = 0;
+        let mut prev = Span::new(0, 0);
+        for item in Scanner::new(js) {
+            let item = item.unwrap();
+            let trivia = scanner.trivia_between(&prev, &item.span);
+            assert_eq!(trivia.start, covered, "{:?}", item);
+            covered = trivia.end;
+            assert_eq!(item.span.start, covered, "{:?}", item);
+            covered = item.span.end;
+            prev = item.span;
+        }
+        assert_eq!(covered, js.len());
+    }
+    #[test]
+    fn spans_are_contiguous() {
+        verify_spans("");
+        verify_spans("  \n\t");
+        verify_spans("#!/usr/bin/env node\nlet a = 1;");
+        verify_spans("\u{feff}var\u{a0}x = /re/g;\r\n  x /= 2; // c\n");
+        verify_spans("let t = `a${ {b: `c${d}`} }e`;\n/* a\n b */ <!-- html\n--> close");
+        verify_spans("if (a) { 'b' } else \"c\"\u{2028}x\u{3000}++");
+        verify_spans(include_str!("../node_modules/jquery/dist/jquery.js"));
+    }
+    #[test]
+    fn html_comments_disabled() {
+        let js = "a <!-- b\n--> c";
+        let tokens = tokenize(js).unwrap();
+        assert!(tokens[1].is_comment());
+        assert!(tokens[2].is_comment());
+        let mut s = Scanner::new(js);
+        s.allow_html_comments(false);
+        let tokens: Vec<_> = s.map(|i| i.unwrap().token).collect();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Ident("a".into()),
+                Token::Punct(Punct::LessThan),
+                Token::Punct(Punct::Bang),
+                Token::Punct(Punct::DoubleDash),
+                Token::Ident("b".into()),
+                Token::Punct(Punct::DoubleDash),
+                Token::Punct(Punct::GreaterThan),
+                Token::Ident("c".into()),
+                Token::EoF,
+            ]
+        );
+    }
+    #[test]
+    fn misjudged_regex_is_division() {
+        let items: Vec<_> = Scanner::new("yield / 2\nlet\n/2")
+            .collect::<Res<_>>()
+            .unwrap();
+        let divs: Vec<_> = items
+            .iter()
+            .filter(|item| item.token.matches_punct(Punct::ForwardSlash))
+            .map(|item| (item.span.start, item.location.start.line))
+            .collect();
+        assert_eq!(divs, vec![(6, 1), (14, 3)]);
+        assert!(items.iter().all(|item| !item.token.is_regex()));
+        assert_eq!(items[items.len() - 2].token, Token::Number("2".into()));
+        // the fallback still produces a regex when there is one
+        let re = Scanner::new("yield /2/").nth(1).unwrap().unwrap();
+        assert!(re.token.is_regex(), "{:?}", re);
+        // and still reports one that can't be division
+        assert!(Scanner::new("x = /2").any(|item| item.is_err()));
+    }
+    #[test]
     fn keyword_regex() {
         let mut s = Scanner::new("break /a/");
         let _break = s.next().unwrap().unwrap();
         let re = s.next().unwrap().unwrap();
         assert!(re.token.is_regex(), "regex was not a regex: {:?}", re);
     }
+    #[test]
+    fn expression_start_regex() {
+        for (js, idx) in &[
+            ("x => /a/.test(x)", 2),
+            ("async () => /a/", 4),
+            ("function* g(){ yield /a/ }", 7),
+            ("async function f() { await /a/ }", 7),
+            ("typeof /a/", 1),
+        ] {
+            let tokens = tokenize(js).unwrap();
+            assert_eq!(
+                tokens[*idx],
+                Token::RegEx(RegEx::from_parts("a", None)),
+                "{}",
+                js
+            );
+        }
+    }
+    #[test]
+    fn function_expression_division() {
+        for js in &[
+            "x = function(){} / 2",
+            "x = function f(){} / 2",
+            "(function(){} / 2)",
+            "[function(){} / 2]",
+            "x => function(){} / 2",
+            "function* g() { yield function(){} / 2 }",
+            "async function g() { await function(){} / 2 }",
+        ] {
+            let tokens = tokenize(js).unwrap();
+            assert!(
+                tokens.contains(&Token::Punct(Punct::ForwardSlash)),
+                "{}: {:?}",
+                js,
+                tokens
+            );
+        }
+    }
+    #[test]
+    fn set_state_restores_braces() {
+        let mut s = Scanner::new("{ } }");
+        let state = s.get_state();
+        let _open = s.next().unwrap().unwrap();
+        s.set_state(state);
+        let _open = s.next().unwrap().unwrap();
+        let _close = s.next().unwrap().unwrap();
+        assert!(s.next().unwrap().is_err());
+    }
+    #[test]
+    fn set_state_regex_after_block() {
+        let js = "if (a) { b } /c/";
+        let expected = tokenize(js).unwrap();
+        let mut s = Scanner::new(js);
+        let mut tokens = Vec::new();
+        loop {
+            // rewind after every token to ensure the
+            // look behind is the same as scanning straight through
+            let state = s.get_state();
+            let _ = s.next();
+            s.set_state(state);
+            let item = s.next().unwrap().unwrap();
+            let done = item.is_eof();
+            tokens.push(item.token);
+            if done {
+                break;
+            }
+        }
+        assert_eq!(tokens, expected);
+    }
+    #[test]
+    fn unary_keyword_regex() {
+        for (js, body) in &[("typeof /a/", "a"), ("void /b/", "b"), ("delete /c/", "c")] {
+            let tokens = tokenize(js).unwrap();
+            assert_eq!(
+                tokens[1],
+                Token::RegEx(RegEx::from_parts(*body, None)),
+                "{}",
+                js
+            );
+            assert!(tokens[2].is_eof(), "{}", js);
+        }
+    }
 }
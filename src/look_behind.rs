@@ -1,6 +1,5 @@
 use crate::tokenizer::RawKeyword;
 use crate::tokens::Punct;
-use std::rc::Rc;
 
 /// A 2 element buffer of
 /// MetaTokens, this will use a
@@ -74,6 +73,12 @@ pub enum MetaToken {
     CloseParen(Paren),
     OpenBrace(Brace, u32),
     CloseBrace(Brace),
+    /// A template head or middle, these end
+    /// with the `${` of a substitution
+    TemplateOpen,
+    /// An `of` in the head of a `for` loop, this
+    /// is an operator like `in`
+    Of,
     Ident,
     Other,
 }
@@ -114,34 +119,12 @@ impl<T> From<(&crate::Token<T>, u32)> for MetaToken {
             crate::Token::Keyword(k) => MetaToken::Keyword(k.into(), line),
             crate::Token::Punct(p) => MetaToken::Punct(*p),
             crate::Token::Ident(_) => MetaToken::Ident,
+            crate::Token::Template(t) if t.is_head() || t.is_middle() => MetaToken::TemplateOpen,
             _ => MetaToken::Other,
         }
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct OpenBrace {
-    pub look_behind: LookBehind,
-    pub parent: Option<Rc<OpenBrace>>,
-}
-
-#[derive(Debug, Clone)]
-pub struct CloseBrace {
-    pub open: Rc<OpenBrace>,
-}
-
-#[derive(Debug, Clone)]
-pub struct CloseParen {
-    pub open: LookBehind,
-}
-
-impl std::ops::Deref for OpenBrace {
-    type Target = LookBehind;
-    fn deref(&self) -> &Self::Target {
-        &self.look_behind
-    }
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
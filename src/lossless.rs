@@ -0,0 +1,35 @@
+//! Pairing every token with the white space
+//! in front of it so no byte of the source is lost
+use crate::{Item, Res, Scanner};
+
+/// An iterator over the items of a `Scanner` along with
+/// the white space directly before each one, see
+/// `Scanner::lossless`
+pub struct Lossless<'a> {
+    scanner: Scanner<'a>,
+    text: &'a str,
+    last_end: usize,
+}
+
+impl<'a> Lossless<'a> {
+    pub(crate) fn new(scanner: Scanner<'a>) -> Self {
+        Self {
+            text: scanner.source(),
+            scanner,
+            last_end: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for Lossless<'a> {
+    type Item = Res<(&'a str, Item<&'a str>)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = match self.scanner.next()? {
+            Ok(item) => item,
+            Err(e) => return Some(Err(e)),
+        };
+        let trivia = &self.text[self.last_end..item.span.start];
+        self.last_end = item.span.end;
+        Some(Ok((trivia, item)))
+    }
+}
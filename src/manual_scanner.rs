@@ -1,8 +1,8 @@
 use crate::{
-    error::{Error, RawError},
+    error::{Error, ErrorKind, RawError},
     tokenizer::{self, RawToken, Tokenizer},
     tokens::{self, prelude::*},
-    Item, Span,
+    Item, OpenCurlyKind, Span,
 };
 
 type Res<T> = Result<T, Error>;
@@ -23,20 +23,32 @@ pub struct ManualScanner<'a> {
 impl<'b> ManualScanner<'b> {
     pub fn new(text: &'b str) -> Self {
         let mut stream = Tokenizer::new(text);
-        let (new_line_count, line_cursor) = stream.skip_whitespace();
+        let (new_line_count, leading_whitespace) = stream.skip_whitespace();
+        // on the first line nothing accounts for the
+        // column the line starts at
+        let line_cursor = if new_line_count == 0 {
+            leading_whitespace + 1
+        } else {
+            leading_whitespace
+        };
         Self {
+            last_skipped_whitespace: stream.stream.idx,
             stream,
             eof: false,
             pending_new_line: false,
             original: text,
             errored: false,
             new_line_count,
-            line_cursor: usize::max(line_cursor, 1),
+            line_cursor,
             at_first_on_line: true,
-            last_skipped_whitespace: line_cursor,
         }
     }
 
+    /// Enable or disable HTML-like comments
+    /// (`<!--` and `-->`), enabled by default
+    pub fn allow_html_comments(&mut self, allow: bool) {
+        self.stream.allow_html_comments(allow);
+    }
     /// Skip any upcoming comments to get the
     /// next valid js token
     pub fn skip_comments(&mut self) -> Res<()> {
@@ -59,6 +71,9 @@ impl<'b> ManualScanner<'b> {
             new_line_count: self.new_line_count,
             line_cursor: self.line_cursor,
             at_first_on_line: self.at_first_on_line,
+            eof: self.eof,
+            curly_stack: self.stream.curly_stack.clone(),
+            template_starts: self.stream.template_starts.clone(),
         }
     }
     /// Set the scanner's current state to the state provided
@@ -68,15 +83,32 @@ impl<'b> ManualScanner<'b> {
         self.new_line_count = state.new_line_count;
         self.line_cursor = state.line_cursor;
         self.at_first_on_line = state.at_first_on_line;
+        self.eof = state.eof;
+        self.stream.curly_stack = state.curly_stack;
+        self.stream.template_starts = state.template_starts;
     }
 
     pub fn next_token(&mut self) -> Ret<'b> {
+        self.next_raw(false)
+    }
+    /// Get the next token as the `<` that starts a JSX
+    /// element, the next character should be a `<`
+    pub fn next_jsx_tag(&mut self) -> Ret<'b> {
+        self.next_raw(true)
+    }
+
+    fn next_raw(&mut self, jsx_tag: bool) -> Ret<'b> {
         if self.eof {
             debug!("end of iterator, returning None");
             return None;
         };
         let (_, prev_lines, prev_line_cursor) = self.capture_cursors();
-        let next = match self.stream.next(self.at_first_on_line) {
+        let next = if jsx_tag {
+            self.stream.next_jsx_tag()
+        } else {
+            self.stream.next(self.at_first_on_line)
+        };
+        let next = match next {
             Ok(n) => n,
             Err(e) => {
                 self.errored = true;
@@ -84,7 +116,7 @@ impl<'b> ManualScanner<'b> {
             }
         };
 
-        let mut len = next.end - next.start;
+        let mut len = self.original[next.start..next.end].chars().count();
         let ret = {
             let mut new_lines = 0;
             let s = &self.original[next.start..next.end];
@@ -96,7 +128,13 @@ impl<'b> ManualScanner<'b> {
                     last_len,
                     end_index,
                 } => {
-                    len = last_len;
+                    // only a multi-line comment tracks its length,
+                    // the others never include a new line
+                    len = if kind == tokens::CommentKind::Multi {
+                        last_len
+                    } else {
+                        s.chars().count()
+                    };
                     new_lines = new_line_count;
                     match kind {
                         tokens::CommentKind::Multi => {
@@ -107,9 +145,7 @@ impl<'b> ManualScanner<'b> {
                                 } else {
                                     (None, s.len())
                                 };
-                            let content = s[..tail_start]
-                                .trim_start_matches("/*")
-                                .trim_end_matches("*/");
+                            let content = &s[2..tail_start - 2];
                             Token::Comment(Comment {
                                 kind: tokens::CommentKind::Multi,
                                 content,
@@ -117,7 +153,7 @@ impl<'b> ManualScanner<'b> {
                             })
                         }
                         tokens::CommentKind::Single => {
-                            Token::Comment(Comment::new_single_line(s.trim_start_matches("//")))
+                            Token::Comment(Comment::new_single_line(&s[2..]))
                         }
                         tokens::CommentKind::Html => {
                             let start_idx = if s.starts_with("<!--") { 4 } else { 0 };
@@ -125,7 +161,7 @@ impl<'b> ManualScanner<'b> {
                                 let actual_end = idx.saturating_add(3);
                                 if actual_end < next.end {
                                     let tail = &s[actual_end..];
-                                    let tail = if tail == "" { None } else { Some(tail) };
+                                    let tail = if tail.is_empty() { None } else { Some(tail) };
                                     (&s[start_idx..idx], tail)
                                 } else {
                                     (&s[start_idx..], None)
@@ -135,13 +171,15 @@ impl<'b> ManualScanner<'b> {
                             };
                             if start_idx == 0 && !self.at_first_on_line(next.start) {
                                 self.errored = true;
+                                let (line, column) = self.position_for(next.start);
                                 return Some(Err(Error {
-                                    line: self.new_line_count,
-                                    column: self.line_cursor,
+                                    kind: ErrorKind::Other,
+                                    line,
+                                    column,
                                     msg: "--> comments must either be a part of a full HTML \
                                           comment or the first item on a new line"
                                         .to_string(),
-                                    idx: start_idx,
+                                    idx: next.start,
                                 }));
                             }
                             Token::Comment(Comment::new_html(content, tail))
@@ -163,6 +201,22 @@ impl<'b> ManualScanner<'b> {
                         self.line_cursor,
                     )));
                 }
+                RawToken::Jsx {
+                    kind,
+                    new_line_count,
+                    last_len,
+                } => {
+                    len = last_len;
+                    new_lines = new_line_count;
+                    Token::Jsx(match kind {
+                        tokenizer::JsxKind::TagStart => Jsx::TagStart,
+                        tokenizer::JsxKind::ClosingTagStart => Jsx::ClosingTagStart,
+                        tokenizer::JsxKind::TagEnd => Jsx::TagEnd,
+                        tokenizer::JsxKind::SelfClosingTagEnd => Jsx::SelfClosingTagEnd,
+                        tokenizer::JsxKind::Name => Jsx::Name(s),
+                        tokenizer::JsxKind::Text => Jsx::Text(s),
+                    })
+                }
                 RawToken::Ident => Token::Ident(Ident::from(s)),
                 RawToken::Keyword(k) => Token::Keyword(k.with_str(s)),
                 RawToken::Null => Token::Null,
@@ -249,16 +303,21 @@ impl<'b> ManualScanner<'b> {
                 self.line_cursor,
             )
         };
+        let whitespace_start = self.stream.stream.idx;
         let (new_line_count, leading_whitespace) = self.stream.skip_whitespace();
         self.bump_line_cursors(new_line_count, leading_whitespace);
         self.pending_new_line = new_line_count > 0;
-        self.last_skipped_whitespace = leading_whitespace;
+        self.last_skipped_whitespace = self.stream.stream.idx - whitespace_start;
         Some(Ok(ret))
     }
     /// Get the next token as a regular expression. The previous token
     /// should have been `/` or `/=`,
     pub fn next_regex(&mut self, prev_len: usize) -> Option<Res<Item<&'b str>>> {
-        let (_, prev_lines, prev_line_cursor) = self.capture_cursors();
+        let (idx, prev_lines, prev_line_cursor) = self.capture_cursors();
+        let skipped = self.original[idx - self.last_skipped_whitespace..idx]
+            .chars()
+            .count();
+        let start_column = prev_line_cursor.saturating_sub(prev_len + skipped);
         self.stream
             .stream
             .skip_back_bytes(self.last_skipped_whitespace);
@@ -271,8 +330,8 @@ impl<'b> ManualScanner<'b> {
         };
         let ret = match next.ty {
             RawToken::RegEx(body_end) => {
-                self.line_cursor = self.line_cursor.saturating_sub(prev_len);
-                self.line_cursor = self.line_cursor.saturating_add(next.end - next.start);
+                self.line_cursor =
+                    start_column + self.original[next.start..next.end].chars().count();
                 let flags = if next.end > body_end {
                     Some(&self.original[body_end..next.end])
                 } else {
@@ -286,15 +345,12 @@ impl<'b> ManualScanner<'b> {
                     next.start,
                     next.end,
                     prev_lines + 1,
-                    prev_line_cursor.saturating_sub(prev_len),
+                    start_column,
                     prev_lines + 1,
                     self.line_cursor,
                 )
             }
-            _ => {
-                todo!();
-                // Some(self.error(todo!()))
-            }
+            _ => unreachable!("non-regex from next_regex"),
         };
         let (new_line_count, leading_whitespace) = self.stream.skip_whitespace();
         self.bump_line_cursors(new_line_count, leading_whitespace);
@@ -302,6 +358,39 @@ impl<'b> ManualScanner<'b> {
         Some(Ok(ret))
     }
 
+    /// Produce an `Invalid` item for the text from the cursor
+    /// up to `end` and move past it, this is how the `Scanner`
+    /// keeps going after an error
+    pub(crate) fn skip_invalid(&mut self, end: usize) -> Item<&'b str> {
+        let (start, prev_lines, prev_line_cursor) = self.capture_cursors();
+        let text = &self.original[start..end];
+        let (new_lines, len) = crate::line_extent(text);
+        self.stream.stream.idx = end;
+        if end == self.original.len() {
+            // anything still open will never be closed
+            self.stream.curly_stack.clear();
+            self.stream.template_starts.clear();
+        }
+        self.errored = false;
+        self.at_first_on_line = false;
+        self.bump_line_cursors(new_lines, len);
+        let ret = Item::new_(
+            Token::Invalid(text),
+            start,
+            end,
+            prev_lines.saturating_add(1),
+            prev_line_cursor,
+            self.new_line_count.saturating_add(1),
+            self.line_cursor,
+        );
+        let whitespace_start = self.stream.stream.idx;
+        let (new_line_count, leading_whitespace) = self.stream.skip_whitespace();
+        self.bump_line_cursors(new_line_count, leading_whitespace);
+        self.pending_new_line = new_line_count > 0;
+        self.last_skipped_whitespace = self.stream.stream.idx - whitespace_start;
+        ret
+    }
+
     fn capture_cursors(&self) -> (usize, usize, usize) {
         (
             self.stream.stream.idx,
@@ -316,43 +405,30 @@ impl<'b> ManualScanner<'b> {
     }
     /// Get a &str for any given span
     pub fn str_for(&self, span: &Span) -> Option<&'b str> {
-        if self.original.len() < span.start || self.original.len() < span.end {
-            None
-        } else {
-            Some(&self.original[span.start..span.end])
-        }
+        self.original.get(span.start..span.end)
     }
-    /// Get the line/column pair for any given byte index
+    /// Get the 1 based line and column for any given byte
+    /// index, a `\r\n` is a single line terminator
     pub fn position_for(&self, idx: usize) -> (usize, usize) {
-        let mut line_ct = 1;
-        // This is the byte position, not the character
-        // position to account for multi byte chars
-        let mut byte_position = 0;
-        // loop over the characters
-        for (i, c) in self.original.chars().enumerate() {
+        let mut line = 1;
+        let mut column = 1;
+        let mut chars = self.original.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
             if i >= idx {
-                return (line_ct, byte_position);
+                break;
+            }
+            if c == '\r' && matches!(chars.peek(), Some((_, '\n'))) {
+                // the `\n` will count the new line
+                continue;
+            }
+            if crate::is_line_term(c) {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
             }
-            match c {
-                '\r' => {
-                    // look ahead 1 char to see if it is a newline pair
-                    // if so, don't include it, it will get included in the next
-                    // iteration
-                    if let Some(next) = self.original.get(byte_position..byte_position + 2) {
-                        if next != "\r\n" {
-                            line_ct += 1;
-                            byte_position = 0;
-                        }
-                    }
-                }
-                '\n' | '\u{2028}' | '\u{2029}' => {
-                    line_ct += 1;
-                    byte_position = 0;
-                }
-                _ => byte_position += c.len_utf8(),
-            };
         }
-        (line_ct, byte_position)
+        (line, column)
     }
     #[inline]
     /// Helper to handle new lines
@@ -368,19 +444,18 @@ impl<'b> ManualScanner<'b> {
     #[inline]
     fn at_first_on_line(&self, token_start: usize) -> bool {
         trace!("at_first_on_line");
-        if self.line_cursor <= 1 {
-            return true;
-        }
-        let start = token_start.saturating_sub(self.line_cursor - 1);
-        let prefix = &self.original[start..token_start];
-        trace!("prefix: {:?}", prefix);
-        prefix.chars().all(|c| c.is_whitespace())
+        self.original[..token_start]
+            .chars()
+            .rev()
+            .take_while(|c| !crate::is_line_term(*c))
+            .all(crate::is_whitespace)
     }
     /// Helper to handle the error cases
     fn error<T>(&self, raw_error: RawError) -> Res<T> {
-        let RawError { idx, msg } = &raw_error;
+        let RawError { kind, idx, msg } = &raw_error;
         let (line, column) = self.position_for(*idx);
         Err(Error {
+            kind: *kind,
             line,
             column,
             msg: msg.clone(),
@@ -398,4 +473,9 @@ pub struct ScannerState {
     pub new_line_count: usize,
     pub line_cursor: usize,
     pub at_first_on_line: bool,
+    pub eof: bool,
+    /// The tokenizer's open `{`, `${` and JSX elements, so
+    /// a `}` closes the right one
+    pub curly_stack: Vec<OpenCurlyKind>,
+    pub template_starts: Vec<usize>,
 }
@@ -0,0 +1,101 @@
+//! Line/column positions measured the way
+//! browser dev tools report them
+use crate::{tokens::Token, Position, Res, Scanner};
+
+/// An iterator over the tokens of a `Scanner` along with their
+/// start and end `Position`, see `Scanner::with_positions`
+pub struct WithPositions<'a> {
+    scanner: Scanner<'a>,
+    text: &'a str,
+    cursor: Cursor,
+}
+
+impl<'a> WithPositions<'a> {
+    pub(crate) fn new(scanner: Scanner<'a>) -> Self {
+        Self {
+            text: scanner.source(),
+            scanner,
+            cursor: Cursor::default(),
+        }
+    }
+}
+
+impl<'a> Iterator for WithPositions<'a> {
+    type Item = Res<(Token<&'a str>, Position, Position)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = match self.scanner.next()? {
+            Ok(item) => item,
+            Err(e) => return Some(Err(e)),
+        };
+        let start = self.cursor.advance(self.text, item.span.start);
+        let end = self.cursor.advance(self.text, item.span.end);
+        Some(Ok((item.token, start, end)))
+    }
+}
+
+/// The last position that was calculated, since tokens
+/// only move forward each byte of the text is only
+/// visited once
+struct Cursor {
+    idx: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Default for Cursor {
+    fn default() -> Self {
+        Self {
+            idx: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+}
+
+impl Cursor {
+    /// Move forward to the byte index `idx`, columns are
+    /// counted in UTF-16 code units and a `\r\n` pair
+    /// is a single line terminator
+    fn advance(&mut self, text: &str, idx: usize) -> Position {
+        let mut chars = text[self.idx..idx].chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '\r' && chars.peek() == Some(&'\n') {
+                continue;
+            }
+            if crate::is_line_term(c) {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += c.len_utf16();
+            }
+        }
+        self.idx = idx;
+        Position::new(self.line, self.column)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tokens::Punct;
+
+    #[test]
+    fn multi_line_positions() {
+        let js = "function f() {\r\n  return '\u{1F600}'\n    + x;\u{2028}}";
+        let positions: Vec<_> = Scanner::new(js)
+            .with_positions()
+            .map(Result::unwrap)
+            .collect();
+        let (token, start, end) = &positions[8];
+        assert_eq!(token, &Token::Ident("x".into()));
+        assert_eq!(*start, Position::new(3, 7));
+        assert_eq!(*end, Position::new(3, 8));
+        // the emoji is 2 UTF-16 code units
+        let (_, start, end) = &positions[6];
+        assert_eq!(*start, Position::new(2, 10));
+        assert_eq!(*end, Position::new(2, 14));
+        let (token, start, _) = &positions[10];
+        assert_eq!(token, &Token::Punct(Punct::CloseBrace));
+        assert_eq!(*start, Position::new(4, 1));
+    }
+}
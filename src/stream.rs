@@ -0,0 +1,87 @@
+//! A wrapper around a `Scanner` with the common
+//! ways of filtering its items
+use crate::{
+    tokens::{Ident, Punct, Token},
+    Item, Res, Scanner, Span,
+};
+
+/// The items of a `Scanner` along with adapters for the
+/// filtering that most users of this crate end up writing
+/// by hand. Errors are always passed through so none of
+/// these can hide a problem with the text.
+///
+/// ```
+/// # use ress::TokenStream;
+/// let names: Vec<_> = TokenStream::new("let a = b /* c */ + 1")
+///     .idents()
+///     .map(|item| item.unwrap().0.to_string())
+///     .collect();
+/// assert_eq!(names, vec!["a", "b"]);
+/// ```
+pub struct TokenStream<'a> {
+    scanner: Scanner<'a>,
+}
+
+impl<'a> TokenStream<'a> {
+    /// Create a stream with a default `Scanner`, use
+    /// `From<Scanner>` to wrap one that has been configured
+    pub fn new(text: &'a str) -> Self {
+        Scanner::new(text).into()
+    }
+    /// Get the `Scanner` back out of this stream
+    pub fn into_inner(self) -> Scanner<'a> {
+        self.scanner
+    }
+    /// Every item that isn't a comment, see
+    /// `Scanner::without_comments`
+    pub fn significant(self) -> impl Iterator<Item = Res<Item<&'a str>>> {
+        self.scanner.without_comments()
+    }
+    /// Only the punctuation and their spans
+    pub fn puncts(self) -> impl Iterator<Item = Res<(Punct, Span)>> + 'a {
+        self.filter_map(|item| match item {
+            Ok(Item {
+                token: Token::Punct(p),
+                span,
+                ..
+            }) => Some(Ok((p, span))),
+            Ok(_) => None,
+            Err(e) => Some(Err(e)),
+        })
+    }
+    /// Only the identifiers and their spans, keywords
+    /// aren't included even when they're used as a name
+    /// (`x.if`)
+    pub fn idents(self) -> impl Iterator<Item = Res<(Ident<&'a str>, Span)>> {
+        self.filter_map(|item| match item {
+            Ok(Item {
+                token: Token::Ident(i),
+                span,
+                ..
+            }) => Some(Ok((i, span))),
+            Ok(_) => None,
+            Err(e) => Some(Err(e)),
+        })
+    }
+    /// Apply `f` to the token of every item, dropping
+    /// the spans and locations
+    pub fn map_tokens<U, F>(self, mut f: F) -> impl Iterator<Item = Res<U>> + 'a
+    where
+        F: FnMut(Token<&'a str>) -> U + 'a,
+    {
+        self.map(move |item| item.map(|item| f(item.token)))
+    }
+}
+
+impl<'a> From<Scanner<'a>> for TokenStream<'a> {
+    fn from(scanner: Scanner<'a>) -> Self {
+        Self { scanner }
+    }
+}
+
+impl<'a> Iterator for TokenStream<'a> {
+    type Item = Res<Item<&'a str>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.scanner.next()
+    }
+}
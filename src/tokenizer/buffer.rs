@@ -172,9 +172,10 @@ impl<'a> JSBuffer<'a> {
             || self.buffer[self.idx] == 13 // \r
             || self.buffer[self.idx] == 32 // ' '
             || (self.buffer[self.idx] == 194 && self.idx + 1 < self.len && self.buffer[self.idx+1] == 160)
-            || (self.buffer[self.idx] >= 226 && self.buffer[self.idx] <= 239 && self.len > self.idx + 2 && {
+            || (self.buffer[self.idx] >= 225 && self.buffer[self.idx] <= 239 && self.len > self.idx + 2 && {
                 match &self.buffer[self.idx..self.idx+3] {
                     [239, 187, 191] //"\u{feff}",
+                    | [225, 154, 128] //"\u{1680}",
                     | [226, 128, 168] //"\u{2028}",
                     | [226, 128, 169] //"\u{2029}",
                     | [226, 128, 128] //"\u{2000}",
@@ -281,7 +282,7 @@ impl<'a> JSBuffer<'a> {
 }
 
 impl<'a> From<&'a str> for JSBuffer<'a> {
-    fn from(s: &'a str) -> JSBuffer {
+    fn from(s: &'a str) -> JSBuffer<'a> {
         Self::new(s.as_bytes())
     }
 }
@@ -338,6 +339,7 @@ mod test {
             32, // ' '
             194, 160, //\u{00A0}
             239, 187, 191, // \u{FEFF}
+            225, 154, 128, // \u{1680}
             226, 128, 168, // \u{2028}
             226, 128, 169, // \u{2029}
             226, 128, 128, // \u{2000}
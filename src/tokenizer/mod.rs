@@ -1,11 +1,11 @@
-use crate::tokens::{CommentKind, NumberKind, Punct};
-use crate::{is_line_term, OpenCurlyKind};
+use crate::tokens::{escape_sequence, CommentKind, Escape, NumberKind, Punct};
+use crate::{is_line_term, line_extent, JsxContext, OpenCurlyKind};
 mod buffer;
 
 mod tokens;
 mod unicode;
-pub use self::tokens::{RawKeyword, RawToken, StringKind, TemplateKind};
-use crate::error::RawError;
+pub use self::tokens::{JsxKind, RawKeyword, RawToken, StringKind, TemplateKind};
+use crate::error::{ErrorKind, RawError};
 pub(crate) type Res<T> = Result<T, RawError>;
 pub use buffer::JSBuffer;
 use log::trace;
@@ -22,38 +22,79 @@ pub struct RawItem {
     pub end: usize,
 }
 
+/// The UTF-8 encoded byte order mark
+const BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+
 /// This structure will perform the low level
 /// tokenization before the `Scanner` provides
 /// additional context
 pub struct Tokenizer<'a> {
     pub(super) stream: buffer::JSBuffer<'a>,
+    /// The same text as `stream`, for the parts
+    /// that need to look at whole characters
+    text: &'a str,
     pub(super) current_start: usize,
     pub(super) curly_stack: Vec<OpenCurlyKind>,
+    /// The start of each template literal that
+    /// is waiting for its closing back tick
+    pub(super) template_starts: Vec<usize>,
+    pub(super) html_comments: bool,
 }
 
 impl<'a> Tokenizer<'a> {
     /// Create a new tokenizer using
     /// the provided string reference
     /// to create a `JsBuffer`
-    pub fn new(stream: &'a str) -> Self {
+    pub fn new(text: &'a str) -> Self {
+        let mut stream: buffer::JSBuffer = text.into();
+        // a byte order mark at the very start is not
+        // part of the text, anywhere else it is white space
+        if stream.buffer.starts_with(BOM) {
+            stream.skip_bytes(BOM.len());
+        }
         Tokenizer {
-            current_start: 0,
-            stream: stream.into(),
+            current_start: stream.idx,
+            stream,
+            text,
             curly_stack: Vec::with_capacity(2),
+            template_starts: Vec::new(),
+            html_comments: true,
         }
     }
+    /// Enable or disable the Annex B HTML-like
+    /// comments (`<!--` and `-->`), these are
+    /// enabled by default but are not allowed
+    /// in module code
+    pub fn allow_html_comments(&mut self, allow: bool) {
+        self.html_comments = allow;
+    }
+    /// How many template literals are still
+    /// open after the last token
+    pub fn template_depth(&self) -> usize {
+        self.template_starts.len()
+    }
     /// Get the next raw token from the js text
     pub fn next(&mut self, allow_html_comment_close: bool) -> Res<RawItem> {
         trace!("next {} {}", self.stream.idx, self.stream.len);
         self.current_start = self.stream.idx;
+        if let Some(&OpenCurlyKind::Jsx(context)) = self.curly_stack.last() {
+            return self.jsx(context);
+        }
         let next_char = match self.stream.next_char() {
             Some(ch) => ch,
             None => {
+                if let Some(&idx) = self.template_starts.last() {
+                    return Err(RawError {
+                        kind: ErrorKind::UnterminatedTemplate,
+                        msg: "unterminated template literal".to_string(),
+                        idx,
+                    });
+                }
                 return Ok(RawItem {
                     start: self.stream.idx,
                     end: self.stream.idx,
                     ty: RawToken::EoF,
-                })
+                });
             }
         };
 
@@ -63,7 +104,7 @@ impl<'a> Tokenizer<'a> {
         if next_char == '(' || next_char == ')' || next_char == ';' {
             return self.punct(next_char, allow_html_comment_close);
         }
-        if next_char.is_digit(10) {
+        if next_char.is_ascii_digit() {
             return self.number(next_char);
         }
         if next_char == '`' {
@@ -78,6 +119,24 @@ impl<'a> Tokenizer<'a> {
         }
         self.punct(next_char, allow_html_comment_close)
     }
+    /// Get the `<` that starts a JSX element, the text after it
+    /// is scanned as JSX until the element is closed.
+    ///
+    /// note: this should only be used when the next character
+    /// is a `<` where an expression could start
+    pub fn next_jsx_tag(&mut self) -> Res<RawItem> {
+        trace!("next_jsx_tag {} {}", self.stream.idx, self.stream.len);
+        self.current_start = self.stream.idx;
+        match self.stream.next_char() {
+            Some('<') => self.jsx_tag_start(),
+            Some(c) => Err(RawError {
+                kind: ErrorKind::UnexpectedChar(c),
+                msg: "a JSX element must start with `<`".to_string(),
+                idx: self.current_start,
+            }),
+            None => self.next(false),
+        }
+    }
     /// get the next regex token from the js text, providing
     /// the lenth of the already consumed token (this will be either 1 or 2)
     ///
@@ -85,13 +144,38 @@ impl<'a> Tokenizer<'a> {
     /// from the `next` method.
     pub fn next_regex(&mut self, start_len: usize) -> Res<RawItem> {
         trace!("next_regex{} {}", self.stream.idx, self.stream.len);
+        let start = self.stream.idx;
+        // with the `v` flag character classes can be nested but the
+        // flags come last, so try that first and start over with the
+        // plain rules if a nested class was found without the flag
+        match self.regex(start_len, true) {
+            Ok((item, false)) => Ok(item),
+            Ok((item, true)) if self.regex_flags(&item).contains(&b'v') => Ok(item),
+            _ => {
+                self.stream.idx = start;
+                self.regex(start_len, false).map(|(item, _)| item)
+            }
+        }
+    }
+    /// Get the flags of a regex produced by `regex`
+    fn regex_flags(&self, item: &RawItem) -> &[u8] {
+        match item.ty {
+            RawToken::RegEx(body_end) => &self.stream.buffer[body_end..item.end],
+            _ => &[],
+        }
+    }
+    /// Scan a regex body and flags, if `nested_classes` is true a `[`
+    /// inside of a character class opens another class. The returned
+    /// flag is true when that happened.
+    fn regex(&mut self, start_len: usize, nested_classes: bool) -> Res<(RawItem, bool)> {
         self.current_start = self.stream.idx;
         let mut end_of_body = false;
         let mut body_idx = 0;
         if self.look_ahead_matches("\\/") {
             self.stream.skip_bytes(2);
         }
-        let mut in_class = false;
+        let mut class_depth = 0usize;
+        let mut found_nested = false;
         while let Some(c) = self.stream.next_char() {
             if end_of_body {
                 if c == '\\' {
@@ -108,15 +192,17 @@ impl<'a> Tokenizer<'a> {
                     }
                 } else if !Self::is_id_continue(c) {
                     let _ = self.stream.prev_char();
-                    return self.gen_regex(start_len, body_idx);
+                    return Ok((self.gen_regex(start_len, body_idx)?, found_nested));
                 }
             } else if c == '\\' {
                 if self.stream.at_new_line() {
                     return Err(RawError {
+                        kind: ErrorKind::UnterminatedRegex,
                         idx: self.stream.idx,
                         msg: "new line in regex literal".to_string(),
                     });
                 } else if self.look_ahead_byte_matches('[')
+                    || self.look_ahead_byte_matches(']')
                     || self.look_ahead_byte_matches('/')
                     || self.look_ahead_byte_matches('\\')
                 {
@@ -124,25 +210,30 @@ impl<'a> Tokenizer<'a> {
                 }
             } else if is_line_term(c) {
                 return Err(RawError {
+                    kind: ErrorKind::UnterminatedRegex,
                     idx: self.stream.idx,
                     msg: "new line in regex literal".to_string(),
                 });
-            } else if in_class {
+            } else if class_depth > 0 {
                 // we ignore the /
                 if c == ']' {
-                    in_class = false;
+                    class_depth -= 1;
+                } else if c == '[' && nested_classes {
+                    class_depth += 1;
+                    found_nested = true;
                 }
             } else if c == '/' {
                 end_of_body = true;
                 body_idx = self.stream.idx;
             } else if c == '[' {
-                in_class = true;
+                class_depth = 1;
             }
         }
         if end_of_body {
-            return self.gen_regex(start_len, body_idx);
+            return Ok((self.gen_regex(start_len, body_idx)?, found_nested));
         }
         Err(RawError {
+            kind: ErrorKind::UnterminatedRegex,
             msg: format!(
                 "unterminated regex at {}",
                 String::from_utf8_lossy(&self.stream.buffer[self.current_start..self.stream.idx])
@@ -165,6 +256,7 @@ impl<'a> Tokenizer<'a> {
             if !Self::is_id_start(c) {
                 debug!("bad char: {:?}", c);
                 return Err(RawError {
+                    kind: ErrorKind::InvalidEscape,
                     msg: "invalid escaped identifier start".to_string(),
                     idx: self.current_start,
                 });
@@ -181,6 +273,7 @@ impl<'a> Tokenizer<'a> {
                 let c = self.escaped_ident_part()?;
                 if !Self::is_id_continue(c) {
                     return Err(RawError {
+                        kind: ErrorKind::InvalidEscape,
                         msg: format!("invalid escaped identifier character: {}", c),
                         idx: self.current_start,
                     });
@@ -211,6 +304,7 @@ impl<'a> Tokenizer<'a> {
                 }
             } else {
                 return Err(RawError {
+                    kind: ErrorKind::InvalidEscape,
                     msg: "invalid unicode escape sequence in identifier".to_string(),
                     idx: self.current_start,
                 });
@@ -219,12 +313,14 @@ impl<'a> Tokenizer<'a> {
                 Ok(c)
             } else {
                 Err(RawError {
+                    kind: ErrorKind::InvalidEscape,
                     msg: "invalid unicode escape sequence in identifier".to_string(),
                     idx: self.current_start,
                 })
             }
         } else {
             Err(RawError {
+                kind: ErrorKind::InvalidEscape,
                 msg: "invalid unicode escape sequence in identifier".to_string(),
                 idx: self.current_start,
             })
@@ -241,7 +337,7 @@ impl<'a> Tokenizer<'a> {
     #[inline]
     pub(crate) fn escaped_with_code_point(&mut self) -> Res<(u32, usize)> {
         trace!("escaped_with_code_point");
-        let mut code = 0;
+        let mut code: u32 = 0;
         let mut last_char: char = '{';
         let mut len: usize = 0;
         while let Some(c) = self.stream.next_char() {
@@ -251,9 +347,10 @@ impl<'a> Tokenizer<'a> {
                 break;
             }
             if let Some(n) = c.to_digit(16) {
-                code = (code * 16) + n;
+                code = code.saturating_mul(16).saturating_add(n);
             } else {
                 return Err(RawError {
+                    kind: ErrorKind::InvalidEscape,
                     msg: "escaped unicode code point contains a non-hex digit".to_string(),
                     idx: self.stream.idx,
                 });
@@ -262,11 +359,13 @@ impl<'a> Tokenizer<'a> {
 
         if code > 0x10_FFFF {
             Err(RawError {
+                kind: ErrorKind::InvalidEscape,
                 msg: "escaped unicode codepoint too large".to_string(),
                 idx: self.stream.idx,
             })
         } else if last_char != '}' {
             Err(RawError {
+                kind: ErrorKind::InvalidEscape,
                 msg: "escaped unicode code points must end in }".to_string(),
                 idx: self.current_start,
             })
@@ -283,6 +382,7 @@ impl<'a> Tokenizer<'a> {
             n
         } else {
             return Err(RawError {
+                kind: ErrorKind::InvalidEscape,
                 msg: "escaped unicode char code is not a hex digit".to_string(),
                 idx: self.stream.idx,
             });
@@ -293,12 +393,14 @@ impl<'a> Tokenizer<'a> {
                     code = (code * 16) + n;
                 } else {
                     return Err(RawError {
+                        kind: ErrorKind::InvalidEscape,
                         msg: "escaped unicode code point is not a hex digit".to_string(),
                         idx: self.stream.idx,
                     });
                 }
             } else {
                 return Err(RawError {
+                    kind: ErrorKind::InvalidEscape,
                     msg: "escaped unicode sequence does not have 4 characters".to_string(),
                     idx: self.current_start,
                 });
@@ -331,8 +433,9 @@ impl<'a> Tokenizer<'a> {
                     // sequence
                     let _ = self.stream.prev_char();
                     return Err(RawError {
+                        kind: ErrorKind::UnterminatedString,
                         msg: "unescaped new line in string literal".to_string(),
-                        idx: self.stream.idx,
+                        idx: self.current_start,
                     });
                 }
                 if self.look_ahead_byte_matches('\n') {
@@ -347,8 +450,9 @@ impl<'a> Tokenizer<'a> {
                     // sequence
                     let _ = self.stream.prev_char();
                     return Err(RawError {
+                        kind: ErrorKind::UnterminatedString,
                         msg: "unescaped new line in string literal".to_string(),
-                        idx: self.stream.idx,
+                        idx: self.current_start,
                     });
                 }
                 new_line_count = new_line_count.saturating_add(1);
@@ -370,40 +474,36 @@ impl<'a> Tokenizer<'a> {
                     });
                 }
                 escaped = false;
-            } else {
-                let len = if escaped && c == 'u' {
-                    if let Some(next) = self.stream.next_char() {
-                        if next == '{' {
-                            self.escaped_with_code_point()?;
-                            8
-                        } else {
-                            self.escaped_with_hex4(next)?;
-                            5
-                        }
-                    } else {
+            } else if escaped {
+                let start = self.stream.idx - c.len_utf8();
+                let (escape, len) = match escape_sequence(&self.text[start..], true) {
+                    Some(parsed) => parsed,
+                    None => {
                         return Err(RawError {
+                            kind: ErrorKind::InvalidEscape,
                             idx: self.stream.idx,
                             msg: "Invalid escape in string literal".to_string(),
-                        });
-                    }
-                } else if escaped && c.is_digit(8) {
-                    if c != '0' || self.stream.at_decimal() {
-                        found_octal_escape = true;
+                        })
                     }
-                    1
-                } else {
-                    1
                 };
-                last_len = last_len.saturating_add(len);
+                if let Escape::Legacy(_) = escape {
+                    found_octal_escape = true;
+                }
+                self.stream.skip_bytes(len - c.len_utf8());
+                let chars = self.text[start..start + len].chars().count();
+                last_len = last_len.saturating_add(chars);
                 escaped = false;
+            } else {
+                last_len = last_len.saturating_add(1);
             }
         }
         // back up one to avoid splitting a unicode
         // sequence
         let _ = self.stream.prev_char();
         Err(RawError {
+            kind: ErrorKind::UnterminatedString,
             msg: "unterminated string literal".to_string(),
-            idx: self.stream.idx,
+            idx: self.current_start,
         })
     }
     /// Parse a punctuation mark or sequence the `c` provided is the
@@ -421,7 +521,7 @@ impl<'a> Tokenizer<'a> {
             '[' => self.gen_punct(Punct::OpenBracket),
             ']' => self.gen_punct(Punct::CloseBracket),
             ':' => self.gen_punct(Punct::Colon),
-            '?' => self.gen_punct(Punct::QuestionMark),
+            '?' => self.question_mark(),
             '#' => self.hash(),
             '~' => self.gen_punct(Punct::Tilde),
             '{' => self.open_curly(OpenCurlyKind::Block, Punct::OpenBrace),
@@ -441,6 +541,7 @@ impl<'a> Tokenizer<'a> {
             '%' => self.percent(),
             '^' => self.caret(),
             _ => Err(RawError {
+                kind: ErrorKind::UnexpectedChar(c),
                 msg: format!("unknown punct {:?}", c),
                 idx: self.current_start,
             }),
@@ -489,6 +590,29 @@ impl<'a> Tokenizer<'a> {
             self.gen_punct(Punct::Period)
         }
     }
+    /// A `?` could be `?.` unless the `.` starts a
+    /// number, in `a?.5:1` that is a conditional
+    #[inline]
+    fn question_mark(&mut self) -> Res<RawItem> {
+        trace!(
+            "question_mark ({}, {})",
+            self.current_start,
+            self.stream.idx
+        );
+        if self.look_ahead_byte_matches('.')
+            && !self
+                .stream
+                .buffer
+                .get(self.stream.idx + 1)
+                .map(u8::is_ascii_digit)
+                .unwrap_or(false)
+        {
+            self.stream.skip_bytes(1);
+            self.gen_punct(Punct::QuestionMarkDot)
+        } else {
+            self.gen_punct(Punct::QuestionMark)
+        }
+    }
     /// A `>` could be `>>>=`, `>>>`, `>>=` , `>>` or `>=`
     #[inline]
     fn greater_than(&mut self) -> Res<RawItem> {
@@ -525,7 +649,7 @@ impl<'a> Tokenizer<'a> {
         } else if self.look_ahead_byte_matches('<') {
             self.stream.skip_bytes(1);
             self.gen_punct(Punct::DoubleLessThan)
-        } else if self.look_ahead_matches("!--") {
+        } else if self.html_comments && self.look_ahead_matches("!--") {
             self.stream.skip_bytes(3);
             self.html_comment()
         } else {
@@ -580,13 +704,18 @@ impl<'a> Tokenizer<'a> {
             self.gen_punct(Punct::Asterisk)
         }
     }
-    /// a `&` could be `&&` or `&=`
+    /// a `&` could be `&&`, `&&=` or `&=`
     #[inline]
     fn ampersand(&mut self) -> Res<RawItem> {
         trace!("ampersand ({}, {})", self.current_start, self.stream.idx);
         if self.look_ahead_byte_matches('&') {
             self.stream.skip_bytes(1);
-            self.gen_punct(Punct::DoubleAmpersand)
+            if self.look_ahead_byte_matches('=') {
+                self.stream.skip_bytes(1);
+                self.gen_punct(Punct::DoubleAmpersandEqual)
+            } else {
+                self.gen_punct(Punct::DoubleAmpersand)
+            }
         } else if self.look_ahead_byte_matches('=') {
             self.stream.skip_bytes(1);
             self.gen_punct(Punct::AmpersandEqual)
@@ -594,13 +723,18 @@ impl<'a> Tokenizer<'a> {
             self.gen_punct(Punct::Ampersand)
         }
     }
-    /// a `|` could be `||` or `|=`
+    /// a `|` could be `||`, `||=` or `|=`
     #[inline]
     fn pipe(&mut self) -> Res<RawItem> {
         trace!("pipe ({}, {})", self.current_start, self.stream.idx);
         if self.look_ahead_byte_matches('|') {
             self.stream.skip_bytes(1);
-            self.gen_punct(Punct::DoublePipe)
+            if self.look_ahead_byte_matches('=') {
+                self.stream.skip_bytes(1);
+                self.gen_punct(Punct::DoublePipeEqual)
+            } else {
+                self.gen_punct(Punct::DoublePipe)
+            }
         } else if self.look_ahead_byte_matches('=') {
             self.stream.skip_bytes(1);
             self.gen_punct(Punct::PipeEqual)
@@ -628,7 +762,7 @@ impl<'a> Tokenizer<'a> {
         trace!("minus ({}, {})", self.current_start, self.stream.idx);
         if self.look_ahead_byte_matches('-') {
             self.stream.skip_bytes(1);
-            if allow_html_comment_close && self.look_ahead_byte_matches('>') {
+            if allow_html_comment_close && self.html_comments && self.look_ahead_byte_matches('>') {
                 self.single_comment(CommentKind::Html)
             } else {
                 self.gen_punct(Punct::DoubleDash)
@@ -683,22 +817,149 @@ impl<'a> Tokenizer<'a> {
             self.gen_punct(Punct::Caret)
         }
     }
-    /// a `#` could also be the start of a hash bang comment `#!`
+    /// a `#` is either the start of a hashbang comment `#!`
+    /// at the very start of the text or the start of a
+    /// private name (`#x`), which is produced as a `Hash`
+    /// followed by the identifier
     #[inline]
     fn hash(&mut self) -> Res<RawItem> {
         trace!("hash ({}, {})", self.current_start, self.stream.idx);
         // hashbang comment can only appear at the start
-        if self.current_start == 0 && self.look_ahead_byte_matches('!') {
+        if self.at_text_start() && self.look_ahead_byte_matches('!') {
             while !self.at_new_line() {
                 if self.stream.next_char().is_none() {
                     break;
                 }
             }
             self.gen_comment(CommentKind::Hashbang, 0, 0, self.local_index())
-        } else {
+        } else if self.stream.peek_char().is_some_and(Self::is_id_start) {
             self.gen_punct(Punct::Hash)
+        } else {
+            Err(RawError {
+                kind: ErrorKind::UnexpectedChar('#'),
+                msg: "a `#` must be directly followed by a private name".to_string(),
+                idx: self.current_start,
+            })
+        }
+    }
+    /// The next part of a JSX element, `context` is
+    /// the innermost entry of the `curly_stack`
+    fn jsx(&mut self, context: JsxContext) -> Res<RawItem> {
+        trace!(
+            "jsx {:?} ({}, {})",
+            context,
+            self.current_start,
+            self.stream.idx
+        );
+        let c = match self.stream.next_char() {
+            Some(c) => c,
+            None => {
+                return Err(RawError {
+                    kind: ErrorKind::Other,
+                    msg: "unterminated JSX element".to_string(),
+                    idx: self.current_start,
+                })
+            }
+        };
+        match (context, c) {
+            (JsxContext::Children, '<') if self.look_ahead_byte_matches('/') => {
+                self.stream.skip_bytes(1);
+                self.set_jsx_context(JsxContext::ClosingTag);
+                self.gen_jsx(JsxKind::ClosingTagStart)
+            }
+            (_, '<') => self.jsx_tag_start(),
+            (_, '{') => self.open_curly(OpenCurlyKind::Block, Punct::OpenBrace),
+            (JsxContext::Children, _) => self.jsx_text(),
+            (JsxContext::OpeningTag, '>') => {
+                self.set_jsx_context(JsxContext::Children);
+                self.gen_jsx(JsxKind::TagEnd)
+            }
+            (JsxContext::ClosingTag, '>') => {
+                self.curly_stack.pop();
+                self.gen_jsx(JsxKind::TagEnd)
+            }
+            (_, '/') if self.look_ahead_byte_matches('>') => {
+                self.stream.skip_bytes(1);
+                self.curly_stack.pop();
+                self.gen_jsx(JsxKind::SelfClosingTagEnd)
+            }
+            (_, '"') | (_, '\'') => self.jsx_string(c),
+            (_, c) if c != '\\' && Self::is_id_start(c) => self.jsx_name(),
+            // `.`, `:`, `=` and comments
+            (_, c) => self.punct(c, false),
+        }
+    }
+    /// A `<` that starts an opening tag, this may be
+    /// for a new element or one of the children
+    fn jsx_tag_start(&mut self) -> Res<RawItem> {
+        self.curly_stack
+            .push(OpenCurlyKind::Jsx(JsxContext::OpeningTag));
+        self.gen_jsx(JsxKind::TagStart)
+    }
+    /// A tag or attribute name, this is an identifier
+    /// that can also include `-` but no escapes
+    fn jsx_name(&mut self) -> Res<RawItem> {
+        while let Some(c) = self.stream.peek_char() {
+            if c == '-' || (c != '\\' && Self::is_id_continue(c)) {
+                self.stream.skip(1);
+            } else {
+                break;
+            }
+        }
+        self.gen_jsx(JsxKind::Name)
+    }
+    /// Everything up to the next tag or `{`
+    fn jsx_text(&mut self) -> Res<RawItem> {
+        let rest = &self.text[self.stream.idx..];
+        let len = rest.find(['<', '{']).unwrap_or(rest.len());
+        self.stream.skip_bytes(len);
+        self.gen_jsx(JsxKind::Text)
+    }
+    /// An attribute value in quotes, these can't
+    /// have escapes but can include new lines
+    fn jsx_string(&mut self, quote: char) -> Res<RawItem> {
+        let rest = &self.text[self.stream.idx..];
+        let len = match rest.find(quote) {
+            Some(len) => len,
+            None => {
+                return Err(RawError {
+                    kind: ErrorKind::UnterminatedString,
+                    msg: "unterminated JSX attribute string".to_string(),
+                    idx: self.current_start,
+                })
+            }
+        };
+        self.stream.skip_bytes(len + 1);
+        let (new_line_count, last_len) =
+            line_extent(&self.text[self.current_start..self.stream.idx]);
+        let kind = if quote == '"' {
+            StringKind::Double
+        } else {
+            StringKind::Single
+        };
+        self.gen_token(RawToken::String {
+            kind,
+            new_line_count,
+            last_len,
+            found_octal_escape: false,
+        })
+    }
+    /// Replace the innermost entry of the `curly_stack`,
+    /// which has to be a JSX element
+    fn set_jsx_context(&mut self, context: JsxContext) {
+        if let Some(last) = self.curly_stack.last_mut() {
+            *last = OpenCurlyKind::Jsx(context);
         }
     }
+    fn gen_jsx(&self, kind: JsxKind) -> Res<RawItem> {
+        let (new_line_count, last_len) =
+            line_extent(&self.text[self.current_start..self.stream.idx]);
+        self.gen_token(RawToken::Jsx {
+            kind,
+            new_line_count,
+            last_len,
+        })
+    }
     /// parse a number, this can include decimal or float literals
     /// like `0.01e1` or `10` as well as binary, octal or hex
     /// literals like `0b1`, `0o7`, or `0xf` and BigInt literals
@@ -722,7 +983,7 @@ impl<'a> Tokenizer<'a> {
                         self.bin_number()
                     } else if next == 'n' {
                         self.gen_number(NumberKind::BigInt)
-                    } else if next.is_digit(10) {
+                    } else if next.is_ascii_digit() {
                         self.dec_number(false, next)
                     } else if next == '.' {
                         self.dec_number(true, next)
@@ -765,71 +1026,36 @@ impl<'a> Tokenizer<'a> {
         while let Some(c) = self.stream.next_char() {
             last_len = last_len.saturating_add(1);
             if c == '\\' {
-                if self.look_ahead_matches("${") {
-                    last_len = last_len.saturating_add(2);
-                    self.stream.skip_bytes(2);
-                } else if self.look_ahead_byte_matches('`') || self.look_ahead_byte_matches('\\') {
-                    last_len = last_len.saturating_add(1);
-                    self.stream.skip_bytes(1);
-                } else if self.look_ahead_byte_matches('0') {
-                    last_len = last_len.saturating_add(1);
-                    self.stream.skip_bytes(1);
-                    if self.stream.at_octal() {
-                        found_octal_escape = true;
+                let start = self.stream.idx;
+                match escape_sequence(&self.text[start..], false) {
+                    Some((Escape::LineContinuation, len)) => {
+                        self.stream.skip_bytes(len);
+                        line_count = line_count.saturating_add(1);
+                        last_len = 0;
                     }
-                } else if self.stream.at_octal() {
-                    found_octal_escape = true;
-                } else if self.look_ahead_byte_matches('u') {
-                    self.stream.skip_bytes(1);
-                    last_len = last_len.saturating_add(1);
-                    if let Some(ch) = self.stream.next_char() {
-                        last_len = last_len.saturating_add(1);
-                        if ch == '{' {
-                            let mut acc = 0u32;
-                            while self.stream.at_hex() {
-                                last_len = last_len.saturating_add(1);
-                                if let Some(n) = self.stream.next_char() {
-                                    acc = (acc * 16) + n.to_digit(16).unwrap();
-                                }
-                            }
-                            while !self.look_ahead_byte_matches('}') {
-                                found_invalid_unicode = true;
-                                if self.look_ahead_byte_matches('`') {
-                                    break;
-                                }
-                                self.stream.skip_bytes(1);
-                                last_len = last_len.saturating_add(1);
-                            }
-
-                            if acc > 0x10_FFFF {
-                                found_invalid_unicode = true;
-                            }
-                        } else if ch.is_digit(16) {
-                            for _ in 0..3 {
-                                if self.stream.at_hex() {
-                                    self.stream.skip_bytes(1);
-                                } else {
-                                    found_invalid_unicode = true;
-                                    break;
-                                }
-                            }
-                        } else {
-                            found_invalid_unicode = true;
-                        };
-                    } else {
-                        return Err(RawError {
-                            idx: self.stream.idx,
-                            msg: "Invalid escape sequence in template literal".to_string(),
-                        });
+                    Some((_, len)) => {
+                        self.stream.skip_bytes(len);
+                        let chars = self.text[start..start + len].chars().count();
+                        last_len = last_len.saturating_add(chars);
                     }
-                } else if self.look_ahead_byte_matches('x') {
-                    self.stream.skip_bytes(1);
-                    last_len = last_len.saturating_add(1);
-                    for _ in 0..2 {
-                        if self.stream.at_hex() {
-                            let _ = self.stream.next_char();
-                        } else {
-                            found_invalid_hex = true;
+                    None => {
+                        // only the character after the `\` is part of
+                        // the invalid escape, anything after that is
+                        // regular template content
+                        let next = self.stream.next_char();
+                        match next {
+                            Some('u') => found_invalid_unicode = true,
+                            Some('x') => found_invalid_hex = true,
+                            Some(_) => found_octal_escape = true,
+                            None => (),
+                        }
+                        last_len = last_len.saturating_add(1);
+                        if next.is_some() && self.stream.at_end() {
+                            return Err(RawError {
+                                kind: ErrorKind::InvalidEscape,
+                                idx: self.stream.idx,
+                                msg: "Invalid escape sequence in template literal".to_string(),
+                            });
                         }
                     }
                 }
@@ -847,6 +1073,7 @@ impl<'a> Tokenizer<'a> {
                     self.stream.skip_bytes(1);
                     self.curly_stack.push(OpenCurlyKind::Template);
                     if start == '`' {
+                        self.template_starts.push(self.current_start);
                         return self.gen_template(
                             TemplateKind::Head,
                             line_count,
@@ -877,6 +1104,7 @@ impl<'a> Tokenizer<'a> {
                         found_invalid_hex,
                     );
                 } else {
+                    self.template_starts.pop();
                     return self.gen_template(
                         TemplateKind::Tail,
                         line_count,
@@ -888,12 +1116,22 @@ impl<'a> Tokenizer<'a> {
                 }
             }
         }
+        // point to the back tick that opened the whole template
+        let idx = if start == '`' {
+            self.current_start
+        } else {
+            self.template_starts
+                .last()
+                .copied()
+                .unwrap_or(self.current_start)
+        };
         Err(RawError {
+            kind: ErrorKind::UnterminatedTemplate,
             msg: format!(
                 "unterminated template: {:?}",
                 String::from_utf8_lossy(&self.stream.buffer[self.current_start..self.stream.idx])
             ),
-            idx: self.current_start,
+            idx,
         })
     }
     /// parse a single comment after finding `//`
@@ -946,7 +1184,10 @@ impl<'a> Tokenizer<'a> {
             }
         }
         if found_end {
-            if (new_line_count > 0 || allow_html_comment_close) && self.look_ahead_matches("-->") {
+            if self.html_comments
+                && (new_line_count > 0 || allow_html_comment_close)
+                && self.look_ahead_matches("-->")
+            {
                 self.stream.skip_bytes(3);
 
                 while !self.stream.at_end() && !self.at_new_line() {
@@ -958,6 +1199,7 @@ impl<'a> Tokenizer<'a> {
             self.gen_comment(CommentKind::Multi, new_line_count, last_len, end_idx)
         } else {
             Err(RawError {
+                kind: ErrorKind::UnterminatedComment,
                 idx: self.current_start,
                 msg: "unterminated multi-line comment".to_string(),
             })
@@ -985,6 +1227,7 @@ impl<'a> Tokenizer<'a> {
             return self.gen_comment(CommentKind::Html, 0, 0, end_idx);
         }
         Err(RawError {
+            kind: ErrorKind::UnterminatedComment,
             msg: "unterminated html comment".to_string(),
             idx: self.current_start,
         })
@@ -994,8 +1237,9 @@ impl<'a> Tokenizer<'a> {
     fn hex_number(&mut self) -> Res<RawItem> {
         trace!("hex_number ({}, {})", self.current_start, self.stream.idx);
         let mut prev_char = if let Some(c) = self.stream.next_char() {
-            if !c.is_digit(16) {
+            if !c.is_ascii_hexdigit() {
                 return Err(RawError {
+                    kind: ErrorKind::InvalidNumber,
                     msg: "empty hex literal".to_string(),
                     idx: self.current_start,
                 });
@@ -1003,6 +1247,7 @@ impl<'a> Tokenizer<'a> {
             c
         } else {
             return Err(RawError {
+                kind: ErrorKind::InvalidNumber,
                 msg: "empty hex literal".to_string(),
                 idx: self.current_start,
             });
@@ -1026,6 +1271,7 @@ impl<'a> Tokenizer<'a> {
         let mut prev_char = if let Some(c) = self.stream.next_char() {
             if !c.is_digit(8) {
                 return Err(RawError {
+                    kind: ErrorKind::InvalidNumber,
                     msg: "empty octal literal".to_string(),
                     idx: self.current_start,
                 });
@@ -1033,6 +1279,7 @@ impl<'a> Tokenizer<'a> {
             c
         } else {
             return Err(RawError {
+                kind: ErrorKind::InvalidNumber,
                 msg: "empty octal literal".to_string(),
                 idx: self.current_start,
             });
@@ -1055,6 +1302,7 @@ impl<'a> Tokenizer<'a> {
         let mut prev_char = if let Some(c) = self.stream.next_char() {
             if !c.is_digit(2) {
                 return Err(RawError {
+                    kind: ErrorKind::InvalidNumber,
                     msg: "empty binary literal".to_string(),
                     idx: self.current_start,
                 });
@@ -1062,6 +1310,7 @@ impl<'a> Tokenizer<'a> {
             c
         } else {
             return Err(RawError {
+                kind: ErrorKind::InvalidNumber,
                 msg: "empty binary literal".to_string(),
                 idx: self.current_start,
             });
@@ -1100,8 +1349,10 @@ impl<'a> Tokenizer<'a> {
             if self.look_ahead_byte_matches('-') || self.look_ahead_byte_matches('+') {
                 self.stream.skip_bytes(1);
                 prev_char = '-';
-            } else if !self.stream.at_decimal() {
+            }
+            if !self.stream.at_decimal() {
                 return Err(RawError {
+                    kind: ErrorKind::InvalidNumber,
                     msg: "Invalid decimal, exponents must be followed by +, - or decimal digits"
                         .to_string(),
                     idx: self.current_start,
@@ -1112,6 +1363,7 @@ impl<'a> Tokenizer<'a> {
         let kind = self.bigint_guard(NumberKind::Dec);
         if kind == NumberKind::BigInt && !check_for_n {
             return Err(RawError {
+                kind: ErrorKind::InvalidNumber,
                 msg: "Invalid decimal, Floats cannot be BigInts".to_string(),
                 idx: self.current_start,
             });
@@ -1152,6 +1404,7 @@ impl<'a> Tokenizer<'a> {
         );
         if prev_char == '_' {
             Err(RawError {
+                kind: ErrorKind::InvalidNumber,
                 msg: "Invalid decimal. Numbers cannot end with an underscore".to_string(),
                 idx: self.current_start,
             })
@@ -1159,15 +1412,38 @@ impl<'a> Tokenizer<'a> {
             Ok(())
         }
     }
+    /// Guard against a number literal running directly into
+    /// an identifier (`3in`), a digit that isn't valid for
+    /// its radix (`0b12`) or a second fraction (`1.2.3`)
     #[inline]
     fn check_trailing_ident_start(&mut self) -> Res<()> {
         if let Some(next) = self.stream.peek_char() {
-            if Self::is_id_start(next) {
+            if Self::is_id_start(next) || next == '\\' {
                 return Err(RawError {
+                    kind: ErrorKind::InvalidNumber,
                     idx: self.stream.idx,
                     msg: "Number literal cannot be immedatly followed by an identifier".to_string(),
                 });
             }
+            if next.is_ascii_digit() {
+                return Err(RawError {
+                    kind: ErrorKind::InvalidNumber,
+                    idx: self.stream.idx,
+                    msg: format!("Invalid digit {} in number literal", next),
+                });
+            }
+            if next == '.' {
+                self.stream.skip_bytes(1);
+                let fraction = self.stream.at_decimal();
+                self.stream.skip_back_bytes(1);
+                if fraction {
+                    return Err(RawError {
+                        kind: ErrorKind::InvalidNumber,
+                        idx: self.stream.idx,
+                        msg: "Number literal cannot have a second fraction".to_string(),
+                    });
+                }
+            }
         }
         Ok(())
     }
@@ -1183,6 +1459,7 @@ impl<'a> Tokenizer<'a> {
         );
         if char_1 == '_' && char_2 == '_' {
             Err(RawError {
+                kind: ErrorKind::InvalidNumber,
                 msg: "double numeric seperator".to_string(),
                 idx: self.current_start,
             })
@@ -1358,10 +1635,15 @@ impl<'a> Tokenizer<'a> {
             self.stream.idx,
             self.stream.len
         );
+        // white space between JSX tags is part of the text
+        if let Some(OpenCurlyKind::Jsx(JsxContext::Children)) = self.curly_stack.last() {
+            return (0, 0);
+        }
         let mut new_line_ct = 0usize;
         let mut leading_whitespace = 0usize;
         while self.stream.at_whitespace() {
-            if self.at_new_line() {
+            // the `\n` of a `\r\n` pair will count the new line
+            if self.at_new_line() && !self.stream.look_ahead_matches(b"\r\n") {
                 new_line_ct += 1;
                 leading_whitespace = 0;
             }
@@ -1370,6 +1652,13 @@ impl<'a> Tokenizer<'a> {
         }
         (new_line_ct, leading_whitespace)
     }
+    /// Check if the current token is the first thing
+    /// in the text, ignoring any byte order mark
+    #[inline]
+    fn at_text_start(&self) -> bool {
+        self.current_start == 0
+            || (self.current_start == BOM.len() && self.stream.buffer.starts_with(BOM))
+    }
     /// Check if the look ahead is a new line character
     #[inline]
     fn at_new_line(&mut self) -> bool {
@@ -1404,7 +1693,7 @@ mod test {
         static PUNCTS: &[&str] = &[
             "{", "}", "(", ")", ".", ";", ",", "[", "]", ":", "?", "~", ">", "<", "=", "!", "+",
             "-", "/", "*", "%", "&", "|", "^", ">>>=", //3 char
-            "...", "===", "!==", ">>>", "<<=", ">>=", "**=", //2 char
+            "...", "===", "!==", ">>>", "<<=", ">>=", "**=", "&&=", "||=", //2 char
             "&&", "||", "==", "!=", "+=", "-=", "*=", "/=", "++", "--", "<<", ">>", "&=", "|=",
             "^=", "%=", "<=", ">=", "=>", "**", "@",
         ];
@@ -1469,10 +1758,23 @@ mod test {
         let b = "\n#!/usr/bin/env node";
         let mut t = Tokenizer::new(b);
         t.skip_whitespace();
-        let item = t.next(true).unwrap();
-        match item.ty {
-            RawToken::Punct(Punct::Hash) => (),
-            _ => panic!("expected hash, found {:?}", item.ty),
+        let e = t.next(true).unwrap_err();
+        assert_eq!(e.kind, ErrorKind::UnexpectedChar('#'));
+        assert_eq!(e.idx, 1);
+    }
+    #[test]
+    fn tokenizer_private_names() {
+        for b in &["#x", "#_", "#$", "#\\u0078", "#\u{e9}"] {
+            let mut t = Tokenizer::new(b);
+            let item = t.next(true).unwrap();
+            assert_eq!(item.ty, RawToken::Punct(Punct::Hash), "{}", b);
+            assert_eq!((item.start, item.end), (0, 1));
+        }
+        for b in &["#", "# x", "#1", "#-", " #!"] {
+            let mut t = Tokenizer::new(b);
+            t.skip_whitespace();
+            let e = t.next(true).unwrap_err();
+            assert_eq!(e.kind, ErrorKind::UnexpectedChar('#'), "{}", b);
         }
     }
     #[test]
@@ -1546,6 +1848,11 @@ mod test {
             r#"x‌‍"#,
             r#"\u08BE"#,
             r#"\u{8be}"#,
+            "cafe\u{301}",
+            "x\u{966}\u{967}",
+            "\u{915}\u{93f}",
+            "a\u{203f}b",
+            "a\u{2040}",
         ];
         for i in IDENTS {
             let mut t = Tokenizer::new(dbg!(i));
@@ -1568,6 +1875,67 @@ mod test {
         t.next(true).unwrap();
     }
 
+    #[test]
+    fn tokenizer_ident_zwnj_zwj() {
+        for i in &["a\u{200C}b", "a\u{200D}b", "a\u{200C}\u{200D}"] {
+            let mut t = Tokenizer::new(i);
+            let item = t.next(true).unwrap();
+            assert_eq!(item.ty, RawToken::Ident);
+            assert_eq!(item.end, i.len());
+            assert!(t.stream.at_end());
+        }
+    }
+
+    #[test]
+    fn tokenizer_ident_zwnj_zwj_start() {
+        for i in &["\u{200C}a", "\u{200D}a"] {
+            let mut t = Tokenizer::new(i);
+            assert!(t.next(true).is_err());
+        }
+    }
+
+    #[test]
+    #[should_panic = "invalid escaped identifier start"]
+    fn tokenizer_ident_escaped_digit_start() {
+        let mut t = Tokenizer::new(r#"\u0030abc"#);
+        t.next(true).unwrap();
+    }
+
+    #[test]
+    #[should_panic = "invalid escaped identifier character: -"]
+    fn tokenizer_ident_escaped_illegal_continue() {
+        let mut t = Tokenizer::new(r#"a\u002Db"#);
+        t.next(true).unwrap();
+    }
+
+    #[test]
+    fn tokenizer_ident_astral_escapes() {
+        // U+10000 is a letter outside of the BMP
+        for i in &[r#"\u{10000}"#, r#"a\u{10000}"#, r#"\u{0000010000}"#] {
+            let mut t = Tokenizer::new(i);
+            let item = t.next(true).unwrap();
+            assert_eq!(item.ty, RawToken::Ident);
+            assert_eq!(item.end, i.len());
+        }
+        // an emoji is neither ID_Start nor ID_Continue
+        let e = Tokenizer::new(r#"\u{1F600}"#).next(true).unwrap_err();
+        assert_eq!(e.msg, "invalid escaped identifier start");
+        let e = Tokenizer::new(r#"a\u{1F600}"#).next(true).unwrap_err();
+        assert_eq!(e.msg, "invalid escaped identifier character: \u{1F600}");
+        // and neither half of a surrogate pair is a `char`
+        assert!(Tokenizer::new(r#"\u{D83D}"#).next(true).is_err());
+        assert!(Tokenizer::new(r#"a\uD83D\uDE00"#).next(true).is_err());
+    }
+
+    #[test]
+    fn tokenizer_ident_escape_overflow() {
+        let e = Tokenizer::new(r#"a\u{FFFFFFFFFFFFFFFF}"#)
+            .next(true)
+            .unwrap_err();
+        assert_eq!(e.kind, ErrorKind::InvalidEscape);
+        assert_eq!(e.msg, "escaped unicode codepoint too large");
+    }
+
     #[test]
     #[should_panic = "invalid unicode escape sequence in identifier"]
     fn tokenizer_ident_slash_only() {
@@ -1601,6 +1969,11 @@ mod test {
             "0o777",
             "2e308",
             "1e1",
+            "1e+5",
+            "1E-3",
+            "1E+5",
+            ".5e+2",
+            "1.5e-10",
             "0b1010_0001_1000_0101",
             "0xA0_B0_C0",
             "0o6_5",
@@ -1619,10 +1992,7 @@ mod test {
             let mut t = Tokenizer::new(n);
             let item = t.next(true).unwrap();
             dbg!(&n[item.start..item.end]);
-            assert!(match item.ty {
-                RawToken::Number(_) => true,
-                _ => false,
-            });
+            assert!(matches!(item.ty, RawToken::Number(_)));
             assert!(t.stream.at_end());
         }
     }
@@ -1665,10 +2035,7 @@ mod test {
             let mut t = Tokenizer::new(r);
             let next = t.next(true).unwrap();
             let item = t.next_regex(next.end - next.start).unwrap();
-            assert!(match item.ty {
-                RawToken::RegEx(_) => true,
-                _ => false,
-            });
+            assert!(matches!(item.ty, RawToken::RegEx(_)));
             assert!(t.stream.at_end());
         }
     }
@@ -1846,10 +2213,7 @@ mod test {
         for b in &["true", "false"] {
             let mut t = Tokenizer::new(b);
             let item = t.next(true).unwrap();
-            assert!(match item.ty {
-                RawToken::Boolean(_) => true,
-                _ => false,
-            });
+            assert!(matches!(item.ty, RawToken::Boolean(_)));
             assert!(t.stream.at_end());
         }
     }
@@ -2058,6 +2422,44 @@ mod test {
         t.next(true).unwrap();
     }
     #[test]
+    fn string_escapes() {
+        for js in &[r"'\x41A\u{41}\n\t\0'", r"'😀\u{1F600}'", r"'\q\é'"] {
+            let mut t = Tokenizer::new(js);
+            let item = t.next(true).unwrap();
+            assert!(
+                matches!(
+                    item.ty,
+                    RawToken::String {
+                        found_octal_escape: false,
+                        ..
+                    }
+                ),
+                "{}",
+                js
+            );
+            assert_eq!(item.end, js.len());
+        }
+        for js in &[r"'\101'", r"'\08'", r"'\8'"] {
+            let mut t = Tokenizer::new(js);
+            let item = t.next(true).unwrap();
+            assert!(
+                matches!(
+                    item.ty,
+                    RawToken::String {
+                        found_octal_escape: true,
+                        ..
+                    }
+                ),
+                "{}",
+                js
+            );
+        }
+        for js in &[r"'\x4'", r"'\xg1'", r"'\u{110000}'", r"'\u{41'"] {
+            let mut t = Tokenizer::new(js);
+            assert!(t.next(true).is_err(), "{}", js);
+        }
+    }
+    #[test]
     #[should_panic = "unterminated string literal"]
     fn untermed_str_lit() {
         let mut t = Tokenizer::new("'asdf");
@@ -2208,6 +2610,50 @@ mod test {
         }
     }
 
+    #[test]
+    fn invalid_numbers() {
+        for js in &[
+            "0x",
+            "0X",
+            "0b",
+            "0B",
+            "0o",
+            "0O",
+            "0x;",
+            "0b2",
+            "0o8",
+            "0b12",
+            "0o78",
+            "1.2.3",
+            "1..5",
+            ".5.5",
+            "0x1.5",
+            "1e",
+            "1e+",
+            "1E-",
+            "1e;",
+            "3in",
+            "3abc",
+            "0x1fg",
+            "1.5n",
+            "1_",
+            "1__0",
+            "3\\u0061",
+            "1.toString",
+        ] {
+            let mut t = Tokenizer::new(js);
+            let e = t
+                .next(true)
+                .expect_err(&format!("expected {:?} to be invalid", js));
+            assert_eq!(e.kind, ErrorKind::InvalidNumber, "{:?}", js);
+        }
+        for js in &["1n", "0x1fn", "0b1n", "1..toString", "1.5.x", "1e5", "1e-5"] {
+            let mut t = Tokenizer::new(js);
+            let item = t.next(true).unwrap();
+            assert!(matches!(item.ty, RawToken::Number(_)), "{:?}", js);
+        }
+    }
+
     #[test]
     #[should_panic = "Number literal cannot be immedatly followed by an identifier"]
     fn number_followed_by_ident_start() {
@@ -59,36 +59,30 @@ pub enum RawToken {
         last_len: usize,
         end_index: usize,
     },
+    /// A part of a JSX element, only text
+    /// can include a new line
+    Jsx {
+        kind: JsxKind,
+        new_line_count: usize,
+        last_len: usize,
+    },
 }
 
 impl Copy for Keyword<()> {}
 
 impl RawToken {
     pub fn is_punct(&self) -> bool {
-        if let RawToken::Punct(_) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, RawToken::Punct(_))
     }
 
     pub fn is_comment(&self) -> bool {
-        if let RawToken::Comment { .. } = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, RawToken::Comment { .. })
     }
     pub fn is_div_punct(&self) -> bool {
-        if let RawToken::Punct(ref p) = self {
-            match p {
-                Punct::ForwardSlash => true,
-                Punct::ForwardSlashEqual => true,
-                _ => false,
-            }
-        } else {
-            false
-        }
+        matches!(
+            self,
+            RawToken::Punct(Punct::ForwardSlash) | RawToken::Punct(Punct::ForwardSlashEqual)
+        )
     }
 }
 
@@ -98,6 +92,16 @@ pub enum StringKind {
     Single,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JsxKind {
+    TagStart,
+    ClosingTagStart,
+    TagEnd,
+    SelfClosingTagEnd,
+    Name,
+    Text,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum TemplateKind {
     NoSub,
@@ -125,6 +129,7 @@ pub enum RawKeyword {
     Finally,
     For,
     Function,
+    Get,
     If,
     Implements,
     Import,
@@ -138,6 +143,7 @@ pub enum RawKeyword {
     Protected,
     Public,
     Return,
+    Set,
     Static,
     Super,
     Switch,
@@ -173,6 +179,7 @@ impl RawKeyword {
             RawKeyword::Finally => Keyword::Finally(s),
             RawKeyword::For => Keyword::For(s),
             RawKeyword::Function => Keyword::Function(s),
+            RawKeyword::Get => Keyword::Get(s),
             RawKeyword::If => Keyword::If(s),
             RawKeyword::Implements => Keyword::Implements(s),
             RawKeyword::Import => Keyword::Import(s),
@@ -186,6 +193,7 @@ impl RawKeyword {
             RawKeyword::Protected => Keyword::Protected(s),
             RawKeyword::Public => Keyword::Public(s),
             RawKeyword::Return => Keyword::Return(s),
+            RawKeyword::Set => Keyword::Set(s),
             RawKeyword::Static => Keyword::Static(s),
             RawKeyword::Super => Keyword::Super(s),
             RawKeyword::Switch => Keyword::Switch(s),
@@ -223,6 +231,7 @@ impl<T> From<&Keyword<T>> for RawKeyword {
             Keyword::Finally(_) => RawKeyword::Finally,
             Keyword::For(_) => RawKeyword::For,
             Keyword::Function(_) => RawKeyword::Function,
+            Keyword::Get(_) => RawKeyword::Get,
             Keyword::If(_) => RawKeyword::If,
             Keyword::Implements(_) => RawKeyword::Implements,
             Keyword::Import(_) => RawKeyword::Import,
@@ -236,6 +245,7 @@ impl<T> From<&Keyword<T>> for RawKeyword {
             Keyword::Protected(_) => RawKeyword::Protected,
             Keyword::Public(_) => RawKeyword::Public,
             Keyword::Return(_) => RawKeyword::Return,
+            Keyword::Set(_) => RawKeyword::Set,
             Keyword::Static(_) => RawKeyword::Static,
             Keyword::Super(_) => RawKeyword::Super,
             Keyword::Switch(_) => RawKeyword::Switch,
@@ -60,16 +60,68 @@ impl<T> Comment<T> {
     }
 }
 
-impl<T> ToString for Comment<T>
+impl<T> Comment<T>
 where
     T: AsRef<str>,
 {
-    fn to_string(&self) -> String {
+    /// Check if this is a JSDoc comment, meaning a multi-line
+    /// comment that opens with exactly `/**`, the empty
+    /// comment `/**/` is not JSDoc
+    pub fn is_jsdoc(&self) -> bool {
+        let content = self.content.as_ref();
+        self.is_multi_line() && content.starts_with('*') && !content.starts_with("**")
+    }
+    /// Get the content of this comment with the leading
+    /// and trailing white space removed from each line,
+    /// `content` will always be the raw text between the
+    /// delimiters
+    pub fn trimmed(&self) -> String {
+        self.content
+            .as_ref()
+            .lines()
+            .map(str::trim)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+    /// Get JS text that would tokenize back into this
+    /// comment, unlike `to_string` this keeps the text
+    /// after an HTML comment's `-->`
+    pub fn to_source(&self) -> String {
+        match (self.kind, &self.tail_content) {
+            (CommentKind::Html, Some(tail)) => {
+                format!("<!--{}-->{}", self.content.as_ref(), tail.as_ref())
+            }
+            (CommentKind::Multi, Some(tail)) => {
+                format!("/*{}*/-->{}", self.content.as_ref(), tail.as_ref())
+            }
+            (CommentKind::Html, None) => format!("<!--{}", self.content.as_ref()),
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl Comment<&str> {
+    /// Copy the content of this comment into
+    /// an owned `String`
+    pub fn to_owned(&self) -> Comment<String> {
+        Comment::from_parts(
+            self.content.to_string(),
+            self.kind,
+            self.tail_content.map(String::from),
+        )
+    }
+}
+
+impl<T> ::std::fmt::Display for Comment<T>
+where
+    T: AsRef<str>,
+{
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match self.kind {
-            CommentKind::Single => format!("//{}", self.content.as_ref()),
-            CommentKind::Multi => format!("/*{}*/", self.content.as_ref()),
-            CommentKind::Html => format!("<!--{}-->", self.content.as_ref()),
-            CommentKind::Hashbang => format!("#!{}", self.content.as_ref()),
+            CommentKind::Single => write!(f, "//{}", self.content.as_ref()),
+            CommentKind::Multi => write!(f, "/*{}*/", self.content.as_ref()),
+            CommentKind::Html => write!(f, "<!--{}-->", self.content.as_ref()),
+            CommentKind::Hashbang => write!(f, "#!{}", self.content.as_ref()),
         }
     }
 }
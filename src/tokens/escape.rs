@@ -0,0 +1,151 @@
+use std::borrow::Cow;
+
+/// The value of a single escape sequence
+/// in a string or template literal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Escape {
+    /// An escape that represents a single character,
+    /// this includes the single character escapes (`\n`),
+    /// hex (`\x41`), unicode (`\u0041`, `\u{41}`) and
+    /// any other character escaping itself (`\q`)
+    Char(char),
+    /// A unicode escape for one half of a surrogate pair,
+    /// (`\uD83D`), these can't be represented by a `char`
+    CodeUnit(u16),
+    /// A legacy octal escape (`\101`) or `\8`/`\9`, these
+    /// are only allowed in sloppy mode strings
+    Legacy(char),
+    /// A `\` followed by a line terminator, this
+    /// doesn't add anything to the value
+    LineContinuation,
+}
+
+/// Parse the escape sequence at the start of `text`, which
+/// should be the text directly after a `\`, returning the
+/// value and the number of bytes it covers.
+///
+/// When `allow_legacy` is false, legacy octal escapes are
+/// treated as invalid, this is the case for strict mode
+/// strings and template literals.
+///
+/// ```
+/// # use ress::tokens::{escape_sequence, Escape};
+/// assert_eq!(escape_sequence("n", false), Some((Escape::Char('\n'), 1)));
+/// assert_eq!(escape_sequence("u{1F600}", false), Some((Escape::Char('😀'), 8)));
+/// assert_eq!(escape_sequence("101", true), Some((Escape::Legacy('A'), 3)));
+/// assert_eq!(escape_sequence("101", false), None);
+/// assert_eq!(escape_sequence("xZ", false), None);
+/// ```
+pub fn escape_sequence(text: &str, allow_legacy: bool) -> Option<(Escape, usize)> {
+    let c = text.chars().next()?;
+    let single = |c| Some((Escape::Char(c), 1));
+    match c {
+        'b' => single('\u{8}'),
+        'f' => single('\u{c}'),
+        'n' => single('\n'),
+        'r' => single('\r'),
+        't' => single('\t'),
+        'v' => single('\u{b}'),
+        '0' if !text[1..].starts_with(|c: char| c.is_ascii_digit()) => single('\0'),
+        '0'..='9' if allow_legacy => Some(legacy(text)),
+        '0'..='9' => None,
+        'x' => {
+            let code = hex(text.get(1..3)?)?;
+            Some((Escape::Char(std::char::from_u32(code)?), 3))
+        }
+        'u' => unicode(&text[1..]).map(|(e, len)| (e, len + 1)),
+        '\r' if text[1..].starts_with('\n') => Some((Escape::LineContinuation, 2)),
+        '\r' | '\n' | '\u{2028}' | '\u{2029}' => Some((Escape::LineContinuation, c.len_utf8())),
+        _ => Some((Escape::Char(c), c.len_utf8())),
+    }
+}
+
+/// Replace every escape sequence in `raw` with the value
+/// it represents, when `template` is true a `\r\n` or `\r`
+/// also becomes a `\n`. Nothing is allocated unless there
+/// is something to replace.
+///
+/// A surrogate pair written as two escapes (`\uD83D\uDE00`)
+/// becomes a single character, any other surrogate can't be
+/// part of a `str` so it is replaced by U+FFFD. This will be
+/// `None` if any escape sequence is invalid.
+pub(crate) fn cook(raw: &str, allow_legacy: bool, template: bool) -> Option<Cow<'_, str>> {
+    let changed = raw.contains('\\') || (template && raw.contains('\r'));
+    if !changed {
+        return Some(Cow::Borrowed(raw));
+    }
+    let mut ret = String::with_capacity(raw.len());
+    let mut high_surrogate = None;
+    let mut rest = raw;
+    while let Some(c) = rest.chars().next() {
+        let (escape, len) = match c {
+            '\\' => {
+                let (escape, len) = escape_sequence(&rest[1..], allow_legacy)?;
+                (escape, len + 1)
+            }
+            '\r' if template && rest[1..].starts_with('\n') => (Escape::Char('\n'), 2),
+            '\r' if template => (Escape::Char('\n'), 1),
+            _ => (Escape::Char(c), c.len_utf8()),
+        };
+        rest = &rest[len..];
+        if let Some(high) = high_surrogate.take() {
+            if let Escape::CodeUnit(low @ 0xDC00..=0xDFFF) = escape {
+                let c = 0x10000 + ((high as u32 - 0xD800) << 10) + (low as u32 - 0xDC00);
+                ret.push(std::char::from_u32(c)?);
+                continue;
+            }
+            ret.push(std::char::REPLACEMENT_CHARACTER);
+        }
+        match escape {
+            Escape::Char(c) | Escape::Legacy(c) => ret.push(c),
+            Escape::CodeUnit(high @ 0xD800..=0xDBFF) => high_surrogate = Some(high),
+            Escape::CodeUnit(_) => ret.push(std::char::REPLACEMENT_CHARACTER),
+            Escape::LineContinuation => (),
+        }
+    }
+    if high_surrogate.is_some() {
+        ret.push(std::char::REPLACEMENT_CHARACTER);
+    }
+    Some(Cow::Owned(ret))
+}
+
+/// The part of a unicode escape after the `u`
+fn unicode(text: &str) -> Option<(Escape, usize)> {
+    let (code, len) = if let Some(braced) = text.strip_prefix('{') {
+        let end = braced.find('}')?;
+        (hex(&braced[..end])?, end + 2)
+    } else {
+        (hex(text.get(..4)?)?, 4)
+    };
+    let escape = match std::char::from_u32(code) {
+        Some(c) => Escape::Char(c),
+        None if code <= 0xFFFF => Escape::CodeUnit(code as u16),
+        None => return None,
+    };
+    Some((escape, len))
+}
+
+/// The legacy octal escapes, these can be between 1 and
+/// 3 digits as long as the value stays under 256
+fn legacy(text: &str) -> (Escape, usize) {
+    let bytes = text.as_bytes();
+    let first = bytes[0];
+    if first == b'8' || first == b'9' {
+        return (Escape::Legacy(first as char), 1);
+    }
+    let max = if first <= b'3' { 3 } else { 2 };
+    let len = bytes
+        .iter()
+        .take(max)
+        .take_while(|b| (b'0'..=b'7').contains(b))
+        .count();
+    let code = u32::from_str_radix(&text[..len], 8).unwrap_or(0);
+    (Escape::Legacy(code as u8 as char), len)
+}
+
+fn hex(digits: &str) -> Option<u32> {
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(digits, 16).ok()
+}
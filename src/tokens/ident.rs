@@ -1,3 +1,48 @@
+use std::borrow::Cow;
+
+/// The ReservedWord production from the spec,
+/// this includes the literals `null`, `true` and `false`
+const RESERVED_WORDS: &[&str] = &[
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "new",
+    "null",
+    "return",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
 #[derive(Debug, PartialEq, Clone)]
 /// An identifier
 pub struct Ident<T>(T);
@@ -26,20 +71,88 @@ impl<'a> From<&'a str> for Ident<&'a str> {
     }
 }
 
-impl<T> ToString for Ident<T>
+impl<T> ::std::fmt::Display for Ident<T>
 where
     T: AsRef<str>,
 {
-    fn to_string(&self) -> String {
-        self.0.as_ref().to_string()
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_str(self.0.as_ref())
     }
 }
 
-impl<T> Into<String> for Ident<T>
+impl Ident<&str> {
+    /// Copy this identifier into an owned `String`
+    pub fn to_owned(&self) -> Ident<String> {
+        Ident(self.0.to_string())
+    }
+}
+
+impl<T> From<Ident<T>> for String
 where
     T: ToString,
 {
-    fn into(self) -> String {
-        self.0.to_string()
+    fn from(id: Ident<T>) -> String {
+        id.0.to_string()
+    }
+}
+
+impl<T> Ident<T>
+where
+    T: AsRef<str>,
+{
+    /// Check if this identifier contains
+    /// any `\u` escape sequences
+    pub fn has_unicode_escape(&self) -> bool {
+        self.0.as_ref().contains("\\u")
+    }
+    /// Check if this identifier is spelled like a
+    /// reserved word, this is the case for a keyword
+    /// used as a property name (`obj.class`) or one
+    /// written with escapes (`\\u0069f`)
+    pub fn is_reserved_word(&self) -> bool {
+        RESERVED_WORDS.contains(&self.decoded().as_ref())
+    }
+    /// Get the value of this identifier with any
+    /// `\uHHHH` or `\u{H...}` escapes replaced
+    /// by the character they represent. Malformed
+    /// escapes are left as-is.
+    pub fn decoded(&self) -> Cow<'_, str> {
+        let raw = self.0.as_ref();
+        if !self.has_unicode_escape() {
+            return Cow::Borrowed(raw);
+        }
+        let mut ret = String::with_capacity(raw.len());
+        let mut rest = raw;
+        while let Some(idx) = rest.find("\\u") {
+            ret.push_str(&rest[..idx]);
+            rest = &rest[idx..];
+            if let Some((c, len)) = Self::decode_escape(&rest[2..]) {
+                ret.push(c);
+                rest = &rest[2 + len..];
+            } else {
+                ret.push_str(&rest[..2]);
+                rest = &rest[2..];
+            }
+        }
+        ret.push_str(rest);
+        Cow::Owned(ret)
+    }
+    /// Decode the hex portion of a unicode escape,
+    /// returning the character and the number of
+    /// bytes consumed
+    fn decode_escape(s: &str) -> Option<(char, usize)> {
+        let (hex, len) = if let Some(braced) = s.strip_prefix('{') {
+            let end = braced.find('}')?;
+            (&braced[..end], end + 2)
+        } else {
+            (s.get(..4)?, 4)
+        };
+        if hex.is_empty() || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let c = u32::from_str_radix(hex, 16)
+            .ok()
+            .and_then(std::char::from_u32)?;
+        Some((c, len))
     }
 }
@@ -0,0 +1,76 @@
+#[derive(Debug, PartialEq, Clone)]
+/// The parts of a JSX element that aren't regular
+/// JS tokens, these are only produced when
+/// `Scanner::jsx` is enabled.
+///
+/// The `=`, `.` and `:` inside of a tag are
+/// still `Punct`s, attribute values in quotes
+/// are `String`s and the braces around an
+/// expression are `OpenBrace` and `CloseBrace`
+pub enum Jsx<T> {
+    /// The `<` that starts an opening tag
+    TagStart,
+    /// The `</` that starts a closing tag
+    ClosingTagStart,
+    /// The `>` that ends either kind of tag
+    TagEnd,
+    /// The `/>` that ends a self-closing tag
+    SelfClosingTagEnd,
+    /// An element or attribute name, unlike an
+    /// identifier this can include `-` (`data-id`)
+    Name(T),
+    /// The text between tags, exactly as it is
+    /// written including any white space
+    Text(T),
+}
+
+impl<T> Jsx<T> {
+    pub fn is_tag_start(&self) -> bool {
+        matches!(self, Jsx::TagStart)
+    }
+    pub fn is_closing_tag_start(&self) -> bool {
+        matches!(self, Jsx::ClosingTagStart)
+    }
+    pub fn is_tag_end(&self) -> bool {
+        matches!(self, Jsx::TagEnd)
+    }
+    pub fn is_self_closing_tag_end(&self) -> bool {
+        matches!(self, Jsx::SelfClosingTagEnd)
+    }
+    pub fn is_name(&self) -> bool {
+        matches!(self, Jsx::Name(_))
+    }
+    pub fn is_text(&self) -> bool {
+        matches!(self, Jsx::Text(_))
+    }
+}
+
+impl Jsx<&str> {
+    /// Copy this JSX token into one that owns its text
+    pub fn to_owned(&self) -> Jsx<String> {
+        match self {
+            Jsx::TagStart => Jsx::TagStart,
+            Jsx::ClosingTagStart => Jsx::ClosingTagStart,
+            Jsx::TagEnd => Jsx::TagEnd,
+            Jsx::SelfClosingTagEnd => Jsx::SelfClosingTagEnd,
+            Jsx::Name(n) => Jsx::Name(n.to_string()),
+            Jsx::Text(t) => Jsx::Text(t.to_string()),
+        }
+    }
+}
+
+impl<T> ::std::fmt::Display for Jsx<T>
+where
+    T: AsRef<str>,
+{
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match self {
+            Jsx::TagStart => f.write_str("<"),
+            Jsx::ClosingTagStart => f.write_str("</"),
+            Jsx::TagEnd => f.write_str(">"),
+            Jsx::SelfClosingTagEnd => f.write_str("/>"),
+            Jsx::Name(n) => f.write_str(n.as_ref()),
+            Jsx::Text(t) => f.write_str(t.as_ref()),
+        }
+    }
+}
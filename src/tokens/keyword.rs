@@ -1,3 +1,6 @@
+use crate::error::UnknownToken;
+use crate::tokens::Ident;
+
 #[derive(Debug)]
 /// A JS Keyword
 ///
@@ -45,6 +48,12 @@
 /// interface
 /// private (40)
 /// public
+/// # Accessors
+/// get
+/// set
+///
+/// These are only produced by a `Scanner` with
+/// `accessor_keywords` turned on
 pub enum Keyword<T> {
     Await(T),
     Break(T),
@@ -64,6 +73,7 @@ pub enum Keyword<T> {
     Finally(T),
     For(T),
     Function(T),
+    Get(T),
     If(T),
     Implements(T),
     Import(T),
@@ -77,6 +87,7 @@ pub enum Keyword<T> {
     Protected(T),
     Public(T),
     Return(T),
+    Set(T),
     Static(T),
     Super(T),
     Switch(T),
@@ -115,6 +126,7 @@ where
             Self::Finally(i) => Self::Finally(i.clone()),
             Self::For(i) => Self::For(i.clone()),
             Self::Function(i) => Self::Function(i.clone()),
+            Self::Get(i) => Self::Get(i.clone()),
             Self::If(i) => Self::If(i.clone()),
             Self::Implements(i) => Self::Implements(i.clone()),
             Self::Import(i) => Self::Import(i.clone()),
@@ -128,6 +140,7 @@ where
             Self::Protected(i) => Self::Protected(i.clone()),
             Self::Public(i) => Self::Public(i.clone()),
             Self::Return(i) => Self::Return(i.clone()),
+            Self::Set(i) => Self::Set(i.clone()),
             Self::Static(i) => Self::Static(i.clone()),
             Self::Super(i) => Self::Super(i.clone()),
             Self::Switch(i) => Self::Switch(i.clone()),
@@ -167,6 +180,7 @@ impl<T, U> PartialEq<Keyword<T>> for Keyword<U> {
                 | (Finally(_), Finally(_))
                 | (For(_), For(_))
                 | (Function(_), Function(_))
+                | (Get(_), Get(_))
                 | (If(_), If(_))
                 | (Implements(_), Implements(_))
                 | (Import(_), Import(_))
@@ -180,6 +194,7 @@ impl<T, U> PartialEq<Keyword<T>> for Keyword<U> {
                 | (Protected(_), Protected(_))
                 | (Public(_), Public(_))
                 | (Return(_), Return(_))
+                | (Set(_), Set(_))
                 | (Static(_), Static(_))
                 | (Super(_), Super(_))
                 | (Switch(_), Switch(_))
@@ -197,7 +212,26 @@ impl<T, U> PartialEq<Keyword<T>> for Keyword<U> {
 }
 
 impl Keyword<()> {
+    /// Find the keyword an identifier's text spells, after
+    /// replacing any unicode escapes. This is how to tell
+    /// that an `Ident` like the `class` in `obj.class` is a
+    /// reserved word, `is_reserved`, `is_strict_reserved`
+    /// and `is_contextual` on the result tell if it can be
+    /// used as a binding.
+    ///
+    /// ```
+    /// # use ress::tokens::Keyword;
+    /// assert_eq!(Keyword::from_ident_text(r"\u0063lass"), Some(Keyword::Class(())));
+    /// assert_eq!(Keyword::from_ident_text("klass"), None);
+    /// ```
+    pub fn from_ident_text(text: &str) -> Option<Self> {
+        Ident::from(text).decoded().parse().ok()
+    }
     pub fn with_str(self, s: &str) -> Keyword<&str> {
+        self.with(s)
+    }
+    /// Attach any value to this keyword
+    pub fn with<T>(self, s: T) -> Keyword<T> {
         match self {
             Keyword::Await(_) => Keyword::Await(s),
             Keyword::Break(_) => Keyword::Break(s),
@@ -217,6 +251,7 @@ impl Keyword<()> {
             Keyword::Finally(_) => Keyword::Finally(s),
             Keyword::For(_) => Keyword::For(s),
             Keyword::Function(_) => Keyword::Function(s),
+            Keyword::Get(_) => Keyword::Get(s),
             Keyword::If(_) => Keyword::If(s),
             Keyword::Implements(_) => Keyword::Implements(s),
             Keyword::Import(_) => Keyword::Import(s),
@@ -230,6 +265,7 @@ impl Keyword<()> {
             Keyword::Protected(_) => Keyword::Protected(s),
             Keyword::Public(_) => Keyword::Public(s),
             Keyword::Return(_) => Keyword::Return(s),
+            Keyword::Set(_) => Keyword::Set(s),
             Keyword::Static(_) => Keyword::Static(s),
             Keyword::Super(_) => Keyword::Super(s),
             Keyword::Switch(_) => Keyword::Switch(s),
@@ -246,10 +282,11 @@ impl Keyword<()> {
     }
 }
 
-impl<T> ToString for Keyword<T> {
-    /// Convert a keyword into a string
-    fn to_string(&self) -> String {
-        self.as_str().into()
+impl<T> ::std::fmt::Display for Keyword<T> {
+    /// Write the keyword without any escapes
+    /// that were used in the source
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_str(self.as_str())
     }
 }
 
@@ -267,13 +304,10 @@ impl<T> Keyword<T> {
     /// - implements
     /// - super
     pub fn is_future_reserved(&self) -> bool {
-        match self {
-            Keyword::Enum(_) => true,
-            Keyword::Export(_) => true,
-            Keyword::Implements(_) => true,
-            Keyword::Super(_) => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            Keyword::Enum(_) | Keyword::Export(_) | Keyword::Implements(_) | Keyword::Super(_)
+        )
     }
     /// Is this keyword a reserved word when the context
     /// has a 'use strict' directive.
@@ -289,18 +323,39 @@ impl<T> Keyword<T> {
     /// - yield
     /// - let
     pub fn is_strict_reserved(&self) -> bool {
-        match self {
-            Keyword::Implements(_) => true,
-            Keyword::Interface(_) => true,
-            Keyword::Package(_) => true,
-            Keyword::Private(_) => true,
-            Keyword::Protected(_) => true,
-            Keyword::Public(_) => true,
-            Keyword::Static(_) => true,
-            Keyword::Yield(_) => true,
-            Keyword::Let(_) => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            Keyword::Implements(_)
+                | Keyword::Interface(_)
+                | Keyword::Package(_)
+                | Keyword::Private(_)
+                | Keyword::Protected(_)
+                | Keyword::Public(_)
+                | Keyword::Static(_)
+                | Keyword::Yield(_)
+                | Keyword::Let(_)
+        )
+    }
+    /// Is this keyword only reserved in some contexts,
+    /// outside of those it can be used as an identifier
+    ///
+    /// ## Keywords
+    /// - await (in modules and async functions)
+    /// - get (before an accessor's name)
+    /// - let (in strict mode and declarations)
+    /// - set (before an accessor's name)
+    /// - static (in strict mode and class bodies)
+    /// - yield (in strict mode and generators)
+    pub fn is_contextual(&self) -> bool {
+        matches!(
+            self,
+            Keyword::Await(_)
+                | Keyword::Get(_)
+                | Keyword::Let(_)
+                | Keyword::Set(_)
+                | Keyword::Static(_)
+                | Keyword::Yield(_)
+        )
     }
     /// Is this keyword a reserved word
     ///
@@ -388,6 +443,7 @@ impl<T> Keyword<T> {
             Keyword::Finally(_) => "finally",
             Keyword::For(_) => "for",
             Keyword::Function(_) => "function",
+            Keyword::Get(_) => "get",
             Keyword::If(_) => "if",
             Keyword::In(_) => "in",
             Keyword::Implements(_) => "implements",
@@ -401,6 +457,7 @@ impl<T> Keyword<T> {
             Keyword::Public(_) => "public",
             Keyword::Static(_) => "static",
             Keyword::Return(_) => "return",
+            Keyword::Set(_) => "set",
             Keyword::Super(_) => "super",
             Keyword::Switch(_) => "switch",
             Keyword::This(_) => "this",
@@ -415,6 +472,59 @@ impl<T> Keyword<T> {
         }
     }
 
+    /// The text of this keyword as it appeared in the
+    /// source, this may include unicode escapes (`\u0069f`)
+    /// unlike `as_str`
+    pub fn raw(&self) -> &T {
+        match self {
+            Keyword::Await(s) => s,
+            Keyword::Break(s) => s,
+            Keyword::Case(s) => s,
+            Keyword::Catch(s) => s,
+            Keyword::Class(s) => s,
+            Keyword::Const(s) => s,
+            Keyword::Continue(s) => s,
+            Keyword::Debugger(s) => s,
+            Keyword::Default(s) => s,
+            Keyword::Import(s) => s,
+            Keyword::Delete(s) => s,
+            Keyword::Do(s) => s,
+            Keyword::Else(s) => s,
+            Keyword::Enum(s) => s,
+            Keyword::Export(s) => s,
+            Keyword::Extends(s) => s,
+            Keyword::Finally(s) => s,
+            Keyword::For(s) => s,
+            Keyword::Function(s) => s,
+            Keyword::Get(s) => s,
+            Keyword::If(s) => s,
+            Keyword::In(s) => s,
+            Keyword::Implements(s) => s,
+            Keyword::InstanceOf(s) => s,
+            Keyword::Interface(s) => s,
+            Keyword::Let(s) => s,
+            Keyword::New(s) => s,
+            Keyword::Package(s) => s,
+            Keyword::Private(s) => s,
+            Keyword::Protected(s) => s,
+            Keyword::Public(s) => s,
+            Keyword::Static(s) => s,
+            Keyword::Return(s) => s,
+            Keyword::Set(s) => s,
+            Keyword::Super(s) => s,
+            Keyword::Switch(s) => s,
+            Keyword::This(s) => s,
+            Keyword::Throw(s) => s,
+            Keyword::Try(s) => s,
+            Keyword::TypeOf(s) => s,
+            Keyword::Var(s) => s,
+            Keyword::Void(s) => s,
+            Keyword::While(s) => s,
+            Keyword::With(s) => s,
+            Keyword::Yield(s) => s,
+        }
+    }
+
     pub fn to_empty(&self) -> Keyword<()> {
         match self {
             Keyword::Await(_) => Keyword::Await(()),
@@ -436,6 +546,7 @@ impl<T> Keyword<T> {
             Keyword::Finally(_) => Keyword::Finally(()),
             Keyword::For(_) => Keyword::For(()),
             Keyword::Function(_) => Keyword::Function(()),
+            Keyword::Get(_) => Keyword::Get(()),
             Keyword::If(_) => Keyword::If(()),
             Keyword::In(_) => Keyword::In(()),
             Keyword::Implements(_) => Keyword::Implements(()),
@@ -449,6 +560,7 @@ impl<T> Keyword<T> {
             Keyword::Public(_) => Keyword::Public(()),
             Keyword::Static(_) => Keyword::Static(()),
             Keyword::Return(_) => Keyword::Return(()),
+            Keyword::Set(_) => Keyword::Set(()),
             Keyword::Super(_) => Keyword::Super(()),
             Keyword::Switch(_) => Keyword::Switch(()),
             Keyword::This(_) => Keyword::This(()),
@@ -464,7 +576,12 @@ impl<T> Keyword<T> {
     }
 }
 
-impl<'a> Keyword<&'a str> {
+impl Keyword<&str> {
+    /// Copy the text of this keyword into
+    /// an owned `String`
+    pub fn to_owned(&self) -> Keyword<String> {
+        self.to_empty().with(self.raw().to_string())
+    }
     #[cfg(test)]
     pub fn new(s: &str) -> Self {
         match s {
@@ -487,6 +604,7 @@ impl<'a> Keyword<&'a str> {
             "finally" => Keyword::Finally("finally"),
             "for" => Keyword::For("for"),
             "function" => Keyword::Function("function"),
+            "get" => Keyword::Get("get"),
             "if" => Keyword::If("if"),
             "in" => Keyword::In("in"),
             "implements" => Keyword::Implements("implements"),
@@ -500,6 +618,7 @@ impl<'a> Keyword<&'a str> {
             "public" => Keyword::Public("public"),
             "static" => Keyword::Static("static"),
             "return" => Keyword::Return("return"),
+            "set" => Keyword::Set("set"),
             "super" => Keyword::Super("super"),
             "switch" => Keyword::Switch("switch"),
             "this" => Keyword::This("this"),
@@ -515,51 +634,63 @@ impl<'a> Keyword<&'a str> {
         }
     }
     pub fn has_unicode_escape(&self) -> bool {
-        match self {
-            Keyword::Await(s) => s,
-            Keyword::Break(s) => s,
-            Keyword::Case(s) => s,
-            Keyword::Catch(s) => s,
-            Keyword::Class(s) => s,
-            Keyword::Const(s) => s,
-            Keyword::Continue(s) => s,
-            Keyword::Debugger(s) => s,
-            Keyword::Default(s) => s,
-            Keyword::Import(s) => s,
-            Keyword::Delete(s) => s,
-            Keyword::Do(s) => s,
-            Keyword::Else(s) => s,
-            Keyword::Enum(s) => s,
-            Keyword::Export(s) => s,
-            Keyword::Extends(s) => s,
-            Keyword::Finally(s) => s,
-            Keyword::For(s) => s,
-            Keyword::Function(s) => s,
-            Keyword::If(s) => s,
-            Keyword::In(s) => s,
-            Keyword::Implements(s) => s,
-            Keyword::InstanceOf(s) => s,
-            Keyword::Interface(s) => s,
-            Keyword::Let(s) => s,
-            Keyword::New(s) => s,
-            Keyword::Package(s) => s,
-            Keyword::Private(s) => s,
-            Keyword::Protected(s) => s,
-            Keyword::Public(s) => s,
-            Keyword::Static(s) => s,
-            Keyword::Return(s) => s,
-            Keyword::Super(s) => s,
-            Keyword::Switch(s) => s,
-            Keyword::This(s) => s,
-            Keyword::Throw(s) => s,
-            Keyword::Try(s) => s,
-            Keyword::TypeOf(s) => s,
-            Keyword::Var(s) => s,
-            Keyword::Void(s) => s,
-            Keyword::While(s) => s,
-            Keyword::With(s) => s,
-            Keyword::Yield(s) => s,
-        }
-        .contains("\\u")
+        self.raw().contains("\\u")
+    }
+}
+
+impl ::std::str::FromStr for Keyword<()> {
+    type Err = UnknownToken;
+    /// Parse a keyword without any escapes, the
+    /// inverse of its `Display` implementation. Use
+    /// `with` to attach the text it came from
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "await" => Keyword::Await(()),
+            "break" => Keyword::Break(()),
+            "case" => Keyword::Case(()),
+            "catch" => Keyword::Catch(()),
+            "class" => Keyword::Class(()),
+            "const" => Keyword::Const(()),
+            "continue" => Keyword::Continue(()),
+            "debugger" => Keyword::Debugger(()),
+            "default" => Keyword::Default(()),
+            "import" => Keyword::Import(()),
+            "delete" => Keyword::Delete(()),
+            "do" => Keyword::Do(()),
+            "else" => Keyword::Else(()),
+            "enum" => Keyword::Enum(()),
+            "export" => Keyword::Export(()),
+            "extends" => Keyword::Extends(()),
+            "finally" => Keyword::Finally(()),
+            "for" => Keyword::For(()),
+            "function" => Keyword::Function(()),
+            "get" => Keyword::Get(()),
+            "if" => Keyword::If(()),
+            "in" => Keyword::In(()),
+            "implements" => Keyword::Implements(()),
+            "instanceof" => Keyword::InstanceOf(()),
+            "interface" => Keyword::Interface(()),
+            "let" => Keyword::Let(()),
+            "new" => Keyword::New(()),
+            "package" => Keyword::Package(()),
+            "private" => Keyword::Private(()),
+            "protected" => Keyword::Protected(()),
+            "public" => Keyword::Public(()),
+            "static" => Keyword::Static(()),
+            "return" => Keyword::Return(()),
+            "set" => Keyword::Set(()),
+            "super" => Keyword::Super(()),
+            "switch" => Keyword::Switch(()),
+            "this" => Keyword::This(()),
+            "throw" => Keyword::Throw(()),
+            "try" => Keyword::Try(()),
+            "typeof" => Keyword::TypeOf(()),
+            "var" => Keyword::Var(()),
+            "void" => Keyword::Void(()),
+            "while" => Keyword::While(()),
+            "with" => Keyword::With(()),
+            "yield" => Keyword::Yield(()),
+            _ => return Err(UnknownToken(s.to_string())),
+        })
     }
 }
@@ -1,6 +1,8 @@
 mod boolean;
 mod comment;
+mod escape;
 mod ident;
+mod jsx;
 mod keyword;
 mod number;
 mod regex;
@@ -9,27 +11,33 @@ mod template;
 
 pub mod prelude {
     pub use super::{
-        Boolean, Comment, Ident, Keyword, Number, Punct, RegEx, StringLit, Template,
-        TemplateLiteral, Token,
+        Boolean, Comment, Ident, Jsx, Keyword, Number, Punct, RegEx, StringLit, Template,
+        TemplateLiteral, Token, TokenKind,
     };
 }
 
 pub use boolean::Boolean;
 pub use comment::{Comment, CommentKind};
+pub use escape::{escape_sequence, Escape};
 pub use ident::Ident;
+pub use jsx::Jsx;
 pub use keyword::Keyword;
-pub use number::{Number, NumberKind};
+pub use number::{DecimalParts, Number, NumberKind};
 pub use regex::RegEx;
 pub use string::{InnerString, StringLit};
 pub use template::{Template, TemplateLiteral};
 
+use crate::error::UnknownToken;
+
 #[derive(PartialEq, Clone, Debug)]
 /// The representation of any single
 /// JS part
 pub enum Token<T> {
     /// `true` of `false`
     Boolean(Boolean),
-    /// The end of the file
+    /// The end of the file, the `Item` for this
+    /// always has an empty span at the length of
+    /// the text
     EoF,
     /// An identifier this will be either a variable name
     /// or a function/method name
@@ -64,6 +72,34 @@ pub enum Token<T> {
     /// */
     /// ```
     Comment(Comment<T>),
+    /// Text that couldn't be tokenized, this is only
+    /// produced when `Scanner::recover_errors` is on
+    Invalid(T),
+    /// A part of a JSX element, this is only
+    /// produced when `Scanner::jsx` is on
+    /// ```jsx
+    /// <div className="x">{y}</div>
+    /// ```
+    Jsx(Jsx<T>),
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+/// Which variant of `Token` something is
+/// without any of its associated value
+pub enum TokenKind {
+    Boolean,
+    EoF,
+    Ident,
+    Keyword,
+    Null,
+    Number,
+    Punct,
+    String,
+    RegEx,
+    Template,
+    Comment,
+    Invalid,
+    Jsx,
 }
 
 impl<T> PartialEq<&str> for Token<T>
@@ -73,7 +109,7 @@ where
     fn eq(&self, other: &&str) -> bool {
         match self {
             Token::Boolean(b) => b.eq(*other),
-            Token::EoF => (*other).eq(""),
+            Token::EoF => other.is_empty(),
             Token::Ident(s) => s.eq(other),
             Token::Keyword(k) => k.as_str().eq(*other),
             Token::Null => (*other).eq("null"),
@@ -96,6 +132,26 @@ impl<T> PartialEq<bool> for Token<T> {
 }
 
 impl<T> Token<T> {
+    /// Get the `TokenKind` for this token
+    pub fn kind(&self) -> TokenKind {
+        match self {
+            Token::Boolean(_) => TokenKind::Boolean,
+            Token::EoF => TokenKind::EoF,
+            Token::Ident(_) => TokenKind::Ident,
+            Token::Keyword(_) => TokenKind::Keyword,
+            Token::Null => TokenKind::Null,
+            Token::Number(_) => TokenKind::Number,
+            Token::Punct(_) => TokenKind::Punct,
+            Token::String(_) => TokenKind::String,
+            Token::RegEx(_) => TokenKind::RegEx,
+            Token::Template(_) => TokenKind::Template,
+            Token::Comment(_) => TokenKind::Comment,
+            Token::Invalid(_) => TokenKind::Invalid,
+            Token::Jsx(_) => TokenKind::Jsx,
+        }
+    }
+    /// Check if this token is a `true` or `false` literal
+    #[inline]
     pub fn is_boolean(&self) -> bool {
         matches!(self, Token::Boolean(_))
     }
@@ -114,12 +170,18 @@ impl<T> Token<T> {
             _ => false,
         }
     }
+    /// Check if this token is the end of the input
+    #[inline]
     pub fn is_eof(&self) -> bool {
         matches!(self, Token::EoF)
     }
+    /// Check if this token is an identifier
+    #[inline]
     pub fn is_ident(&self) -> bool {
         matches!(self, Token::Ident(_))
     }
+    /// Check if this token is a reserved word
+    #[inline]
     pub fn is_keyword(&self) -> bool {
         matches!(self, Token::Keyword(_))
     }
@@ -129,17 +191,23 @@ impl<T> Token<T> {
             _ => false,
         }
     }
-
+    /// Check if this token is the `null` literal
+    #[inline]
     pub fn is_null(&self) -> bool {
         matches!(self, Token::Null)
     }
+    /// Check if this token is a numeric literal
+    #[inline]
     pub fn is_number(&self) -> bool {
         matches!(self, Token::Number(_))
     }
-
+    /// Check if this token is a punctuation mark
+    #[inline]
     pub fn is_punct(&self) -> bool {
         matches!(self, Token::Punct(_))
     }
+    /// Check if this token is a single or double quoted string literal
+    #[inline]
     pub fn is_string(&self) -> bool {
         matches!(self, Token::String(_))
     }
@@ -155,9 +223,13 @@ impl<T> Token<T> {
             _ => false,
         }
     }
+    /// Check if this token is a regular expression literal
+    #[inline]
     pub fn is_regex(&self) -> bool {
         matches!(self, Token::RegEx(_))
     }
+    /// Check if this token is any part of a template literal
+    #[inline]
     pub fn is_template(&self) -> bool {
         matches!(self, Token::Template(_))
     }
@@ -196,9 +268,22 @@ impl<T> Token<T> {
                 | Token::Template(_)
         )
     }
+    /// Check if this token is a comment
+    #[inline]
     pub fn is_comment(&self) -> bool {
         matches!(self, Token::Comment(_))
     }
+    /// Check if this token is text that was
+    /// skipped while recovering from an error
+    #[inline]
+    pub fn is_invalid(&self) -> bool {
+        matches!(self, Token::Invalid(_))
+    }
+    /// Check if this token is a part of a JSX element
+    #[inline]
+    pub fn is_jsx(&self) -> bool {
+        matches!(self, Token::Jsx(_))
+    }
     pub fn is_multi_line_comment(&self) -> bool {
         match self {
             Token::Comment(ref t) => t.kind == CommentKind::Multi,
@@ -239,6 +324,21 @@ impl<T> Token<T> {
             _ => false,
         }
     }
+    /// Check if this token is any one of `keywords`, the
+    /// text of the keywords is ignored so `()` works for
+    /// building the list
+    /// ```
+    /// # use ress::prelude::*;
+    /// let ret = Token::Keyword(Keyword::Return("return"));
+    /// assert!(ret.matches_any_keyword(&[Keyword::Throw(()), Keyword::Return(())]));
+    /// assert!(!ret.matches_any_keyword(&[Keyword::Case(())]));
+    /// ```
+    pub fn matches_any_keyword<K>(&self, keywords: &[Keyword<K>]) -> bool {
+        match self {
+            Token::Keyword(k) => keywords.iter().any(|other| k.eq(other)),
+            _ => false,
+        }
+    }
 
     pub fn matches_punct(&self, p: Punct) -> bool {
         match self {
@@ -252,6 +352,41 @@ impl<T> Token<T> {
             _ => false,
         }
     }
+    /// Check if this token is any one of `puncts`
+    /// ```
+    /// # use ress::prelude::*;
+    /// let semi = Token::<&str>::Punct(Punct::SemiColon);
+    /// assert!(semi.matches_any_punct(&[Punct::SemiColon, Punct::CloseBrace]));
+    /// assert!(!semi.matches_any_punct(&[]));
+    /// ```
+    pub fn matches_any_punct(&self, puncts: &[Punct]) -> bool {
+        match self {
+            Token::Punct(p) => puncts.contains(p),
+            _ => false,
+        }
+    }
+    /// Get the `Punct` this token represents, if it is
+    /// a punctuation token
+    pub fn to_punct(&self) -> Option<Punct> {
+        match self {
+            Token::Punct(p) => Some(*p),
+            _ => None,
+        }
+    }
+}
+
+impl<T> Token<T>
+where
+    T: Clone,
+{
+    /// Get a copy of the `Keyword` this token represents,
+    /// if it is a keyword token
+    pub fn to_keyword(&self) -> Option<Keyword<T>> {
+        match self {
+            Token::Keyword(k) => Some(k.clone()),
+            _ => None,
+        }
+    }
 }
 
 impl<T> Token<T>
@@ -265,6 +400,17 @@ where
         }
     }
 
+    /// Check if this token is an identifier spelled like
+    /// a reserved word, the scanner produces these for
+    /// property names (`obj.class`). Use
+    /// `Keyword::from_ident_text` to find out which word it is.
+    pub fn ident_is_reserved_word(&self) -> bool {
+        match self {
+            Token::Ident(ref i) => i.is_reserved_word(),
+            _ => false,
+        }
+    }
+
     pub fn is_hex_literal(&self) -> bool {
         match self {
             Token::Number(ref n) => n.is_hex(),
@@ -314,25 +460,113 @@ where
             _ => false,
         }
     }
+    /// Get JS text that would tokenize back into this token.
+    /// This is the same as `to_string` except keywords keep
+    /// any unicode escapes and HTML comments keep the text
+    /// after their `-->`
+    pub fn to_source(&self) -> String {
+        match self {
+            Token::Keyword(k) => k.raw().as_ref().to_string(),
+            Token::Comment(c) => c.to_source(),
+            _ => self.to_string(),
+        }
+    }
+    /// Get the text of this token if it is an identifier
+    /// ```
+    /// # use ress::prelude::*;
+    /// let token = Scanner::new("thing").next().unwrap().unwrap().token;
+    /// assert_eq!(token.as_ident(), Some("thing"));
+    /// assert_eq!(token.as_number(), None);
+    /// ```
+    pub fn as_ident(&self) -> Option<&str> {
+        match self {
+            Token::Ident(i) => Some(i.as_ref()),
+            _ => None,
+        }
+    }
+    /// Get the text of this token if it is a number
+    /// ```
+    /// # use ress::prelude::*;
+    /// let token = Scanner::new("0x1f").next().unwrap().unwrap().token;
+    /// assert_eq!(token.as_number(), Some("0x1f"));
+    /// ```
+    pub fn as_number(&self) -> Option<&str> {
+        match self {
+            Token::Number(n) => Some(n.as_ref()),
+            _ => None,
+        }
+    }
+    /// Get the unquoted content of this token if
+    /// it is a string literal
+    /// ```
+    /// # use ress::prelude::*;
+    /// let token = Scanner::new("'a\\nb'").next().unwrap().unwrap().token;
+    /// assert_eq!(token.as_string(), Some("a\\nb"));
+    /// ```
+    pub fn as_string(&self) -> Option<&str> {
+        match self {
+            Token::String(s) => Some(s.as_ref()),
+            _ => None,
+        }
+    }
+    /// Get the body and flags of this token if it
+    /// is a regular expression literal
+    /// ```
+    /// # use ress::prelude::*;
+    /// let mut scanner = Scanner::new("x = /a+/g");
+    /// let re = scanner.nth(2).unwrap().unwrap().token;
+    /// let re = re.as_regex().unwrap();
+    /// assert_eq!(re.body, "a+");
+    /// assert_eq!(re.flags, Some("g"));
+    /// ```
+    pub fn as_regex(&self) -> Option<&RegEx<T>> {
+        match self {
+            Token::RegEx(r) => Some(r),
+            _ => None,
+        }
+    }
+    /// Get the `Keyword` this token represents without
+    /// copying it, see `to_keyword` for an owned copy.
+    ///
+    /// This returns a reference rather than a `Keyword<T>`
+    /// on purpose. A keyword carries its raw text, which may
+    /// be an escaped spelling (`\u0069f`) and, for
+    /// `Token<String>`, would have to be cloned. Use
+    /// `Keyword::to_empty` for a `Keyword<()>` that only
+    /// names which keyword it is.
+    /// ```
+    /// # use ress::prelude::*;
+    /// let token = Scanner::new("while").next().unwrap().unwrap().token;
+    /// assert_eq!(token.as_keyword(), Some(&Keyword::While("while")));
+    /// assert_eq!(token.as_keyword().map(Keyword::to_empty), Some(Keyword::While(())));
+    /// ```
+    pub fn as_keyword(&self) -> Option<&Keyword<T>> {
+        match self {
+            Token::Keyword(k) => Some(k),
+            _ => None,
+        }
+    }
 }
 
-impl<T> ToString for Token<T>
+impl<T> ::std::fmt::Display for Token<T>
 where
     T: AsRef<str>,
 {
-    fn to_string(&self) -> String {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match self {
-            Token::Boolean(ref b) => b.to_string(),
-            Token::Comment(ref c) => c.to_string(),
-            Token::EoF => String::new(),
-            Token::Ident(ref i) => i.to_string(),
-            Token::Keyword(ref k) => k.to_string(),
-            Token::Null => "null".to_string(),
-            Token::Number(ref n) => n.to_string(),
-            Token::Punct(ref p) => p.to_string(),
-            Token::RegEx(ref r) => r.to_string(),
-            Token::String(ref s) => s.to_string(),
-            Token::Template(ref t) => t.to_string(),
+            Token::Boolean(ref b) => b.fmt(f),
+            Token::Comment(ref c) => c.fmt(f),
+            Token::EoF => Ok(()),
+            Token::Ident(ref i) => i.fmt(f),
+            Token::Keyword(ref k) => k.fmt(f),
+            Token::Null => f.write_str("null"),
+            Token::Number(ref n) => n.fmt(f),
+            Token::Punct(ref p) => p.fmt(f),
+            Token::RegEx(ref r) => r.fmt(f),
+            Token::String(ref s) => s.fmt(f),
+            Token::Template(ref t) => t.fmt(f),
+            Token::Invalid(ref s) => f.write_str(s.as_ref()),
+            Token::Jsx(ref j) => j.fmt(f),
         }
     }
 }
@@ -359,6 +593,7 @@ pub enum Punct {
     DoubleDash,
     DashEqual,
     DoubleAmpersand,
+    DoubleAmpersandEqual,
     DoubleAsterisk,
     DoubleAsteriskEqual,
     DoubleEqual,
@@ -367,6 +602,7 @@ pub enum Punct {
     DoubleLessThan,
     DoubleLessThanEqual,
     DoublePipe,
+    DoublePipeEqual,
     DoublePlus,
     Ellipsis,
     Equal,
@@ -389,6 +625,7 @@ pub enum Punct {
     Plus,
     PlusEqual,
     QuestionMark,
+    QuestionMarkDot,
     SemiColon,
     Tilde,
     TripleEqual,
@@ -402,6 +639,74 @@ impl PartialEq<str> for Punct {
 }
 
 impl Punct {
+    /// Check if this is an assignment operator,
+    /// `=` or any compound assignment like `+=` or `&&=`
+    pub fn is_assignment(self) -> bool {
+        matches!(
+            self,
+            Punct::Equal
+                | Punct::PlusEqual
+                | Punct::DashEqual
+                | Punct::AsteriskEqual
+                | Punct::DoubleAsteriskEqual
+                | Punct::ForwardSlashEqual
+                | Punct::PercentEqual
+                | Punct::DoubleLessThanEqual
+                | Punct::DoubleGreaterThanEqual
+                | Punct::TripleGreaterThanEqual
+                | Punct::AmpersandEqual
+                | Punct::PipeEqual
+                | Punct::CaretEqual
+                | Punct::DoubleAmpersandEqual
+                | Punct::DoublePipeEqual
+        )
+    }
+    /// Check if this is a binary operator, this includes the
+    /// arithmetic, bitwise, logical and comparison operators
+    /// but not the keywords `in` and `instanceof`
+    pub fn is_binary_operator(self) -> bool {
+        self.is_comparison()
+            || matches!(
+                self,
+                Punct::Plus
+                    | Punct::Dash
+                    | Punct::Asterisk
+                    | Punct::DoubleAsterisk
+                    | Punct::ForwardSlash
+                    | Punct::Percent
+                    | Punct::DoubleLessThan
+                    | Punct::DoubleGreaterThan
+                    | Punct::TripleGreaterThan
+                    | Punct::Ampersand
+                    | Punct::Pipe
+                    | Punct::Caret
+                    | Punct::DoubleAmpersand
+                    | Punct::DoublePipe
+            )
+    }
+    /// Check if this is a prefix unary operator, not
+    /// including the keywords `delete`, `void` and `typeof`
+    pub fn is_unary_operator(self) -> bool {
+        matches!(self, Punct::Plus | Punct::Dash | Punct::Tilde | Punct::Bang)
+    }
+    /// Check if this is `++` or `--`
+    pub fn is_update_operator(self) -> bool {
+        matches!(self, Punct::DoublePlus | Punct::DoubleDash)
+    }
+    /// Check if this is an equality or relational operator
+    pub fn is_comparison(self) -> bool {
+        matches!(
+            self,
+            Punct::DoubleEqual
+                | Punct::TripleEqual
+                | Punct::BangEqual
+                | Punct::BangDoubleEqual
+                | Punct::LessThan
+                | Punct::LessThanEqual
+                | Punct::GreaterThan
+                | Punct::GreaterThanEqual
+        )
+    }
     fn matches_str(self, s: &str) -> bool {
         match self {
             Punct::OpenBrace => "{" == s,
@@ -415,6 +720,7 @@ impl Punct {
             Punct::CloseBracket => "]" == s,
             Punct::Colon => ":" == s,
             Punct::QuestionMark => "?" == s,
+            Punct::QuestionMarkDot => "?." == s,
             Punct::Tilde => "~" == s,
             Punct::GreaterThan => ">" == s,
             Punct::LessThan => "<" == s,
@@ -436,6 +742,8 @@ impl Punct {
             Punct::DoubleLessThanEqual => "<<=" == s,
             Punct::DoubleGreaterThanEqual => ">>=" == s,
             Punct::DoubleAsteriskEqual => "**=" == s,
+            Punct::DoubleAmpersandEqual => "&&=" == s,
+            Punct::DoublePipeEqual => "||=" == s,
             Punct::DoubleAmpersand => "&&" == s,
             Punct::DoublePipe => "||" == s,
             Punct::DoubleEqual => "==" == s,
@@ -462,9 +770,9 @@ impl Punct {
     }
 }
 
-impl ToString for Punct {
-    fn to_string(&self) -> String {
-        match self {
+impl ::std::fmt::Display for Punct {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_str(match self {
             Punct::OpenBrace => "{",
             Punct::CloseBrace => "}",
             Punct::OpenParen => "(",
@@ -476,6 +784,7 @@ impl ToString for Punct {
             Punct::CloseBracket => "]",
             Punct::Colon => ":",
             Punct::QuestionMark => "?",
+            Punct::QuestionMarkDot => "?.",
             Punct::Tilde => "~",
             Punct::GreaterThan => ">",
             Punct::LessThan => "<",
@@ -497,6 +806,8 @@ impl ToString for Punct {
             Punct::DoubleLessThanEqual => "<<=",
             Punct::DoubleGreaterThanEqual => ">>=",
             Punct::DoubleAsteriskEqual => "**=",
+            Punct::DoubleAmpersandEqual => "&&=",
+            Punct::DoublePipeEqual => "||=",
             Punct::DoubleAmpersand => "&&",
             Punct::DoublePipe => "||",
             Punct::DoubleEqual => "==",
@@ -519,12 +830,111 @@ impl ToString for Punct {
             Punct::DoubleAsterisk => "**",
             Punct::Hash => "#",
             Punct::AtMark => "@",
-        }
-        .into()
+        })
+    }
+}
+impl ::std::str::FromStr for Punct {
+    type Err = UnknownToken;
+    /// Parse the text of a punctuator, the
+    /// inverse of its `Display` implementation
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "{" => Punct::OpenBrace,
+            "}" => Punct::CloseBrace,
+            "(" => Punct::OpenParen,
+            ")" => Punct::CloseParen,
+            "." => Punct::Period,
+            ";" => Punct::SemiColon,
+            "," => Punct::Comma,
+            "[" => Punct::OpenBracket,
+            "]" => Punct::CloseBracket,
+            ":" => Punct::Colon,
+            "?" => Punct::QuestionMark,
+            "?." => Punct::QuestionMarkDot,
+            "~" => Punct::Tilde,
+            ">" => Punct::GreaterThan,
+            "<" => Punct::LessThan,
+            "=" => Punct::Equal,
+            "!" => Punct::Bang,
+            "+" => Punct::Plus,
+            "-" => Punct::Dash,
+            "*" => Punct::Asterisk,
+            "%" => Punct::Percent,
+            "|" => Punct::Pipe,
+            "&" => Punct::Ampersand,
+            "^" => Punct::Caret,
+            "/" => Punct::ForwardSlash,
+            ">>>=" => Punct::TripleGreaterThanEqual,
+            "..." => Punct::Ellipsis,
+            "===" => Punct::TripleEqual,
+            "!==" => Punct::BangDoubleEqual,
+            ">>>" => Punct::TripleGreaterThan,
+            "<<=" => Punct::DoubleLessThanEqual,
+            ">>=" => Punct::DoubleGreaterThanEqual,
+            "**=" => Punct::DoubleAsteriskEqual,
+            "&&=" => Punct::DoubleAmpersandEqual,
+            "||=" => Punct::DoublePipeEqual,
+            "&&" => Punct::DoubleAmpersand,
+            "||" => Punct::DoublePipe,
+            "==" => Punct::DoubleEqual,
+            "!=" => Punct::BangEqual,
+            "+=" => Punct::PlusEqual,
+            "-=" => Punct::DashEqual,
+            "*=" => Punct::AsteriskEqual,
+            "/=" => Punct::ForwardSlashEqual,
+            "++" => Punct::DoublePlus,
+            "--" => Punct::DoubleDash,
+            "<<" => Punct::DoubleLessThan,
+            ">>" => Punct::DoubleGreaterThan,
+            "&=" => Punct::AmpersandEqual,
+            "|=" => Punct::PipeEqual,
+            "^=" => Punct::CaretEqual,
+            "%=" => Punct::PercentEqual,
+            "=>" => Punct::EqualGreaterThan,
+            ">=" => Punct::GreaterThanEqual,
+            "<=" => Punct::LessThanEqual,
+            "**" => Punct::DoubleAsterisk,
+            "#" => Punct::Hash,
+            "@" => Punct::AtMark,
+            _ => return Err(UnknownToken(s.to_string())),
+        })
     }
 }
 
-impl<'a> Token<&'a str> {
+impl Token<&str> {
+    /// Copy any text this token borrows from the
+    /// original source into owned `String`s so it
+    /// can outlive that source
+    ///
+    /// This inherent method takes precedence over
+    /// `ToOwned::to_owned`, which would only clone the
+    /// borrowed `Token<&str>`. Call that through
+    /// `Clone::clone` if a copy of the borrowed token
+    /// is what you want.
+    ///
+    /// ```
+    /// # use ress::prelude::*;
+    /// let borrowed = Token::Ident(Ident::from("thing"));
+    /// let owned: Token<String> = borrowed.to_owned();
+    /// assert_eq!(owned.to_string(), "thing");
+    /// ```
+    pub fn to_owned(&self) -> Token<String> {
+        match self {
+            Token::Boolean(b) => Token::Boolean(*b),
+            Token::EoF => Token::EoF,
+            Token::Ident(i) => Token::Ident(i.to_owned()),
+            Token::Keyword(k) => Token::Keyword(k.to_owned()),
+            Token::Null => Token::Null,
+            Token::Number(n) => Token::Number(n.to_owned()),
+            Token::Punct(p) => Token::Punct(*p),
+            Token::String(s) => Token::String(s.to_owned()),
+            Token::RegEx(r) => Token::RegEx(r.to_owned()),
+            Token::Template(t) => Token::Template(t.to_owned()),
+            Token::Comment(c) => Token::Comment(c.to_owned()),
+            Token::Invalid(s) => Token::Invalid(s.to_string()),
+            Token::Jsx(j) => Token::Jsx(j.to_owned()),
+        }
+    }
     pub fn is_div_punct(&self) -> bool {
         matches!(
             self,
@@ -537,6 +947,114 @@ impl<'a> Token<&'a str> {
 mod test {
     use super::*;
 
+    #[test]
+    fn variant_predicates() {
+        let tokens = [
+            Token::Boolean(Boolean::True),
+            Token::EoF,
+            Token::Ident("x".into()),
+            Token::Keyword(Keyword::Var("var")),
+            Token::Null,
+            Token::Number("1".into()),
+            Token::Punct(Punct::Comma),
+            Token::String(StringLit::single("a", false)),
+            Token::RegEx(RegEx::from_parts("a", None)),
+            Token::Template(Template::no_sub_template("a", false, false, false)),
+            Token::Comment(Comment::new_single_line("a")),
+        ];
+        let predicates: [fn(&Token<&'static str>) -> bool; 11] = [
+            Token::is_boolean,
+            Token::is_eof,
+            Token::is_ident,
+            Token::is_keyword,
+            Token::is_null,
+            Token::is_number,
+            Token::is_punct,
+            Token::is_string,
+            Token::is_regex,
+            Token::is_template,
+            Token::is_comment,
+        ];
+        for (i, predicate) in predicates.iter().enumerate() {
+            for (j, token) in tokens.iter().enumerate() {
+                assert_eq!(predicate(token), i == j, "{}: {:?}", i, token);
+            }
+        }
+    }
+
+    #[test]
+    fn payload_accessors() {
+        let re = Token::RegEx(RegEx::from_parts("[a-z]+", Some("gi")));
+        let inner = re.as_regex().unwrap();
+        assert_eq!(inner.body, "[a-z]+");
+        assert_eq!(inner.flags, Some("gi"));
+        assert_eq!(re.as_ident(), None);
+        let ident = Token::Ident("x".into());
+        assert_eq!(ident.as_ident(), Some("x"));
+        assert!(ident.as_regex().is_none());
+        assert!(ident.as_keyword().is_none());
+        assert_eq!(Token::Number("1e3".into()).as_number(), Some("1e3"));
+        let s = Token::String(StringLit::double("it's", false));
+        assert_eq!(s.as_string(), Some("it's"));
+        assert_eq!(s.as_number(), None);
+        let k = Token::Keyword(Keyword::Static("static"));
+        assert_eq!(k.as_keyword(), Some(&Keyword::Static("static")));
+        assert_eq!(k.as_string(), None);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", Punct::EqualGreaterThan), "=>");
+        assert_eq!(format!("{}", Keyword::Function("function")), "function");
+        assert_eq!(format!("{}", Token::Ident(Ident::from("x"))), "x");
+        let tokens: [Token<&str>; 8] = [
+            Token::Boolean(Boolean::True),
+            Token::Null,
+            Token::Number("0x1f".into()),
+            Token::Punct(Punct::TripleGreaterThanEqual),
+            Token::String(StringLit::double("a\\\"b", false)),
+            Token::RegEx(RegEx::from_parts("a/", Some("g"))),
+            Token::Template(Template::template_middle("b", false, false, false)),
+            Token::EoF,
+        ];
+        for token in &tokens {
+            assert_eq!(token.to_string(), token.to_source());
+        }
+    }
+    #[test]
+    fn punct_categories() {
+        assert!(Punct::Equal.is_assignment());
+        assert!(Punct::TripleGreaterThanEqual.is_assignment());
+        assert!(Punct::DoublePipeEqual.is_assignment());
+        assert!(!Punct::DoubleEqual.is_assignment());
+        assert!(Punct::Percent.is_binary_operator());
+        assert!(Punct::DoubleAmpersand.is_binary_operator());
+        assert!(Punct::BangDoubleEqual.is_binary_operator());
+        assert!(!Punct::Bang.is_binary_operator());
+        assert!(!Punct::PlusEqual.is_binary_operator());
+        assert!(Punct::Tilde.is_unary_operator());
+        assert!(Punct::Dash.is_unary_operator());
+        assert!(!Punct::DoubleDash.is_unary_operator());
+        assert!(Punct::DoublePlus.is_update_operator());
+        assert!(Punct::DoubleDash.is_update_operator());
+        assert!(!Punct::Plus.is_update_operator());
+        assert!(Punct::LessThanEqual.is_comparison());
+        assert!(Punct::BangEqual.is_comparison());
+        assert!(!Punct::DoubleLessThan.is_comparison());
+        for p in &[
+            Punct::Period,
+            Punct::Comma,
+            Punct::OpenParen,
+            Punct::EqualGreaterThan,
+        ] {
+            assert!(!p.is_assignment());
+            assert!(!p.is_binary_operator());
+            assert!(!p.is_unary_operator());
+            assert!(!p.is_update_operator());
+            assert!(!p.is_comparison());
+        }
+    }
+
     #[test]
     fn booleans() {
         let t = Token::<&str>::Boolean(Boolean::True);
@@ -563,6 +1081,95 @@ mod test {
         assert!(c2.is_multi_line_comment());
     }
     #[test]
+    fn to_keyword_and_punct() {
+        let k = Token::Keyword(Keyword::Function("function"));
+        assert_eq!(k.to_keyword(), Some(Keyword::Function("function")));
+        assert_eq!(k.to_punct(), None);
+        let i = Token::Ident(Ident::from("function2"));
+        assert_eq!(i.to_keyword(), None);
+        let p = Token::<&str>::Punct(Punct::Ellipsis);
+        assert_eq!(p.to_punct(), Some(Punct::Ellipsis));
+        assert_eq!(p.to_keyword(), None);
+    }
+    #[test]
+    fn regex_flags() {
+        let re = RegEx::from_parts("a", Some("dgimsuy"));
+        assert!(re.has_indices());
+        assert!(re.global());
+        assert!(re.ignore_case());
+        assert!(re.multiline());
+        assert!(re.dot_all());
+        assert!(re.unicode());
+        assert!(!re.unicode_sets());
+        assert!(re.sticky());
+        let re = RegEx::from_parts("a", Some("v"));
+        assert!(re.unicode_sets());
+        assert!(!re.unicode());
+        let re = RegEx::from_parts("a", None);
+        assert!(!re.has_indices() && !re.global() && !re.unicode_sets());
+    }
+    #[test]
+    fn typed_keywords() {
+        let tokens = crate::tokenize("if implements await").unwrap();
+        assert_eq!(tokens[0], Token::Keyword(Keyword::If("if")));
+        assert!(tokens[0].matches_keyword(Keyword::If(())));
+        let reserved = tokens[0].to_keyword().unwrap();
+        assert!(reserved.is_reserved());
+        assert!(!reserved.is_strict_reserved());
+        assert!(!reserved.is_contextual());
+        let strict = tokens[1].to_keyword().unwrap();
+        assert_eq!(strict, Keyword::Implements(()));
+        assert!(!strict.is_reserved());
+        assert!(strict.is_strict_reserved());
+        assert!(!strict.is_contextual());
+        let contextual = tokens[2].to_keyword().unwrap();
+        assert_eq!(contextual, Keyword::Await(()));
+        assert!(!contextual.is_reserved());
+        assert!(!contextual.is_strict_reserved());
+        assert!(contextual.is_contextual());
+    }
+    #[test]
+    fn typed_punct() {
+        let tokens = crate::tokenize("a >>>= b >>> c >>= d").unwrap();
+        assert_eq!(tokens[1], Token::Punct(Punct::TripleGreaterThanEqual));
+        assert!(tokens[1].matches_punct(Punct::TripleGreaterThanEqual));
+        assert!(!tokens[1].matches_punct(Punct::TripleGreaterThan));
+        assert_eq!(tokens[3].to_punct(), Some(Punct::TripleGreaterThan));
+        assert_eq!(tokens[5].to_punct(), Some(Punct::DoubleGreaterThanEqual));
+    }
+    #[test]
+    fn owned_tokens() {
+        let js = "var x = /a/g + `a${b}c` + 'd' + 0x1f // e";
+        let tokens = crate::tokenize(js).unwrap();
+        let owned: Vec<Token<String>> = tokens.iter().map(Token::to_owned).collect();
+        assert_eq!(owned.len(), tokens.len());
+        for (owned, borrowed) in owned.iter().zip(tokens.iter()) {
+            assert_eq!(owned.to_string(), borrowed.to_string());
+        }
+        assert_eq!(owned[0], Token::Keyword(Keyword::Var("var".to_string())));
+        let escaped = Keyword::If("\\u0069f").to_owned();
+        assert_eq!(escaped.raw(), "\\u0069f");
+        assert_eq!(
+            owned[3],
+            Token::RegEx(RegEx::from_parts("a".to_string(), Some("g".to_string())))
+        );
+    }
+    #[test]
+    fn jsdoc_comments() {
+        let doc = crate::tokenize("/** doc */").unwrap();
+        let normal = crate::tokenize("/* normal */").unwrap();
+        let empty = crate::tokenize("/**/").unwrap();
+        let line = crate::tokenize("//* not a block").unwrap();
+        let check = |tokens: &[Token<&str>]| match &tokens[0] {
+            Token::Comment(c) => c.is_jsdoc(),
+            other => panic!("expected comment found {:?}", other),
+        };
+        assert!(check(&doc));
+        assert!(!check(&normal));
+        assert!(!check(&empty));
+        assert!(!check(&line));
+    }
+    #[test]
     fn idents() {
         let i = Token::Ident(Ident::from("asdf"));
         assert!(i.is_ident());
@@ -570,6 +1177,71 @@ mod test {
         assert!(i == "asdf");
     }
     #[test]
+    fn escaped_idents() {
+        let start = Ident::from(r"\u0061bc");
+        assert!(start.has_unicode_escape());
+        assert_eq!(start.as_ref(), r"\u0061bc");
+        assert_eq!(start.decoded(), "abc");
+        let cont = Ident::from(r"a\u{62}\u{0063}");
+        assert_eq!(cont.decoded(), "abc");
+        let astral = Ident::from(r"x\u{e01d5}");
+        assert_eq!(astral.decoded(), "x\u{e01d5}");
+        let malformed = Ident::from(r"a\u{}b\u00");
+        assert_eq!(malformed.decoded(), r"a\u{}b\u00");
+    }
+    #[test]
+    fn reserved_word_idents() {
+        assert!(Ident::from("class").is_reserved_word());
+        assert!(Ident::from("null").is_reserved_word());
+        assert!(Ident::from(r"\u0069f").is_reserved_word());
+        assert!(!Ident::from("classy").is_reserved_word());
+        assert!(!Ident::from("let").is_reserved_word());
+        assert!(!Ident::from(r"\u0069").is_reserved_word());
+    }
+    #[test]
+    fn matches_any() {
+        let puncts = [Punct::Comma, Punct::QuestionMark, Punct::Colon];
+        for p in &puncts {
+            let token = Token::<&str>::Punct(*p);
+            assert!(token.matches_any_punct(&puncts));
+            assert!(!token.matches_any_keyword(&[Keyword::In(())]));
+        }
+        assert!(!Token::<&str>::Punct(Punct::Plus).matches_any_punct(&puncts));
+        assert!(!Token::Ident("in".into()).matches_any_keyword(&[Keyword::In(())]));
+        let keywords = [Keyword::In("in"), Keyword::TypeOf("typeof")];
+        assert!(Token::Keyword(Keyword::In(())).matches_any_keyword(&keywords));
+        assert!(!Token::Keyword(Keyword::InstanceOf(())).matches_any_keyword(&keywords));
+        assert!(!Token::Keyword(Keyword::In("in")).matches_any_punct(&puncts));
+    }
+    #[test]
+    fn keywords_from_ident_text() {
+        let class = Keyword::from_ident_text("class").unwrap();
+        assert_eq!(class, Keyword::Class(()));
+        assert!(class.is_reserved());
+        let yield_ = Keyword::from_ident_text("yield").unwrap();
+        assert_eq!(yield_, Keyword::Yield(()));
+        assert!(!yield_.is_reserved());
+        assert!(yield_.is_strict_reserved());
+        let await_ = Keyword::from_ident_text(r"\u{61}wait").unwrap();
+        assert_eq!(await_, Keyword::Await(()));
+        assert!(!await_.is_strict_reserved());
+        assert!(await_.is_contextual());
+        assert_eq!(Keyword::from_ident_text("classy"), None);
+        assert_eq!(Keyword::from_ident_text(r"\u0069"), None);
+
+        let tokens = crate::tokenize("obj.class; obj.yield; var \\u0063lass; x.await").unwrap();
+        assert_eq!(tokens[2], Token::Ident("class".into()));
+        assert!(tokens[2].ident_is_reserved_word());
+        assert!(tokens[6].ident_is_reserved_word());
+        // an escaped keyword is still a keyword
+        assert!(tokens[9].matches_keyword(Keyword::Class(())));
+        assert!(!tokens[9].ident_is_reserved_word());
+        assert!(tokens[13].ident_is_reserved_word());
+        assert!(!tokens[0].ident_is_reserved_word());
+        assert!(!tokens[8].ident_is_reserved_word());
+        assert!(!Token::Keyword(Keyword::Class("class")).ident_is_reserved_word());
+    }
+    #[test]
     fn keywords() {
         check_keyword("await", Token::Keyword(Keyword::Await("await")));
         check_keyword("break", Token::Keyword(Keyword::Break("break")));
@@ -629,6 +1301,86 @@ mod test {
         assert_eq!(tok, s);
     }
     #[test]
+    fn number_values() {
+        assert_eq!(Number::from(".5").value(), Some(0.5));
+        assert_eq!(Number::from("5.").value(), Some(5.0));
+        assert_eq!(Number::from("5.e0").value(), Some(5.0));
+        assert_eq!(Number::from("1_000.25e-2").value(), Some(10.0025));
+        assert_eq!(Number::from("0xff").value(), Some(255.0));
+        assert_eq!(Number::from("0b101").value(), Some(5.0));
+        assert_eq!(Number::from("0o17").value(), Some(15.0));
+        assert_eq!(Number::from("017").value(), Some(15.0));
+        assert_eq!(Number::from("019").value(), Some(19.0));
+        assert_eq!(Number::from("10n").value(), None);
+    }
+    #[test]
+    fn number_as_f64() {
+        assert_eq!(Number::from("0x10").as_f64(), 16.0);
+        assert_eq!(Number::from("1e3").as_f64(), 1000.0);
+        assert_eq!(Number::from(".5").as_f64(), 0.5);
+        assert_eq!(Number::from("0b101").as_f64(), 5.0);
+        assert_eq!(Number::from("0O17").as_f64(), 15.0);
+        assert_eq!(Number::from("0755").as_f64(), 493.0);
+        assert_eq!(Number::from("10n").as_f64(), 10.0);
+        assert_eq!(Number::from("0x1fn").as_f64(), 31.0);
+        assert_eq!(Number::from("1E-2").as_f64(), 0.01);
+        // 2^57 + 31 is closer to 2^57 + 32 than 2^57
+        assert_eq!(
+            Number::from("0x20000000000001f").as_f64(),
+            144_115_188_075_855_904.0
+        );
+        assert!(Number::from("0x").as_f64().is_nan());
+    }
+    #[test]
+    fn number_as_f64_limits() {
+        let f = |s| Number::from(s).as_f64();
+        // overflow
+        assert_eq!(f("1e308"), 1e308);
+        assert_eq!(f("1.7976931348623157e308"), f64::MAX);
+        assert_eq!(f("1.7976931348623158e308"), f64::MAX);
+        assert_eq!(f("1.7976931348623159e308"), f64::INFINITY);
+        assert_eq!(f("1e309"), f64::INFINITY);
+        assert_eq!(f("1E+400"), f64::INFINITY);
+        // underflow
+        assert_eq!(f("1e-324"), 0.0);
+        assert_eq!(f("2.4703282292062327e-324"), 0.0);
+        assert_eq!(f("0e999999"), 0.0);
+        // denormals
+        assert_eq!(f("5e-324"), 5e-324);
+        assert_eq!(f("4.9e-324"), 5e-324);
+        assert_eq!(f("2.4703282292062328e-324"), 5e-324);
+        assert_eq!(f("2.2250738585072011e-308"), 2.225_073_858_507_201e-308);
+        assert!(f("2.2250738585072011e-308") < f64::MIN_POSITIVE);
+        // small and sloppy
+        assert_eq!(f("0.0000001"), 1e-7);
+        assert_eq!(f("007.50"), 7.5);
+        assert_eq!(f("08.5"), 8.5);
+        assert_eq!(f("1_000e1_0"), 1e13);
+    }
+    #[test]
+    fn number_decimal_parts() {
+        fn parts(
+            integer: &'static str,
+            fraction: &'static str,
+            exponent: &'static str,
+        ) -> Option<DecimalParts<'static>> {
+            Some(DecimalParts {
+                integer,
+                fraction,
+                exponent,
+            })
+        }
+        assert_eq!(Number::from(".5").decimal_parts(), parts("", "5", ""));
+        assert_eq!(Number::from("5.").decimal_parts(), parts("5", "", ""));
+        assert_eq!(Number::from("5.e0").decimal_parts(), parts("5", "", "0"));
+        assert_eq!(
+            Number::from("1.5E+3").decimal_parts(),
+            parts("1", "5", "+3")
+        );
+        assert_eq!(Number::from("42").decimal_parts(), parts("42", "", ""));
+        assert_eq!(Number::from("0x1").decimal_parts(), None);
+    }
+    #[test]
     fn numbers() {
         let int = "1234";
         let tok = Token::Number(Number::from(int));
@@ -763,4 +1515,140 @@ mod test {
         assert_ne!(t, "");
         assert_ne!(t, "}asdf`");
     }
+
+    #[test]
+    fn escape_sequences() {
+        let chars = [
+            ("b", '\u{8}', 1),
+            ("f", '\u{c}', 1),
+            ("n", '\n', 1),
+            ("r", '\r', 1),
+            ("t", '\t', 1),
+            ("v", '\u{b}', 1),
+            ("0", '\0', 1),
+            ("0a", '\0', 1),
+            ("'", '\'', 1),
+            ("\"", '"', 1),
+            ("\\", '\\', 1),
+            ("q", 'q', 1),
+            ("é", 'é', 2),
+            ("x41", 'A', 3),
+            ("x7ex", '~', 3),
+            ("u0041", 'A', 5),
+            ("u00411", 'A', 5),
+            ("u{41}", 'A', 5),
+            ("u{0000041}", 'A', 10),
+            ("u{10FFFF}", '\u{10FFFF}', 9),
+        ];
+        for (text, c, len) in &chars {
+            assert_eq!(
+                escape_sequence(text, false),
+                Some((Escape::Char(*c), *len)),
+                "{}",
+                text
+            );
+        }
+        assert_eq!(
+            escape_sequence("uD83D", false),
+            Some((Escape::CodeUnit(0xD83D), 5))
+        );
+        assert_eq!(
+            escape_sequence("u{DE00}", false),
+            Some((Escape::CodeUnit(0xDE00), 7))
+        );
+        for text in &["\n", "\r", "\u{2028}", "\u{2029}"] {
+            assert_eq!(
+                escape_sequence(text, false),
+                Some((Escape::LineContinuation, text.len()))
+            );
+        }
+        assert_eq!(
+            escape_sequence("\r\n", false),
+            Some((Escape::LineContinuation, 2))
+        );
+        let legacy = [
+            ("1", '\u{1}', 1),
+            ("08", '\0', 1),
+            ("101", 'A', 3),
+            ("377", '\u{ff}', 3),
+            ("400", ' ', 2),
+            ("78", '\u{7}', 1),
+            ("8", '8', 1),
+            ("9", '9', 1),
+        ];
+        for (text, c, len) in &legacy {
+            assert_eq!(
+                escape_sequence(text, true),
+                Some((Escape::Legacy(*c), *len)),
+                "{}",
+                text
+            );
+            assert_eq!(escape_sequence(text, false), None, "{}", text);
+        }
+        for text in &[
+            "",
+            "x",
+            "x4",
+            "xg0",
+            "u",
+            "u004",
+            "u{}",
+            "u{110000}",
+            "u{41",
+            "u{g}",
+            "u0g00",
+        ] {
+            assert_eq!(escape_sequence(text, true), None, "{}", text);
+        }
+    }
+    #[test]
+    fn from_str() {
+        assert_eq!("=>".parse(), Ok(Punct::EqualGreaterThan));
+        assert_eq!("...".parse(), Ok(Punct::Ellipsis));
+        assert_eq!(">>>=".parse(), Ok(Punct::TripleGreaterThanEqual));
+        assert_eq!("?.".parse(), Ok(Punct::QuestionMarkDot));
+        assert_eq!("function".parse(), Ok(Keyword::Function(())));
+        assert_eq!("yield".parse(), Ok(Keyword::Yield(())));
+        assert_eq!("=>>".parse::<Punct>(), Err(UnknownToken("=>>".to_string())));
+        assert!("".parse::<Punct>().is_err());
+        assert!("Function".parse::<Keyword<()>>().is_err());
+        assert!(r"\u0069f".parse::<Keyword<()>>().is_err());
+        for js in &["+", "-=", "**", "&&=", "#", "@", "<", "~"] {
+            let p: Punct = js.parse().unwrap();
+            assert_eq!(&p.to_string(), js);
+        }
+        for js in &["await", "class", "get", "instanceof", "typeof"] {
+            let k: Keyword<()> = js.parse().unwrap();
+            assert_eq!(&k.to_string(), js);
+        }
+    }
+    #[test]
+    fn cooked_values() {
+        use std::borrow::Cow;
+        let plain = StringLit::single("plain", false);
+        assert!(matches!(plain.cooked(), Cow::Borrowed("plain")));
+        let escaped = StringLit::double(
+            r"a\tb\x41\u{1F600}😀\101\
+c",
+            true,
+        );
+        assert!(matches!(escaped.cooked(), Cow::Owned(_)));
+        assert_eq!(escaped.cooked(), "a\tbA😀😀Ac");
+        let lone = StringLit::single(r"\uD83Dx\uDE00", false);
+        assert_eq!(lone.cooked(), "\u{FFFD}x\u{FFFD}");
+        let invalid = StringLit::single(r"\x4", false);
+        assert_eq!(invalid.cooked(), r"\x4");
+
+        let plain = Template::no_sub_template("a${", false, false, false);
+        assert!(matches!(plain.cooked(), Some(Cow::Borrowed("a${"))));
+        let escaped = Template::template_head(r"\`\${\n", false, false, false);
+        assert!(matches!(escaped.cooked(), Some(Cow::Owned(_))));
+        assert_eq!(escaped.cooked().unwrap(), "`${\n");
+        let lines = Template::template_tail("a\r\nb\rc\\\r\nd", false, false, false);
+        assert_eq!(lines.cooked().unwrap(), "a\nb\ncd");
+        let invalid = Template::template_middle(r"\unicode", false, true, false);
+        assert_eq!(invalid.cooked(), None);
+        let octal = Template::no_sub_template(r"\01", true, false, false);
+        assert_eq!(octal.cooked(), None);
+    }
 }
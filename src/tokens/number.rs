@@ -3,26 +3,14 @@
 /// literals allowed in JS.
 ///
 /// - Decimal Literals - This includes integers and decimals with
-///     optional exponent notation
+///   optional exponent notation
 /// - Hexadecimal Literals - These begin with 0x and consist of numbers
-///     0-9 and letters A-F (case insensitive)
+///   0-9 and letters A-F (case insensitive)
 /// - Octal Literals - These being with 0o and consist of numbers
-///     0-7
+///   0-7
 /// - Binary Literals - These begin with 0b and consist of numbers 0 and 1
 pub struct Number<T>(T);
 
-/// Extension methods for allowing Number
-/// to work with both &str and String
-pub trait NumberExt {
-    fn kind(&self) -> NumberKind;
-    fn is_hex(&self) -> bool;
-    fn is_bin(&self) -> bool;
-    fn is_oct(&self) -> bool;
-    fn is_dec(&self) -> bool;
-    fn has_exponent(&self) -> bool;
-    fn is_big_int(&self) -> bool;
-}
-
 impl<T> Number<T>
 where
     T: AsRef<str>,
@@ -34,7 +22,7 @@ where
             Some("0b") | Some("0B") => NumberKind::Bin,
             Some("0o") | Some("0O") => NumberKind::Oct,
             _ => {
-                if s.ends_with("n") {
+                if s.ends_with('n') {
                     NumberKind::BigInt
                 } else {
                     NumberKind::Dec
@@ -57,13 +45,102 @@ where
     }
     pub fn has_exponent(&self) -> bool {
         match self.kind() {
-            NumberKind::Dec => self.0.as_ref().contains(|c| c == 'e' || c == 'E'),
+            NumberKind::Dec => self.0.as_ref().contains(['e', 'E']),
             _ => false,
         }
     }
     pub fn is_big_int(&self) -> bool {
         self.kind() == NumberKind::BigInt
     }
+    /// Check if this is a decimal literal with a leading
+    /// zero, either a legacy octal literal (`017`) or one
+    /// that has an `8` or `9` (`019`). Neither is allowed
+    /// in strict mode
+    pub fn is_legacy(&self) -> bool {
+        let s = self.0.as_ref();
+        self.is_dec() && s.starts_with('0') && s[1..].starts_with(|c: char| c.is_ascii_digit())
+    }
+    /// Split a decimal literal into its integer, fraction
+    /// and exponent parts, any of which may be empty
+    /// (`.5` has no integer part, `5.` has no fraction part).
+    /// Returns `None` for any non-decimal literal
+    pub fn decimal_parts(&self) -> Option<DecimalParts<'_>> {
+        if self.kind() != NumberKind::Dec {
+            return None;
+        }
+        let s = self.0.as_ref();
+        let (mantissa, exponent) = match s.find(['e', 'E']) {
+            Some(idx) => (&s[..idx], &s[idx + 1..]),
+            None => (s, ""),
+        };
+        let (integer, fraction) = match mantissa.find('.') {
+            Some(idx) => (&mantissa[..idx], &mantissa[idx + 1..]),
+            None => (mantissa, ""),
+        };
+        Some(DecimalParts {
+            integer,
+            fraction,
+            exponent,
+        })
+    }
+    /// Get the numeric value of this literal, numeric
+    /// separators are ignored. Returns `None` for BigInt
+    /// literals or any text that isn't a valid number
+    pub fn value(&self) -> Option<f64> {
+        let s = self.0.as_ref().replace('_', "");
+        match self.kind() {
+            NumberKind::Hex => Self::radix_value(&s[2..], 16),
+            NumberKind::Bin => Self::radix_value(&s[2..], 2),
+            NumberKind::Oct => Self::radix_value(&s[2..], 8),
+            NumberKind::Dec => {
+                if s.len() > 1
+                    && s.starts_with('0')
+                    && s.bytes().all(|b| (b'0'..=b'7').contains(&b))
+                {
+                    // legacy octal literal (`017`)
+                    Self::radix_value(&s[1..], 8)
+                } else {
+                    s.parse().ok()
+                }
+            }
+            NumberKind::BigInt => None,
+        }
+    }
+    /// Get the value of this literal the way JS would
+    /// convert it to a `Number`, a BigInt literal like
+    /// `10n` is `10.0` and invalid text is `NaN`
+    pub fn as_f64(&self) -> f64 {
+        let s = self.0.as_ref();
+        // `n` isn't a digit in any radix so this
+        // also handles `0x1fn`
+        Number(s.strip_suffix('n').unwrap_or(s))
+            .value()
+            .unwrap_or(f64::NAN)
+    }
+
+    fn radix_value(digits: &str, radix: u32) -> Option<f64> {
+        if digits.is_empty() {
+            return None;
+        }
+        // going through an integer first rounds to the
+        // nearest f64 the same way JS does
+        if let Ok(n) = u128::from_str_radix(digits, radix) {
+            return Some(n as f64);
+        }
+        digits.chars().try_fold(0f64, |acc, c| {
+            Some(acc * f64::from(radix) + f64::from(c.to_digit(radix)?))
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+/// The parts of a decimal number literal,
+/// `1.5e3` would have an `integer` of `1`, a
+/// `fraction` of `5` and an `exponent` of `3`
+pub struct DecimalParts<'a> {
+    pub integer: &'a str,
+    pub fraction: &'a str,
+    pub exponent: &'a str,
 }
 
 impl<'a> From<&'a str> for Number<&'a str> {
@@ -72,12 +149,28 @@ impl<'a> From<&'a str> for Number<&'a str> {
     }
 }
 
-impl<T> ToString for Number<T>
+impl Number<&str> {
+    /// Copy this number into an owned `String`
+    pub fn to_owned(&self) -> Number<String> {
+        Number(self.0.to_string())
+    }
+}
+
+impl<T> ::std::fmt::Display for Number<T>
+where
+    T: AsRef<str>,
+{
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_str(self.0.as_ref())
+    }
+}
+
+impl<T> AsRef<str> for Number<T>
 where
     T: AsRef<str>,
 {
-    fn to_string(&self) -> String {
-        self.0.as_ref().to_string()
+    fn as_ref(&self) -> &str {
+        self.0.as_ref()
     }
 }
 
@@ -5,6 +5,10 @@
 /// body ends with another `/`
 /// optionally a series of one letter
 /// flags can be included after the `/`
+///
+/// The `body` and `flags` are the exact
+/// text from the source, escapes are not
+/// changed in any way
 pub struct RegEx<T> {
     pub body: T,
     pub flags: Option<T>,
@@ -16,16 +20,65 @@ impl<T> RegEx<T> {
     }
 }
 
-impl<T> ToString for RegEx<T>
+impl<T> RegEx<T>
+where
+    T: AsRef<str>,
+{
+    fn has_flag(&self, flag: char) -> bool {
+        self.flags
+            .as_ref()
+            .map(|f| f.as_ref().contains(flag))
+            .unwrap_or(false)
+    }
+    /// `d`, generate indices for substring matches
+    pub fn has_indices(&self) -> bool {
+        self.has_flag('d')
+    }
+    /// `g`, global search
+    pub fn global(&self) -> bool {
+        self.has_flag('g')
+    }
+    /// `i`, case-insensitive search
+    pub fn ignore_case(&self) -> bool {
+        self.has_flag('i')
+    }
+    /// `m`, `^` and `$` match at line terminators
+    pub fn multiline(&self) -> bool {
+        self.has_flag('m')
+    }
+    /// `s`, `.` also matches line terminators
+    pub fn dot_all(&self) -> bool {
+        self.has_flag('s')
+    }
+    /// `u`, treat the pattern as a sequence of code points
+    pub fn unicode(&self) -> bool {
+        self.has_flag('u')
+    }
+    /// `v`, an upgrade to `u` that allows nested character
+    /// classes and set operations like `[\p{L}--[a-z]]`
+    pub fn unicode_sets(&self) -> bool {
+        self.has_flag('v')
+    }
+    /// `y`, only match from `lastIndex`
+    pub fn sticky(&self) -> bool {
+        self.has_flag('y')
+    }
+}
+
+impl RegEx<&str> {
+    /// Copy the body and flags of this regular
+    /// expression into owned `String`s
+    pub fn to_owned(&self) -> RegEx<String> {
+        RegEx::from_parts(self.body.to_string(), self.flags.map(String::from))
+    }
+}
+
+impl<T> ::std::fmt::Display for RegEx<T>
 where
     T: AsRef<str>,
 {
-    fn to_string(&self) -> String {
-        let f = if let Some(f) = &self.flags {
-            f.as_ref().to_string()
-        } else {
-            String::new()
-        };
-        format!("/{}/{}", self.body.as_ref(), f)
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        let flags = self.flags.as_ref().map(AsRef::as_ref).unwrap_or("");
+        write!(f, "/{}/{}", self.body.as_ref(), flags)
     }
 }
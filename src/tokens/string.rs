@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 #[derive(Debug, PartialEq, Clone)]
 /// A single or double quoted string
 /// literal
@@ -7,18 +9,42 @@ pub enum StringLit<T> {
 }
 #[derive(Debug, PartialEq, Clone)]
 pub struct InnerString<T> {
+    /// The text between the quotes exactly as
+    /// it appears in the source, escapes included
     pub content: T,
     pub contains_octal_escape: bool,
 }
 
-impl<T> ToString for StringLit<T>
+impl StringLit<&str> {
+    /// Copy the content of this string literal
+    /// into an owned `String`
+    pub fn to_owned(&self) -> StringLit<String> {
+        match self {
+            StringLit::Single(inner) => StringLit::Single(inner.to_owned()),
+            StringLit::Double(inner) => StringLit::Double(inner.to_owned()),
+        }
+    }
+}
+
+impl InnerString<&str> {
+    /// Copy the content of this string into an owned
+    /// `String`, the escape flags are kept as is
+    pub fn to_owned(&self) -> InnerString<String> {
+        InnerString {
+            content: self.content.to_string(),
+            contains_octal_escape: self.contains_octal_escape,
+        }
+    }
+}
+
+impl<T> ::std::fmt::Display for StringLit<T>
 where
     T: AsRef<str>,
 {
-    fn to_string(&self) -> String {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match self {
-            StringLit::Single(ref s) => format!(r#"'{}'"#, s.content.as_ref()),
-            StringLit::Double(ref s) => format!(r#""{}""#, s.content.as_ref()),
+            StringLit::Single(ref s) => write!(f, r#"'{}'"#, s.content.as_ref()),
+            StringLit::Double(ref s) => write!(f, r#""{}""#, s.content.as_ref()),
         }
     }
 }
@@ -39,7 +65,35 @@ where
     T: AsRef<str>,
 {
     fn as_ref(&self) -> &str {
-        &self.content.as_ref()
+        self.content.as_ref()
+    }
+}
+
+impl<T> StringLit<T>
+where
+    T: AsRef<str>,
+{
+    /// The value of this string with all of its escape
+    /// sequences replaced, see `InnerString::cooked`
+    pub fn cooked(&self) -> Cow<'_, str> {
+        match self {
+            StringLit::Single(inner) | StringLit::Double(inner) => inner.cooked(),
+        }
+    }
+}
+
+impl<T> InnerString<T>
+where
+    T: AsRef<str>,
+{
+    /// The value of this string with all of its escape
+    /// sequences replaced, this only allocates when there
+    /// is at least one escape. Content with an invalid
+    /// escape, which the tokenizer would have rejected,
+    /// is returned as is
+    pub fn cooked(&self) -> Cow<'_, str> {
+        let raw = self.content.as_ref();
+        super::escape::cook(raw, true, false).unwrap_or(Cow::Borrowed(raw))
     }
 }
 
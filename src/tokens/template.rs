@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 #[derive(Debug, PartialEq, Clone)]
 /// A template string
 ///
@@ -34,6 +36,46 @@ impl<T> TemplateLiteral<T> {
     }
 }
 
+impl<T> TemplateLiteral<T>
+where
+    T: AsRef<str>,
+{
+    /// The value of this part of the template with all of
+    /// its escape sequences replaced and line endings turned
+    /// into `\n`, this only allocates when something had to
+    /// be replaced. Like a tagged template's cooked strings
+    /// this is `None` when there is an invalid escape
+    pub fn cooked(&self) -> Option<Cow<'_, str>> {
+        super::escape::cook(self.content.as_ref(), false, true)
+    }
+    /// The text of this part of the template exactly as it
+    /// appears in the source, escapes and line endings included
+    pub fn raw(&self) -> &str {
+        self.content.as_ref()
+    }
+}
+
+impl<T> Template<T>
+where
+    T: AsRef<str>,
+{
+    /// The cooked value of this part of the template,
+    /// see `TemplateLiteral::cooked`
+    pub fn cooked(&self) -> Option<Cow<'_, str>> {
+        self.literal().cooked()
+    }
+    /// The raw text of this part of the template,
+    /// see `TemplateLiteral::raw`
+    pub fn raw(&self) -> &str {
+        self.literal().raw()
+    }
+    fn literal(&self) -> &TemplateLiteral<T> {
+        match self {
+            Template::NoSub(t) | Template::Head(t) | Template::Middle(t) | Template::Tail(t) => t,
+        }
+    }
+}
+
 impl<T> Template<T> {
     pub fn no_sub_template(content: T, oct: bool, uni: bool, hex: bool) -> Self {
         Template::NoSub(TemplateLiteral::new(content, oct, uni, hex))
@@ -48,41 +90,55 @@ impl<T> Template<T> {
         Template::Tail(TemplateLiteral::new(content, oct, uni, hex))
     }
     pub fn is_head(&self) -> bool {
-        match self {
-            Template::Head(_) => true,
-            _ => false,
-        }
+        matches!(self, Template::Head(_))
     }
     pub fn is_middle(&self) -> bool {
-        match self {
-            Template::Middle(_) => true,
-            _ => false,
-        }
+        matches!(self, Template::Middle(_))
     }
     pub fn is_tail(&self) -> bool {
-        match self {
-            Template::Tail(_) => true,
-            _ => false,
-        }
+        matches!(self, Template::Tail(_))
     }
     pub fn is_no_sub(&self) -> bool {
+        matches!(self, Template::NoSub(_))
+    }
+}
+
+impl Template<&str> {
+    /// Copy the content of this template part
+    /// into an owned `String`
+    pub fn to_owned(&self) -> Template<String> {
         match self {
-            Template::NoSub(_) => true,
-            _ => false,
+            Template::NoSub(t) => Template::NoSub(t.to_owned()),
+            Template::Head(t) => Template::Head(t.to_owned()),
+            Template::Middle(t) => Template::Middle(t.to_owned()),
+            Template::Tail(t) => Template::Tail(t.to_owned()),
         }
     }
 }
 
-impl<T> ToString for Template<T>
+impl TemplateLiteral<&str> {
+    /// Copy the content of this literal into an owned
+    /// `String`, the escape flags are kept as is
+    pub fn to_owned(&self) -> TemplateLiteral<String> {
+        TemplateLiteral::new(
+            self.content.to_string(),
+            self.contains_octal_escape,
+            self.contains_invalid_unicode_escape,
+            self.contains_invalid_hex_escape,
+        )
+    }
+}
+
+impl<T> ::std::fmt::Display for Template<T>
 where
     T: AsRef<str>,
 {
-    fn to_string(&self) -> String {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match self {
-            Template::NoSub(ref t) => format!("`{}`", t.content.as_ref()),
-            Template::Head(ref t) => format!("`{}${{", t.content.as_ref()),
-            Template::Middle(ref t) => format!("}}{}${{", t.content.as_ref()),
-            Template::Tail(ref t) => format!("}}{}`", t.content.as_ref()),
+            Template::NoSub(ref t) => write!(f, "`{}`", t.content.as_ref()),
+            Template::Head(ref t) => write!(f, "`{}${{", t.content.as_ref()),
+            Template::Middle(ref t) => write!(f, "}}{}${{", t.content.as_ref()),
+            Template::Tail(ref t) => write!(f, "}}{}`", t.content.as_ref()),
         }
     }
 }
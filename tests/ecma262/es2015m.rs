@@ -157,7 +157,7 @@ lazy_static! {
         Token::Keyword(Keyword::Export("Export")),
         Token::Keyword(Keyword::Class("Class")),
         Token::Ident("e14".into()),
-        Token::Keyword(Keyword::Extends("extends".into())),
+        Token::Keyword(Keyword::Extends("extends")),
         Token::Ident("e15".into()),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
@@ -2380,7 +2380,7 @@ export default (0, 1);
         Token::Punct(Punct::CloseBrace),
         Token::Keyword(Keyword::Class("Class")),
         Token::Ident("B".into()),
-        Token::Keyword(Keyword::Extends("extends".into())),
+        Token::Keyword(Keyword::Extends("extends")),
         Token::Keyword(Keyword::New("New")),
         Token::Ident("A".into()),
         Token::Punct(Punct::OpenBrace),
@@ -2809,7 +2809,7 @@ export default (0, 1);
         Token::Punct(Punct::CloseBrace),
         Token::Keyword(Keyword::Class("Class")),
         Token::Ident("C".into()),
-        Token::Keyword(Keyword::Extends("extends".into())),
+        Token::Keyword(Keyword::Extends("extends")),
         Token::Ident("B".into()),
         Token::Punct(Punct::OpenBrace),
         Token::String(StringLit::double("constructor", false)),
@@ -9,62 +9,62 @@ lazy_static! {
         Token::Comment(Comment::new_single_line(" whitespace")),
         Token::Ident("tab".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Ident("tab".into()),
         Token::Punct(Punct::SemiColon),
         Token::Ident("verticalTab".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Ident("verticalTab".into()),
         Token::Punct(Punct::SemiColon),
         Token::Ident("formFeed".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Ident("formFeed".into()),
         Token::Punct(Punct::SemiColon),
         Token::Ident("space".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Ident("space".into()),
         Token::Punct(Punct::SemiColon),
         Token::Ident("nbsp".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Ident("nbsp".into()),
         Token::Punct(Punct::SemiColon),
         Token::Ident("bom".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Ident("bom".into()),
         Token::Punct(Punct::SemiColon),
         Token::Comment(Comment::new_single_line(" line terminators")),
@@ -94,7 +94,7 @@ lazy_static! {
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
         Token::Comment(Comment::new_single_line(" identifier names")),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("$".into()),
         Token::Punct(Punct::Comma),
         Token::Ident("_".into()),
@@ -126,7 +126,7 @@ lazy_static! {
         Token::Comment(Comment::new_single_line(
             " a representative sample of ID_Start and ID_Continue"
         )),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("䩶".into()),
         Token::Punct(Punct::Comma),
         Token::Ident("x󠇕".into()),
@@ -145,8 +145,8 @@ lazy_static! {
         Token::Punct(Punct::Comma),
         Token::Ident("x‌‍".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Var("var".into())),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Var("var")),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Punct(Punct::SemiColon),
         Token::Keyword(Keyword::Let("Let")),
         Token::Ident("letx".into()),
@@ -460,7 +460,7 @@ lazy_static! {
         Token::Punct(Punct::Colon),
         Token::Number("0".into()),
         Token::Punct(Punct::Comma),
-        Token::Keyword(Keyword::In("in".into())),
+        Token::Keyword(Keyword::In("in")),
         Token::Punct(Punct::Colon),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseBrace),
@@ -544,14 +544,14 @@ lazy_static! {
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::Comma),
         Token::Ident("get".into()),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::Comma),
         Token::Ident("set".into()),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Punct(Punct::OpenParen),
         Token::Ident("a".into()),
         Token::Punct(Punct::CloseParen),
@@ -627,7 +627,7 @@ lazy_static! {
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::Comma),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Punct(Punct::OpenParen),
         Token::Ident("a".into()),
         Token::Punct(Punct::Comma),
@@ -672,7 +672,7 @@ lazy_static! {
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::Comma),
         Token::Ident("set".into()),
-        Token::Keyword(Keyword::In("in".into())),
+        Token::Keyword(Keyword::In("in")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::OpenBracket),
         Token::Ident("a".into()),
@@ -768,7 +768,7 @@ lazy_static! {
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::Comma),
         Token::Punct(Punct::Asterisk),
-        Token::Keyword(Keyword::In("in".into())),
+        Token::Keyword(Keyword::In("in")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
@@ -840,12 +840,12 @@ lazy_static! {
         )),
         Token::Ident("x".into()),
         Token::Punct(Punct::Equal),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Return("return".into())),
+        Token::Keyword(Keyword::Return("return")),
         Token::Ident("f".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
@@ -863,20 +863,20 @@ lazy_static! {
         Token::Punct(Punct::Equal),
         Token::Ident("x".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::OpenBracket),
         Token::Number("0".into()),
@@ -884,14 +884,14 @@ lazy_static! {
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::Period),
         Token::Ident("a".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::OpenBracket),
         Token::Number("0".into()),
@@ -901,7 +901,7 @@ lazy_static! {
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::Period),
         Token::Ident("a".into()),
@@ -911,29 +911,29 @@ lazy_static! {
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::Period),
         Token::Ident("a".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
@@ -1011,14 +1011,14 @@ lazy_static! {
         Token::Punct(Punct::CloseBracket),
         Token::Punct(Punct::Comma),
         Token::Punct(Punct::Ellipsis),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Punct(Punct::Asterisk),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Return("return".into())),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Return("return")),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Number("2".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
@@ -1102,15 +1102,15 @@ lazy_static! {
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
         Token::Number("0".into()),
-        Token::Keyword(Keyword::InstanceOf("instanceof".into())),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::InstanceOf("instanceof")),
+        Token::Keyword(Keyword::Function("function")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::SemiColon),
         Token::Number("0".into()),
-        Token::Keyword(Keyword::In("in".into())),
+        Token::Keyword(Keyword::In("in")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::SemiColon),
@@ -1267,41 +1267,41 @@ lazy_static! {
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("x".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("x".into()),
         Token::Punct(Punct::Comma),
         Token::Ident("y".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("x".into()),
         Token::Punct(Punct::Comma),
         Token::Ident("y".into()),
         Token::Punct(Punct::Comma),
         Token::Ident("z".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("x".into()),
         Token::Punct(Punct::Equal),
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("x".into()),
         Token::Punct(Punct::Equal),
         Token::Number("0".into()),
         Token::Punct(Punct::Comma),
         Token::Ident("y".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("x".into()),
         Token::Punct(Punct::Comma),
         Token::Ident("y".into()),
         Token::Punct(Punct::Equal),
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("x".into()),
         Token::Punct(Punct::Equal),
         Token::Number("0".into()),
@@ -1311,17 +1311,17 @@ lazy_static! {
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::If("if".into())),
+        Token::Keyword(Keyword::If("if")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::If("if".into())),
+        Token::Keyword(Keyword::If("if")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Else("else".into())),
+        Token::Keyword(Keyword::Else("else")),
         Token::Punct(Punct::SemiColon),
         Token::Keyword(Keyword::Do("Do")),
         Token::Punct(Punct::SemiColon),
@@ -1350,14 +1350,14 @@ lazy_static! {
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
@@ -1366,11 +1366,11 @@ lazy_static! {
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
-        Token::Keyword(Keyword::In("in".into())),
+        Token::Keyword(Keyword::In("in")),
         Token::Punct(Punct::OpenBracket),
         Token::Punct(Punct::CloseBracket),
         Token::Punct(Punct::CloseParen),
@@ -1379,18 +1379,18 @@ lazy_static! {
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("a".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("a".into()),
         Token::Punct(Punct::Comma),
         Token::Ident("b".into()),
@@ -1400,25 +1400,25 @@ lazy_static! {
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("a".into()),
         Token::Punct(Punct::Equal),
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("a".into()),
         Token::Punct(Punct::Equal),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
-        Token::Keyword(Keyword::In("in".into())),
+        Token::Keyword(Keyword::In("in")),
         Token::Punct(Punct::OpenBracket),
         Token::Punct(Punct::CloseBracket),
         Token::Punct(Punct::CloseParen),
@@ -1427,24 +1427,24 @@ lazy_static! {
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
-        Token::Keyword(Keyword::In("in".into())),
+        Token::Keyword(Keyword::In("in")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("x".into()),
-        Token::Keyword(Keyword::In("in".into())),
+        Token::Keyword(Keyword::In("in")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
         Token::Ident("of".into()),
@@ -1452,76 +1452,76 @@ lazy_static! {
         Token::Punct(Punct::CloseBracket),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("x".into()),
         Token::Ident("of".into()),
         Token::Punct(Punct::OpenBracket),
         Token::Punct(Punct::CloseBracket),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Continue("continue".into())),
+        Token::Keyword(Keyword::Continue("continue")),
         Token::Punct(Punct::SemiColon),
         Token::Ident("x".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Continue("continue".into())),
+        Token::Keyword(Keyword::Continue("continue")),
         Token::Ident("x".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Punct(Punct::SemiColon),
         Token::Ident("x".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Ident("x".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Case("case".into())),
+        Token::Keyword(Keyword::Case("case")),
         Token::Number("0".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Return("return".into())),
+        Token::Keyword(Keyword::Return("return")),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Return("return".into())),
+        Token::Keyword(Keyword::Return("return")),
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
@@ -1530,34 +1530,34 @@ lazy_static! {
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Case("case".into())),
+        Token::Keyword(Keyword::Case("case")),
         Token::Number("0".into()),
         Token::Punct(Punct::Colon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Case("case".into())),
+        Token::Keyword(Keyword::Case("case")),
         Token::Number("0".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::Case("case".into())),
+        Token::Keyword(Keyword::Case("case")),
         Token::Number("0".into()),
         Token::Punct(Punct::Colon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
@@ -1565,42 +1565,42 @@ lazy_static! {
         Token::Keyword(Keyword::Default("Default")),
         Token::Punct(Punct::Colon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Case("case".into())),
+        Token::Keyword(Keyword::Case("case")),
         Token::Number("0".into()),
         Token::Punct(Punct::Colon),
         Token::Keyword(Keyword::Default("Default")),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::Case("case".into())),
+        Token::Keyword(Keyword::Case("case")),
         Token::Number("0".into()),
         Token::Punct(Punct::Colon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Case("case".into())),
+        Token::Keyword(Keyword::Case("case")),
         Token::Number("0".into()),
         Token::Punct(Punct::Colon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Case("case".into())),
+        Token::Keyword(Keyword::Case("case")),
         Token::Number("0".into()),
         Token::Punct(Punct::Colon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
@@ -1609,7 +1609,7 @@ lazy_static! {
         Token::Punct(Punct::Colon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
@@ -1627,61 +1627,61 @@ lazy_static! {
         Token::Ident("y".into()),
         Token::Punct(Punct::Colon),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Try("try".into())),
+        Token::Keyword(Keyword::Try("try")),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Throw("throw".into())),
+        Token::Keyword(Keyword::Throw("throw")),
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Catch("catch".into())),
+        Token::Keyword(Keyword::Catch("catch")),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Try("try".into())),
+        Token::Keyword(Keyword::Try("try")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Catch("catch".into())),
+        Token::Keyword(Keyword::Catch("catch")),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Try("try".into())),
+        Token::Keyword(Keyword::Try("try")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Finally("finally".into())),
+        Token::Keyword(Keyword::Finally("finally")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Try("try".into())),
+        Token::Keyword(Keyword::Try("try")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Catch("catch".into())),
+        Token::Keyword(Keyword::Catch("catch")),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Finally("finally".into())),
+        Token::Keyword(Keyword::Finally("finally")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Debugger("debugger".into())),
+        Token::Keyword(Keyword::Debugger("debugger")),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
@@ -1690,12 +1690,12 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
@@ -1703,7 +1703,7 @@ lazy_static! {
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
@@ -1713,7 +1713,7 @@ lazy_static! {
         Token::Punct(Punct::SemiColon),
         Token::Comment(Comment::new_single_line("for (;0;) label: function f(){} 0")),
         Token::Comment(Comment::new_single_line("do label: function f(){} while(0)")),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Ident("a".into()),
@@ -1757,35 +1757,35 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::String(StringLit::double("use strict", false)),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::String(StringLit::single("use strict", false)),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::String(StringLit::double("other directive", false)),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::String(StringLit::single("other directive", false)),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
@@ -1794,7 +1794,7 @@ lazy_static! {
         Token::String(StringLit::double("string", false)),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
@@ -1803,7 +1803,7 @@ lazy_static! {
         Token::String(StringLit::single("string", false)),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
@@ -1812,7 +1812,7 @@ lazy_static! {
         Token::Punct(Punct::Plus),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Punct(Punct::Asterisk),
         Token::Ident("g".into()),
         Token::Punct(Punct::OpenParen),
@@ -1856,22 +1856,22 @@ lazy_static! {
         Token::Ident("k".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Return("return".into())),
+        Token::Keyword(Keyword::Return("return")),
         Token::Ident("a".into()),
         Token::Punct(Punct::Equal),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Punct(Punct::Asterisk),
         Token::Ident("b".into()),
         Token::Punct(Punct::Equal),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Ident("c".into()),
         Token::Punct(Punct::Equal),
-        Token::Keyword(Keyword::Yield("yield".into())),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Yield("yield")),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Punct(Punct::Asterisk),
         Token::Ident("g".into()),
         Token::Punct(Punct::OpenParen),
@@ -1915,33 +1915,33 @@ lazy_static! {
         Token::Ident("k".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Return("return".into())),
+        Token::Keyword(Keyword::Return("return")),
         Token::Ident("a".into()),
         Token::Punct(Punct::Equal),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Punct(Punct::Asterisk),
         Token::Ident("b".into()),
         Token::Punct(Punct::Equal),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Ident("c".into()),
         Token::Punct(Punct::Equal),
-        Token::Keyword(Keyword::Yield("yield".into())),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Yield("yield")),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Punct(Punct::Plus),
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Punct(Punct::Asterisk),
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
@@ -1949,7 +1949,7 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
         Token::Punct(Punct::CloseParen),
@@ -1958,7 +1958,7 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
         Token::Punct(Punct::Comma),
@@ -1969,11 +1969,11 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
@@ -1983,7 +1983,7 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
@@ -1992,7 +1992,7 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
@@ -2002,7 +2002,7 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
@@ -2014,12 +2014,12 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
@@ -2076,7 +2076,7 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::EqualGreaterThan),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Return("return".into())),
+        Token::Keyword(Keyword::Return("return")),
         Token::Ident("x".into()),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::SemiColon),
@@ -2167,10 +2167,10 @@ lazy_static! {
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Try("try".into())),
+        Token::Keyword(Keyword::Try("try")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Catch("catch".into())),
+        Token::Keyword(Keyword::Catch("catch")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::OpenBracket),
         Token::Ident("e".into()),
@@ -2178,10 +2178,10 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Try("try".into())),
+        Token::Keyword(Keyword::Try("try")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Catch("catch".into())),
+        Token::Keyword(Keyword::Catch("catch")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::OpenBrace),
         Token::Ident("e".into()),
@@ -2189,14 +2189,14 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Class("class".into())),
+        Token::Keyword(Keyword::Class("class")),
         Token::Ident("A".into()),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Class("class".into())),
+        Token::Keyword(Keyword::Class("class")),
         Token::Ident("B".into()),
-        Token::Keyword(Keyword::Extends("extends".into())),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::Extends("extends")),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("A".into()),
         Token::Punct(Punct::OpenBrace),
         Token::Ident("constructor".into()),
@@ -2241,14 +2241,14 @@ lazy_static! {
         Token::Ident("k".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Super("super".into())),
+        Token::Keyword(Keyword::Super("super")),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
         Token::Punct(Punct::Period),
         Token::Ident("target".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Super("super".into())),
+        Token::Keyword(Keyword::Super("super")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Template(Template::no_sub_template("template", false, false, false)),
@@ -2256,7 +2256,7 @@ lazy_static! {
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::EqualGreaterThan),
-        Token::Keyword(Keyword::Super("super".into())),
+        Token::Keyword(Keyword::Super("super")),
         Token::Punct(Punct::OpenParen),
         Token::Keyword(Keyword::This("This")),
         Token::Punct(Punct::CloseParen),
@@ -2304,13 +2304,13 @@ lazy_static! {
         Token::Ident("k".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Super("super".into())),
+        Token::Keyword(Keyword::Super("super")),
         Token::Punct(Punct::Period),
         Token::Ident("m".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Super("super".into())),
+        Token::Keyword(Keyword::Super("super")),
         Token::Punct(Punct::Period),
         Token::Ident("m".into()),
         Token::Template(Template::no_sub_template("template", false, false, false)),
@@ -2318,7 +2318,7 @@ lazy_static! {
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::EqualGreaterThan),
-        Token::Keyword(Keyword::Super("super".into())),
+        Token::Keyword(Keyword::Super("super")),
         Token::Punct(Punct::Period),
         Token::Ident("m".into()),
         Token::Punct(Punct::OpenParen),
@@ -2359,7 +2359,7 @@ lazy_static! {
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
         Token::Keyword(Keyword::Static("static")),
@@ -2368,7 +2368,7 @@ lazy_static! {
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
         Token::Keyword(Keyword::Static("Static")),
@@ -2377,7 +2377,7 @@ lazy_static! {
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
         Token::Keyword(Keyword::Static("static")),
@@ -2388,18 +2388,18 @@ lazy_static! {
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
         Token::Keyword(Keyword::Static("static")),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Keyword(Keyword::Static("static")),
         Token::Punct(Punct::Asterisk),
-        Token::Keyword(Keyword::In("in".into())),
+        Token::Keyword(Keyword::In("in")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
@@ -2470,14 +2470,14 @@ lazy_static! {
         Token::Punct(Punct::CloseBrace),
         Token::Keyword(Keyword::Static("static")),
         Token::Ident("get".into()),
-        Token::Keyword(Keyword::If("if".into())),
+        Token::Keyword(Keyword::If("if")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Keyword(Keyword::Static("static")),
         Token::Ident("set".into()),
-        Token::Keyword(Keyword::If("if".into())),
+        Token::Keyword(Keyword::If("if")),
         Token::Punct(Punct::OpenParen),
         Token::Ident("a".into()),
         Token::Punct(Punct::CloseParen),
@@ -2510,7 +2510,7 @@ lazy_static! {
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::Asterisk),
@@ -2518,7 +2518,7 @@ lazy_static! {
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::Asterisk),
@@ -2526,7 +2526,7 @@ lazy_static! {
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::Asterisk),
@@ -2536,20 +2536,20 @@ lazy_static! {
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::Asterisk),
-        Token::Keyword(Keyword::In("in".into())),
+        Token::Keyword(Keyword::In("in")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Yield("yield".into())),
+        Token::Keyword(Keyword::Yield("yield")),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
         Token::Ident("get".into()),
@@ -2609,29 +2609,29 @@ lazy_static! {
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Ident("get".into()),
-        Token::Keyword(Keyword::If("if".into())),
+        Token::Keyword(Keyword::If("if")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Ident("set".into()),
-        Token::Keyword(Keyword::If("if".into())),
+        Token::Keyword(Keyword::If("if")),
         Token::Punct(Punct::OpenParen),
         Token::Ident("f".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Class("class".into())),
+        Token::Keyword(Keyword::Class("class")),
         Token::Ident("C".into()),
-        Token::Keyword(Keyword::Extends("extends".into())),
+        Token::Keyword(Keyword::Extends("extends")),
         Token::Ident("B".into()),
         Token::Punct(Punct::OpenBrace),
         Token::String(StringLit::double("constructor", false)),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Super("super".into())),
+        Token::Keyword(Keyword::Super("super")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
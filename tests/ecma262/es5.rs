@@ -8,62 +8,62 @@ lazy_static! {
         Token::Comment(Comment::new_single_line(" whitespace")),
         Token::Ident("tab".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Ident("tab".into()),
         Token::Punct(Punct::SemiColon),
         Token::Ident("verticalTab".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Ident("verticalTab".into()),
         Token::Punct(Punct::SemiColon),
         Token::Ident("formFeed".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Ident("formFeed".into()),
         Token::Punct(Punct::SemiColon),
         Token::Ident("space".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Ident("space".into()),
         Token::Punct(Punct::SemiColon),
         Token::Ident("nbsp".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Ident("nbsp".into()),
         Token::Punct(Punct::SemiColon),
         Token::Ident("bom".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Ident("bom".into()),
         Token::Punct(Punct::SemiColon),
         Token::Comment(Comment::new_single_line(" line terminators")),
@@ -93,7 +93,7 @@ lazy_static! {
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
         Token::Comment(Comment::new_single_line(" identifier names")),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("$".into()),
         Token::Punct(Punct::Comma),
         Token::Ident("_".into()),
@@ -121,7 +121,7 @@ lazy_static! {
         Token::Comment(Comment::new_single_line(
             " a representative sample of unicode letters and numbers"
         )),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("œ一".into()),
         Token::Punct(Punct::Comma),
         Token::Ident("ǻ둘".into()),
@@ -267,7 +267,7 @@ lazy_static! {
         Token::Punct(Punct::SemiColon),
         Token::RegEx(RegEx::from_parts(".{0,0}", None)),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::This("this".into())),
+        Token::Keyword(Keyword::This("this")),
         Token::Punct(Punct::SemiColon),
         Token::Ident("x".into()),
         Token::Punct(Punct::SemiColon),
@@ -361,7 +361,7 @@ lazy_static! {
         Token::Punct(Punct::Colon),
         Token::Number("0".into()),
         Token::Punct(Punct::Comma),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Punct(Punct::Colon),
         Token::Number("0".into()),
         Token::Punct(Punct::Comma),
@@ -464,14 +464,14 @@ lazy_static! {
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::Comma),
         Token::Ident("get".into()),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::Comma),
         Token::Ident("set".into()),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
         Token::Punct(Punct::CloseParen),
@@ -495,12 +495,12 @@ lazy_static! {
         )),
         Token::Ident("x".into()),
         Token::Punct(Punct::Equal),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Return("return".into())),
+        Token::Keyword(Keyword::Return("return")),
         Token::Ident("f".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
@@ -518,20 +518,20 @@ lazy_static! {
         Token::Punct(Punct::Equal),
         Token::Ident("x".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::OpenBracket),
         Token::Number("0".into()),
@@ -539,14 +539,14 @@ lazy_static! {
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::Period),
         Token::Ident("a".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::OpenBracket),
         Token::Number("0".into()),
@@ -556,7 +556,7 @@ lazy_static! {
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::Period),
         Token::Ident("a".into()),
@@ -566,29 +566,29 @@ lazy_static! {
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::Period),
         Token::Ident("a".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::New("new".into())),
-        Token::Keyword(Keyword::New("new".into())),
+        Token::Keyword(Keyword::New("new")),
+        Token::Keyword(Keyword::New("new")),
         Token::Ident("x".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
@@ -657,9 +657,9 @@ lazy_static! {
         Token::Ident("x".into()),
         Token::Punct(Punct::DoubleDash),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Delete("delete".into())),
-        Token::Keyword(Keyword::Void("void".into())),
-        Token::Keyword(Keyword::TypeOf("typeof".into())),
+        Token::Keyword(Keyword::Delete("delete")),
+        Token::Keyword(Keyword::Void("void")),
+        Token::Keyword(Keyword::TypeOf("typeof")),
         Token::Punct(Punct::Plus),
         Token::Punct(Punct::Dash),
         Token::Punct(Punct::Tilde),
@@ -721,15 +721,15 @@ lazy_static! {
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
         Token::Number("0".into()),
-        Token::Keyword(Keyword::InstanceOf("instanceof".into())),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::InstanceOf("instanceof")),
+        Token::Keyword(Keyword::Function("function")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::SemiColon),
         Token::Number("0".into()),
-        Token::Keyword(Keyword::In("in".into())),
+        Token::Keyword(Keyword::In("in")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::SemiColon),
@@ -886,41 +886,41 @@ lazy_static! {
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("x".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("x".into()),
         Token::Punct(Punct::Comma),
         Token::Ident("y".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("x".into()),
         Token::Punct(Punct::Comma),
         Token::Ident("y".into()),
         Token::Punct(Punct::Comma),
         Token::Ident("z".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("x".into()),
         Token::Punct(Punct::Equal),
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("x".into()),
         Token::Punct(Punct::Equal),
         Token::Number("0".into()),
         Token::Punct(Punct::Comma),
         Token::Ident("y".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("x".into()),
         Token::Punct(Punct::Comma),
         Token::Ident("y".into()),
         Token::Punct(Punct::Equal),
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("x".into()),
         Token::Punct(Punct::Equal),
         Token::Number("0".into()),
@@ -930,38 +930,38 @@ lazy_static! {
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::If("if".into())),
+        Token::Keyword(Keyword::If("if")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::If("if".into())),
+        Token::Keyword(Keyword::If("if")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Else("else".into())),
+        Token::Keyword(Keyword::Else("else")),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Do("do".into())),
+        Token::Keyword(Keyword::Do("do")),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::While("while".into())),
+        Token::Keyword(Keyword::While("while")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::While("while".into())),
+        Token::Keyword(Keyword::While("while")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
@@ -970,11 +970,11 @@ lazy_static! {
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
-        Token::Keyword(Keyword::In("in".into())),
+        Token::Keyword(Keyword::In("in")),
         Token::Punct(Punct::OpenBracket),
         Token::Punct(Punct::CloseBracket),
         Token::Punct(Punct::CloseParen),
@@ -983,18 +983,18 @@ lazy_static! {
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("a".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("a".into()),
         Token::Punct(Punct::Comma),
         Token::Ident("b".into()),
@@ -1004,25 +1004,25 @@ lazy_static! {
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("a".into()),
         Token::Punct(Punct::Equal),
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("a".into()),
         Token::Punct(Punct::Equal),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
-        Token::Keyword(Keyword::In("in".into())),
+        Token::Keyword(Keyword::In("in")),
         Token::Punct(Punct::OpenBracket),
         Token::Punct(Punct::CloseBracket),
         Token::Punct(Punct::CloseParen),
@@ -1031,205 +1031,205 @@ lazy_static! {
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
-        Token::Keyword(Keyword::In("in".into())),
+        Token::Keyword(Keyword::In("in")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("x".into()),
-        Token::Keyword(Keyword::In("in".into())),
+        Token::Keyword(Keyword::In("in")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("x".into()),
         Token::Punct(Punct::Equal),
         Token::Punct(Punct::OpenBracket),
         Token::Punct(Punct::CloseBracket),
-        Token::Keyword(Keyword::In("in".into())),
+        Token::Keyword(Keyword::In("in")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Var("var".into())),
+        Token::Keyword(Keyword::Var("var")),
         Token::Ident("x".into()),
         Token::Punct(Punct::Equal),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
-        Token::Keyword(Keyword::In("in".into())),
+        Token::Keyword(Keyword::In("in")),
         Token::Punct(Punct::OpenBracket),
         Token::Punct(Punct::CloseBracket),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::In("in".into())),
+        Token::Keyword(Keyword::In("in")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Continue("continue".into())),
+        Token::Keyword(Keyword::Continue("continue")),
         Token::Punct(Punct::SemiColon),
         Token::Ident("x".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Continue("continue".into())),
+        Token::Keyword(Keyword::Continue("continue")),
         Token::Ident("x".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Punct(Punct::SemiColon),
         Token::Ident("x".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::For("for".into())),
+        Token::Keyword(Keyword::For("for")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseParen),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Ident("x".into()),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Case("case".into())),
+        Token::Keyword(Keyword::Case("case")),
         Token::Number("0".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::Break("break".into())),
+        Token::Keyword(Keyword::Break("break")),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Return("return".into())),
+        Token::Keyword(Keyword::Return("return")),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Return("return".into())),
+        Token::Keyword(Keyword::Return("return")),
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::With("with".into())),
+        Token::Keyword(Keyword::With("with")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Case("case".into())),
+        Token::Keyword(Keyword::Case("case")),
         Token::Number("0".into()),
         Token::Punct(Punct::Colon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Case("case".into())),
+        Token::Keyword(Keyword::Case("case")),
         Token::Number("0".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::Case("case".into())),
+        Token::Keyword(Keyword::Case("case")),
         Token::Number("0".into()),
         Token::Punct(Punct::Colon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Default("default".into())),
+        Token::Keyword(Keyword::Default("default")),
         Token::Punct(Punct::Colon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Case("case".into())),
+        Token::Keyword(Keyword::Case("case")),
         Token::Number("0".into()),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::Default("default".into())),
+        Token::Keyword(Keyword::Default("default")),
         Token::Punct(Punct::Colon),
-        Token::Keyword(Keyword::Case("case".into())),
+        Token::Keyword(Keyword::Case("case")),
         Token::Number("0".into()),
         Token::Punct(Punct::Colon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Case("case".into())),
+        Token::Keyword(Keyword::Case("case")),
         Token::Number("0".into()),
         Token::Punct(Punct::Colon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Case("case".into())),
+        Token::Keyword(Keyword::Case("case")),
         Token::Number("0".into()),
         Token::Punct(Punct::Colon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Default("default".into())),
+        Token::Keyword(Keyword::Default("default")),
         Token::Punct(Punct::Colon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Switch("switch".into())),
+        Token::Keyword(Keyword::Switch("switch")),
         Token::Punct(Punct::OpenParen),
         Token::Number("0".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Default("default".into())),
+        Token::Keyword(Keyword::Default("default")),
         Token::Punct(Punct::Colon),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::SemiColon),
@@ -1242,61 +1242,61 @@ lazy_static! {
         Token::Ident("y".into()),
         Token::Punct(Punct::Colon),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Try("try".into())),
+        Token::Keyword(Keyword::Try("try")),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Throw("throw".into())),
+        Token::Keyword(Keyword::Throw("throw")),
         Token::Number("0".into()),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Catch("catch".into())),
+        Token::Keyword(Keyword::Catch("catch")),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Try("try".into())),
+        Token::Keyword(Keyword::Try("try")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Catch("catch".into())),
+        Token::Keyword(Keyword::Catch("catch")),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Try("try".into())),
+        Token::Keyword(Keyword::Try("try")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Finally("finally".into())),
+        Token::Keyword(Keyword::Finally("finally")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Try("try".into())),
+        Token::Keyword(Keyword::Try("try")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Catch("catch".into())),
+        Token::Keyword(Keyword::Catch("catch")),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Finally("finally".into())),
+        Token::Keyword(Keyword::Finally("finally")),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Debugger("debugger".into())),
+        Token::Keyword(Keyword::Debugger("debugger")),
         Token::Punct(Punct::SemiColon),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
@@ -1305,47 +1305,47 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::String(StringLit::double("use strict", false)),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::String(StringLit::single("use strict", false)),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::String(StringLit::double("other directive", false)),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
         Token::String(StringLit::single("other directive", false)),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
@@ -1354,7 +1354,7 @@ lazy_static! {
         Token::String(StringLit::double("string", false)),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
@@ -1363,7 +1363,7 @@ lazy_static! {
         Token::String(StringLit::single("string", false)),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::CloseBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
@@ -1373,7 +1373,7 @@ lazy_static! {
         Token::Number("0".into()),
         Token::Punct(Punct::CloseBrace),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
@@ -1381,7 +1381,7 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
         Token::Punct(Punct::CloseParen),
@@ -1390,7 +1390,7 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
         Token::Punct(Punct::Comma),
@@ -1401,11 +1401,11 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
@@ -1415,7 +1415,7 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
@@ -1424,7 +1424,7 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
@@ -1434,7 +1434,7 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Ident("x".into()),
@@ -1446,12 +1446,12 @@ lazy_static! {
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::SemiColon),
         Token::Punct(Punct::OpenParen),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
         Token::Punct(Punct::OpenBrace),
-        Token::Keyword(Keyword::Function("function".into())),
+        Token::Keyword(Keyword::Function("function")),
         Token::Ident("f".into()),
         Token::Punct(Punct::OpenParen),
         Token::Punct(Punct::CloseParen),
@@ -11,7 +11,7 @@ fn moz_central() {
     if !moz_central_path.exists() {
         panic!("please download the JIT tests from the firefox repository. see CONTRIBUTING.md for more info");
     }
-    let paths = get_paths(&moz_central_path);
+    let paths = get_paths(moz_central_path);
     let (failures, total) = walk(&paths);
     eprintln!("completed {:?} tests", total);
     if !failures.is_empty() {
@@ -52,7 +52,7 @@ fn walk(paths: &[PathBuf]) -> (Vec<String>, usize) {
     let mut ct = 0;
     for path in paths {
         ct += 1;
-        let js = read_to_string(&path).unwrap();
+        let js = read_to_string(path).unwrap();
         let s = Scanner::new(js.as_str());
         for item in s {
             if let Err(e) = item {
@@ -2,9 +2,55 @@ extern crate ress;
 #[macro_use]
 extern crate proptest;
 
+use ress::Scanner;
+
+/// Scan `s` to the end, every token but the `EoF`
+/// consumes at least one byte so anything producing
+/// more items than that is stuck
+fn scan_terminates(s: &str) {
+    let mut items = 0;
+    for item in Scanner::new(s) {
+        items += 1;
+        assert!(items <= s.len() + 1, "scanner is not making progress");
+        if item.is_err() {
+            break;
+        }
+    }
+}
+
+/// With error recovery on, scanning `s` never fails
+/// and the items and white space cover every byte
+fn recovery_covers_text(s: &str) {
+    let mut scanner = Scanner::new(s);
+    scanner.recover_errors(true);
+    let mut text = String::new();
+    for item in scanner.lossless() {
+        let (trivia, item) = item.unwrap();
+        assert!(text.len() <= s.len(), "scanner is not making progress");
+        text.push_str(trivia);
+        text.push_str(&s[item.span.start..item.span.end]);
+    }
+    assert_eq!(text, s);
+}
+
 proptest! {
     #[test]
     fn function_idents(s in r#"function [a-zA-Z_$\u2118\u212E\u309B\u309C\u1885\u1886][a-zA-Z_]+"#) {
         ress::tokenize(&s).unwrap();
     }
+
+    #[test]
+    fn any_text_terminates(s in r#"\PC*"#) {
+        scan_terminates(&s);
+    }
+
+    #[test]
+    fn js_like_text_terminates(s in r#"[a-z0-9_$ \t\n\r\u{a0}\u{2028}\u{feff}\u{1F600}/\\`'"{}()\[\].*+\-<>!=?:;,#@%&|^~]{0,64}"#) {
+        scan_terminates(&s);
+    }
+
+    #[test]
+    fn recovery_covers_any_text(s in r#"[a-z0-9_$ \t\n\r\u{a0}\u{2028}\u{1F600}/\\`'"{}()\[\].*+\-<>!=?:;,#@%&|^~]{0,64}"#) {
+        recovery_covers_text(&s);
+    }
 }
@@ -24,7 +24,7 @@ fn moment_regex_error() {
 fn number_member() {
     compare(
         "20..toString()",
-        &vec![
+        &[
             Token::Number("20.".into()),
             Token::Punct(Punct::Period),
             Token::Ident("toString".into()),
@@ -34,10 +34,333 @@ fn number_member() {
     );
 }
 #[test]
+fn number_member_matrix() {
+    let cases = [
+        ("20..x", "20."),
+        ("1..toString", "1."),
+        ("1.0.x", "1.0"),
+        ("1.5.x", "1.5"),
+        (".5.x", ".5"),
+        ("1e3.x", "1e3"),
+        ("0x1.x", "0x1"),
+        ("1n.x", "1n"),
+        ("1 .x", "1"),
+    ];
+    for (js, number) in &cases {
+        let name = js.rsplit('.').next().unwrap();
+        compare(
+            js,
+            &[
+                Token::Number((*number).into()),
+                Token::Punct(Punct::Period),
+                Token::Ident(name.into()),
+                Token::EoF,
+            ],
+        );
+    }
+    compare(
+        "(1).x",
+        &[
+            Token::Punct(Punct::OpenParen),
+            Token::Number("1".into()),
+            Token::Punct(Punct::CloseParen),
+            Token::Punct(Punct::Period),
+            Token::Ident("x".into()),
+            Token::EoF,
+        ],
+    );
+}
+#[test]
+fn period_longest_match() {
+    compare(
+        "a...b",
+        &[
+            Token::Ident("a".into()),
+            Token::Punct(Punct::Ellipsis),
+            Token::Ident("b".into()),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "a....b",
+        &[
+            Token::Ident("a".into()),
+            Token::Punct(Punct::Ellipsis),
+            Token::Punct(Punct::Period),
+            Token::Ident("b".into()),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "a..b",
+        &[
+            Token::Ident("a".into()),
+            Token::Punct(Punct::Period),
+            Token::Punct(Punct::Period),
+            Token::Ident("b".into()),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "1..a",
+        &[
+            Token::Number("1.".into()),
+            Token::Punct(Punct::Period),
+            Token::Ident("a".into()),
+            Token::EoF,
+        ],
+    );
+    compare(".5", &[Token::Number(".5".into()), Token::EoF]);
+    compare(
+        "x?.5:1",
+        &[
+            Token::Ident("x".into()),
+            Token::Punct(Punct::QuestionMark),
+            Token::Number(".5".into()),
+            Token::Punct(Punct::Colon),
+            Token::Number("1".into()),
+            Token::EoF,
+        ],
+    );
+}
+#[test]
+fn js_whitespace() {
+    compare_with_position(
+        "\u{feff}let\u{a0}a\u{1680}=\u{3000}1;\u{2028}a",
+        &[
+            (Token::Keyword(Keyword::Let("let")), 1, 1),
+            (Token::Ident("a".into()), 1, 5),
+            (Token::Punct(Punct::Equal), 1, 7),
+            (Token::Number("1".into()), 1, 9),
+            (Token::Punct(Punct::SemiColon), 1, 10),
+            (Token::Ident("a".into()), 2, 1),
+            (Token::EoF, 2, 2),
+        ],
+    );
+    compare_with_position(
+        "  a\u{3000}= / \u{3000}a/",
+        &[
+            (Token::Ident("a".into()), 1, 3),
+            (Token::Punct(Punct::Equal), 1, 5),
+            (Token::RegEx(RegEx::from_parts(" \u{3000}a", None)), 1, 7),
+            (Token::EoF, 1, 12),
+        ],
+    );
+    compare(
+        "\u{a0}\u{3000}--> comment",
+        &[
+            Token::Comment(Comment::new_html("", Some(" comment"))),
+            Token::EoF,
+        ],
+    );
+    // NEL is white space to rust but not to js
+    assert!(Scanner::new("a\u{85}b").any(|r| r.is_err()));
+}
+#[test]
+fn leading_bom() {
+    let js = "\u{feff}var x";
+    let items: Vec<_> = Scanner::new(js).map(Result::unwrap).collect();
+    assert_eq!(items[0].token, Token::Keyword(Keyword::Var("var")));
+    assert_eq!(items[0].span, ress::Span::new(3, 6));
+    assert_eq!(&js[items[0].span.start..items[0].span.end], "var");
+    assert_eq!(items[0].location.start, Position::new(1, 1));
+    assert_eq!(items[1].span, ress::Span::new(7, 8));
+    compare(
+        "\u{feff}#!/usr/bin/env node\n\u{feff}a",
+        &[
+            Token::Comment(Comment::new_hashbang("/usr/bin/env node")),
+            Token::Ident("a".into()),
+            Token::EoF,
+        ],
+    );
+}
+#[test]
+fn hashbang_and_private_names() {
+    compare(
+        "#!/usr/bin/env node\nclass A { #x = 1; has(o) { return #x in o && this.#x } }",
+        &[
+            Token::Comment(Comment::new_hashbang("/usr/bin/env node")),
+            Token::Keyword(Keyword::Class("class")),
+            Token::Ident("A".into()),
+            Token::Punct(Punct::OpenBrace),
+            Token::Punct(Punct::Hash),
+            Token::Ident("x".into()),
+            Token::Punct(Punct::Equal),
+            Token::Number("1".into()),
+            Token::Punct(Punct::SemiColon),
+            Token::Ident("has".into()),
+            Token::Punct(Punct::OpenParen),
+            Token::Ident("o".into()),
+            Token::Punct(Punct::CloseParen),
+            Token::Punct(Punct::OpenBrace),
+            Token::Keyword(Keyword::Return("return")),
+            Token::Punct(Punct::Hash),
+            Token::Ident("x".into()),
+            Token::Keyword(Keyword::In("in")),
+            Token::Ident("o".into()),
+            Token::Punct(Punct::DoubleAmpersand),
+            Token::Keyword(Keyword::This("this")),
+            Token::Punct(Punct::Period),
+            Token::Punct(Punct::Hash),
+            Token::Ident("x".into()),
+            Token::Punct(Punct::CloseBrace),
+            Token::Punct(Punct::CloseBrace),
+            Token::EoF,
+        ],
+    );
+    // `#!` is only a hashbang at the start and a `#`
+    // anywhere else has to start a private name
+    for js in &[
+        "x\n#!/usr/bin/env node",
+        "class A { # x }",
+        "a = #",
+        "this.#1",
+    ] {
+        let err = Scanner::new(js).find_map(Result::err).unwrap();
+        assert_eq!(
+            err.kind,
+            ress::error::ErrorKind::UnexpectedChar('#'),
+            "{}",
+            js
+        );
+    }
+}
+#[test]
+fn crlf_comment_spans() {
+    let js = "// note\r\nvar x";
+    let items: Vec<_> = Scanner::new(js).map(Result::unwrap).collect();
+    assert_eq!(
+        items[0].token,
+        Token::Comment(Comment::new_single_line(" note"))
+    );
+    assert_eq!(items[0].span, ress::Span::new(0, 7));
+    assert_eq!(items[0].location.end, Position::new(1, 8));
+    assert_eq!(items[1].span, ress::Span::new(9, 12));
+    assert_eq!(items[1].location.start, Position::new(2, 1));
+    assert_eq!(items[2].span, ress::Span::new(13, 14));
+    assert_eq!(items[3].span, ress::Span::new(14, 14));
+    let trivia: Vec<_> = Scanner::new(js).lossless().map(|r| r.unwrap().0).collect();
+    assert_eq!(trivia, vec!["", "\r\n", " ", ""]);
+    // every kind of comment that ends at a line terminator
+    for (js, comment) in &[
+        ("// a\rx", "// a"),
+        ("// a\r\n\r\nx", "// a"),
+        ("<!-- a\r\nx", "<!-- a"),
+        ("x\r\n--> a\r\nx", "--> a"),
+        ("#!node\r\nx", "#!node"),
+    ] {
+        let items: Vec<_> = Scanner::new(js).map(Result::unwrap).collect();
+        let idx = items.iter().position(|i| i.token.is_comment()).unwrap();
+        assert_eq!(&js[items[idx].span.range()], *comment, "{:?}", js);
+        let next = &items[idx + 1];
+        assert_eq!(&js[next.span.range()], "x", "{:?}", js);
+        assert_eq!(next.location.start.column, 1, "{:?}", js);
+    }
+}
+#[test]
+fn jsx_element() {
+    let js = r#"<div className="x">{y}</div>"#;
+    let mut scanner = Scanner::new(js);
+    scanner.jsx(true);
+    let tokens: Vec<_> = scanner.map(|item| item.unwrap().token).collect();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Jsx(Jsx::TagStart),
+            Token::Jsx(Jsx::Name("div")),
+            Token::Jsx(Jsx::Name("className")),
+            Token::Punct(Punct::Equal),
+            Token::String(StringLit::double("x", false)),
+            Token::Jsx(Jsx::TagEnd),
+            Token::Punct(Punct::OpenBrace),
+            Token::Ident("y".into()),
+            Token::Punct(Punct::CloseBrace),
+            Token::Jsx(Jsx::ClosingTagStart),
+            Token::Jsx(Jsx::Name("div")),
+            Token::Jsx(Jsx::TagEnd),
+            Token::EoF,
+        ]
+    );
+    // without the flag it is a comparison
+    compare(
+        "<div>",
+        &[
+            Token::Punct(Punct::LessThan),
+            Token::Ident("div".into()),
+            Token::Punct(Punct::GreaterThan),
+            Token::EoF,
+        ],
+    );
+}
+#[test]
+fn regex_newer_flags() {
+    compare(
+        "x = /a/d",
+        &[
+            Token::Ident("x".into()),
+            Token::Punct(Punct::Equal),
+            Token::RegEx(RegEx::from_parts("a", Some("d"))),
+            Token::EoF,
+        ],
+    );
+    compare(
+        r"x = /[\p{L}--[a]]/v",
+        &[
+            Token::Ident("x".into()),
+            Token::Punct(Punct::Equal),
+            Token::RegEx(RegEx::from_parts(r"[\p{L}--[a]]", Some("v"))),
+            Token::EoF,
+        ],
+    );
+    // a nested class may contain a `/` in v mode
+    compare(
+        r"x = /[[a/]&&[\w]]/gv",
+        &[
+            Token::Ident("x".into()),
+            Token::Punct(Punct::Equal),
+            Token::RegEx(RegEx::from_parts(r"[[a/]&&[\w]]", Some("gv"))),
+            Token::EoF,
+        ],
+    );
+    // but without it `[` is just a char in the class
+    compare(
+        r"x = /[[]/ / /[\]/]/",
+        &[
+            Token::Ident("x".into()),
+            Token::Punct(Punct::Equal),
+            Token::RegEx(RegEx::from_parts("[[]", None)),
+            Token::Punct(Punct::ForwardSlash),
+            Token::RegEx(RegEx::from_parts(r"[\]/]", None)),
+            Token::EoF,
+        ],
+    );
+}
+#[test]
+fn regex_property_escapes() {
+    for body in &[
+        r"\p{Script=Greek}",
+        r"\u{1F4A9}",
+        r"\P{Nd}\p{Letter}+",
+        r"[\p{L}\P{Lu}]",
+        "\u{1F4A9}{2}",
+    ] {
+        let js = format!("x = /{}/u", body);
+        compare(
+            &js,
+            &[
+                Token::Ident("x".into()),
+                Token::Punct(Punct::Equal),
+                Token::RegEx(RegEx::from_parts(body, Some("u"))),
+                Token::EoF,
+            ],
+        );
+    }
+}
+#[test]
 fn if_then_regex() {
     compare(
         "if (1) /a/",
-        &vec![
+        &[
             Token::Keyword(Keyword::If("If")),
             Token::Punct(Punct::OpenParen),
             Token::Number("1".into()),
@@ -164,6 +487,196 @@ fn regex_over_div4() {
         ],
     );
 }
+#[test]
+fn div_after_regex() {
+    compare(
+        "/a/ / /b/",
+        &[
+            Token::RegEx(RegEx::from_parts("a", None)),
+            Token::Punct(Punct::ForwardSlash),
+            Token::RegEx(RegEx::from_parts("b", None)),
+            Token::EoF,
+        ],
+    );
+    compare(
+        r"/a\/[/]b/g / /c/",
+        &[
+            Token::RegEx(RegEx::from_parts(r"a\/[/]b", Some("g"))),
+            Token::Punct(Punct::ForwardSlash),
+            Token::RegEx(RegEx::from_parts("c", None)),
+            Token::EoF,
+        ],
+    );
+}
+#[test]
+fn regex_over_div_arrow() {
+    compare(
+        "let x = (a) => /re/",
+        &[
+            Token::Keyword(Keyword::Let("let")),
+            Token::Ident("x".into()),
+            Token::Punct(Punct::Equal),
+            Token::Punct(Punct::OpenParen),
+            Token::Ident("a".into()),
+            Token::Punct(Punct::CloseParen),
+            Token::Punct(Punct::EqualGreaterThan),
+            Token::RegEx(RegEx::from_parts("re", None)),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "(a, b) => /x/g",
+        &[
+            Token::Punct(Punct::OpenParen),
+            Token::Ident("a".into()),
+            Token::Punct(Punct::Comma),
+            Token::Ident("b".into()),
+            Token::Punct(Punct::CloseParen),
+            Token::Punct(Punct::EqualGreaterThan),
+            Token::RegEx(RegEx::from_parts("x", Some("g"))),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "() => /x/",
+        &[
+            Token::Punct(Punct::OpenParen),
+            Token::Punct(Punct::CloseParen),
+            Token::Punct(Punct::EqualGreaterThan),
+            Token::RegEx(RegEx::from_parts("x", None)),
+            Token::EoF,
+        ],
+    );
+}
+#[test]
+fn div_in_arrow_body() {
+    compare(
+        "a => b / c",
+        &[
+            Token::Ident("a".into()),
+            Token::Punct(Punct::EqualGreaterThan),
+            Token::Ident("b".into()),
+            Token::Punct(Punct::ForwardSlash),
+            Token::Ident("c".into()),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "(a) => (a) / 2",
+        &[
+            Token::Punct(Punct::OpenParen),
+            Token::Ident("a".into()),
+            Token::Punct(Punct::CloseParen),
+            Token::Punct(Punct::EqualGreaterThan),
+            Token::Punct(Punct::OpenParen),
+            Token::Ident("a".into()),
+            Token::Punct(Punct::CloseParen),
+            Token::Punct(Punct::ForwardSlash),
+            Token::Number("2".into()),
+            Token::EoF,
+        ],
+    );
+}
+#[test]
+fn div_over_regex_paren() {
+    compare(
+        "return (y)/2",
+        &[
+            Token::Keyword(Keyword::Return("return")),
+            Token::Punct(Punct::OpenParen),
+            Token::Ident("y".into()),
+            Token::Punct(Punct::CloseParen),
+            Token::Punct(Punct::ForwardSlash),
+            Token::Number("2".into()),
+            Token::EoF,
+        ],
+    );
+}
+#[test]
+fn regex_over_div_yield() {
+    compare(
+        "function* g(){ yield /a/ }",
+        &[
+            Token::Keyword(Keyword::Function("function")),
+            Token::Punct(Punct::Asterisk),
+            Token::Ident("g".into()),
+            Token::Punct(Punct::OpenParen),
+            Token::Punct(Punct::CloseParen),
+            Token::Punct(Punct::OpenBrace),
+            Token::Keyword(Keyword::Yield("yield")),
+            Token::RegEx(RegEx::from_parts("a", None)),
+            Token::Punct(Punct::CloseBrace),
+            Token::EoF,
+        ],
+    );
+}
+
+#[test]
+fn class_const_extends_keywords() {
+    compare(
+        "class Foo extends Bar {} const constant = continued;",
+        &[
+            Token::Keyword(Keyword::Class("class")),
+            Token::Ident("Foo".into()),
+            Token::Keyword(Keyword::Extends("extends")),
+            Token::Ident("Bar".into()),
+            Token::Punct(Punct::OpenBrace),
+            Token::Punct(Punct::CloseBrace),
+            Token::Keyword(Keyword::Const("const")),
+            Token::Ident("constant".into()),
+            Token::Punct(Punct::Equal),
+            Token::Ident("continued".into()),
+            Token::Punct(Punct::SemiColon),
+            Token::EoF,
+        ],
+    );
+}
+
+#[test]
+fn logical_assignment() {
+    compare(
+        "a &&= b; c ||= /d/; e &= f",
+        &[
+            Token::Ident("a".into()),
+            Token::Punct(Punct::DoubleAmpersandEqual),
+            Token::Ident("b".into()),
+            Token::Punct(Punct::SemiColon),
+            Token::Ident("c".into()),
+            Token::Punct(Punct::DoublePipeEqual),
+            Token::RegEx(RegEx::from_parts("d", None)),
+            Token::Punct(Punct::SemiColon),
+            Token::Ident("e".into()),
+            Token::Punct(Punct::AmpersandEqual),
+            Token::Ident("f".into()),
+            Token::EoF,
+        ],
+    );
+}
+
+#[test]
+fn keyword_member_names() {
+    compare(
+        "obj.class; a.new(); x.default / 2",
+        &[
+            Token::Ident("obj".into()),
+            Token::Punct(Punct::Period),
+            Token::Ident("class".into()),
+            Token::Punct(Punct::SemiColon),
+            Token::Ident("a".into()),
+            Token::Punct(Punct::Period),
+            Token::Ident("new".into()),
+            Token::Punct(Punct::OpenParen),
+            Token::Punct(Punct::CloseParen),
+            Token::Punct(Punct::SemiColon),
+            Token::Ident("x".into()),
+            Token::Punct(Punct::Period),
+            Token::Ident("default".into()),
+            Token::Punct(Punct::ForwardSlash),
+            Token::Number("2".into()),
+            Token::EoF,
+        ],
+    );
+}
 
 #[test]
 fn html_comment_close() {
@@ -269,36 +782,344 @@ fn star_only_regex() {
 fn leading_space_regex() {
     let js = r"/ \{[\s\S]*$/";
     compare(
-        &js,
+        js,
         &[Token::RegEx(RegEx {
-            body: r" \{[\s\S]*$".into(),
+            body: r" \{[\s\S]*$",
             flags: None,
         })],
     )
 }
 
 #[test]
-#[should_panic]
-fn var_escaped_cr() {
-    let js = r"var\u000Dx;";
-    run_failure(js);
-}
-
-#[test]
-fn long_comment() {
-    let _ = pretty_env_logger::try_init();
-    let inner = "\n* <!-- I should not be a unique comment -->\n*\n";
-    let js = format!("/*{}*/", inner);
+fn regex_escaped_brackets() {
     compare(
-        &js,
-        &[Token::Comment(Comment {
-            kind: ress::tokens::CommentKind::Multi,
-            content: inner,
+        r"/\[abc\]/",
+        &[
+            Token::RegEx(RegEx {
+                body: r"\[abc\]",
+                flags: None,
+            }),
+            Token::EoF,
+        ],
+    );
+    compare(
+        r"/\[/g",
+        &[
+            Token::RegEx(RegEx {
+                body: r"\[",
+                flags: Some("g"),
+            }),
+            Token::EoF,
+        ],
+    );
+}
+
+#[test]
+fn non_bmp_source_chars() {
+    compare(
+        "'漢字かな한글' \"😀🎉\"",
+        &[
+            Token::String(StringLit::single("漢字かな한글", false)),
+            Token::String(StringLit::double("😀🎉", false)),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "/[漢-字]+😀/u",
+        &[
+            Token::RegEx(RegEx {
+                body: "[漢-字]+😀",
+                flags: Some("u"),
+            }),
+            Token::EoF,
+        ],
+    );
+}
+
+#[test]
+fn ident_boundaries() {
+    for ident in &["foo", "fooBar", "foo$_1"] {
+        compare(ident, &[Token::Ident((*ident).into()), Token::EoF]);
+    }
+    for (js, p) in &[
+        ("foo=bar", Punct::Equal),
+        ("foo*bar", Punct::Asterisk),
+        ("foo-bar", Punct::Dash),
+        ("foo+bar", Punct::Plus),
+    ] {
+        compare(
+            js,
+            &[
+                Token::Ident("foo".into()),
+                Token::Punct(*p),
+                Token::Ident("bar".into()),
+                Token::EoF,
+            ],
+        );
+    }
+}
+
+#[test]
+fn ident_boundaries_exhaustive() {
+    let puncts = [
+        "{", "}", "(", ")", ".", ";", ",", "[", "]", ":", "?", "?.", "~", ">", "<", "=", "!", "+",
+        "-", "*", "%", "|", "&", "^", "/", ">>>=", "...", "===", "!==", ">>>", "<<=", ">>=", "**=",
+        "&&=", "||=", "&&", "||", "==", "!=", "+=", "-=", "*=", "/=", "++", "--", "<<", ">>", "&=",
+        "|=", "^=", "%=", "=>", ">=", "<=", "**", "#", "@",
+    ];
+    for p in puncts.iter() {
+        let punct: Punct = p.parse().unwrap();
+        // close delimiters need an open one before them
+        let (js, skip) = match punct {
+            Punct::CloseBrace => (format!("{{a{}b", p), 1),
+            Punct::CloseParen => (format!("(a{}b", p), 1),
+            Punct::CloseBracket => (format!("[a{}b", p), 1),
+            _ => (format!("a{}b", p), 0),
+        };
+        let tokens: Vec<_> = panicing_scanner(&js).skip(skip).collect();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Ident("a".into()),
+                Token::Punct(punct),
+                Token::Ident("b".into()),
+                Token::EoF,
+            ],
+            "{:?}",
+            js
+        );
+    }
+    // white space, line terminators and comments
+    for sep in [
+        "\t", "\u{a0}", "\u{feff}", "\u{3000}", "\n", "\u{2028}", "/*c*/",
+    ]
+    .iter()
+    {
+        let js = format!("a{}b", sep);
+        let tokens: Vec<_> = panicing_scanner(&js).filter(|t| !t.is_comment()).collect();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Ident("a".into()),
+                Token::Ident("b".into()),
+                Token::EoF
+            ],
+            "{:?}",
+            js
+        );
+    }
+    compare(
+        "a'x'b",
+        &[
+            Token::Ident("a".into()),
+            Token::String(StringLit::single("x", false)),
+            Token::Ident("b".into()),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "a`x`b",
+        &[
+            Token::Ident("a".into()),
+            Token::Template(Template::no_sub_template("x", false, false, false)),
+            Token::Ident("b".into()),
+            Token::EoF,
+        ],
+    );
+    // anything that is ID_Continue doesn't end the identifier
+    for part in ["1", "$", "_", "\u{e9}", "\u{200c}", "\u{200d}", "\u{30fb}"].iter() {
+        let js = format!("a{}b", part);
+        compare(&js, &[Token::Ident(js.as_str().into()), Token::EoF]);
+    }
+}
+
+#[test]
+fn raw_comment_content() {
+    let inner = "*\n     * Decorated\n     *   block comment\n     ";
+    let js = format!("/*{}*/", inner);
+    let tokens = tokenize(&js).unwrap();
+    match &tokens[0] {
+        Token::Comment(c) => {
+            assert!(c.is_multi_line());
+            assert_eq!(c.content, inner);
+            assert_eq!(c.trimmed(), "*\n* Decorated\n*   block comment\n");
+        }
+        other => panic!("expected comment found {:?}", other),
+    }
+    compare(
+        "/*/* nested start */\n/// triple slash",
+        &[
+            Token::Comment(Comment::new_multi_line("/* nested start ")),
+            Token::Comment(Comment::new_single_line("/ triple slash")),
+            Token::EoF,
+        ],
+    );
+}
+
+#[test]
+#[should_panic]
+fn var_escaped_cr() {
+    let js = r"var\u000Dx;";
+    run_failure(js);
+}
+
+#[test]
+fn long_comment() {
+    let _ = pretty_env_logger::try_init();
+    let inner = "\n* <!-- I should not be a unique comment -->\n*\n";
+    let js = format!("/*{}*/", inner);
+    compare(
+        &js,
+        &[Token::Comment(Comment {
+            kind: ress::tokens::CommentKind::Multi,
+            content: inner,
             tail_content: None,
         })],
     )
 }
 
+#[test]
+fn to_source_round_trip() {
+    let js = r#"#!/usr/bin/env node
+'use strict'; "a\"b\u{1F600}" + '\x41\
+c';
+var if1 = \u0074his, $ = 0x1fn + 1_000.5e-3 + .5 + 0b1 + 0o7 + 017;
+let re = /[/\]]+(?<n>a)\k<n>/dgimsuy, y = a / 2 / b;
+x = `a\`${ `b${c}d${ {e: `f`} }` }g\${` + `` + `${x}`;
+if (a) /b/.test(c)
+/* multi
+   line */ // single
+<!-- html --> tail
+a
+--> close
+&&= ||= **= >>>= ?. ... => != !== <= >= << >> >>> ++ --;
+class A extends B { static #p = this?.q ?? null; }
+"#;
+    let items: Vec<_> = Scanner::new(js).map(Result::unwrap).collect();
+    let mut source = String::new();
+    let mut line = 1;
+    for item in &items {
+        if item.location.start.line > line {
+            source.push('\n');
+        } else if !source.is_empty() && !source.ends_with('#') {
+            // a private name can't have a space after the `#`
+            source.push(' ');
+        }
+        line = item.location.end.line;
+        source.push_str(&item.token.to_source());
+    }
+    let tokens: Vec<_> = Scanner::new(&source)
+        .map(|item| item.unwrap().token)
+        .collect();
+    assert_eq!(tokens.len(), items.len(), "{}", source);
+    for (token, item) in tokens.iter().zip(&items) {
+        assert_eq!(token, &item.token, "{}", source);
+        assert_eq!(token.to_source(), item.token.to_source());
+    }
+    assert!(source.contains(r"\u0074his"));
+}
+
+#[test]
+fn number_raw_text() {
+    for js in &[
+        "1e308",
+        "1e309",
+        "1e-324",
+        "5e-324",
+        "0.0000001",
+        "1.7976931348623159e308",
+        "007.50",
+        "08.5",
+        "00",
+        "0.00",
+        "1E+400",
+        "1_000e1_0",
+    ] {
+        compare(js, &[Token::Number((*js).into()), Token::EoF]);
+    }
+}
+
+#[test]
+fn number_then_ident_start() {
+    for js in &["123abc", "3in x", "0x1fz", "1.5e3x", "0b1$", "1_0_"] {
+        assert!(Scanner::new(js).any(|r| r.is_err()), "{:?}", js);
+    }
+    compare(
+        "20..toString()",
+        &[
+            Token::Number("20.".into()),
+            Token::Punct(Punct::Period),
+            Token::Ident("toString".into()),
+            Token::Punct(Punct::OpenParen),
+            Token::Punct(Punct::CloseParen),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "3 in x, 1n",
+        &[
+            Token::Number("3".into()),
+            Token::Keyword(Keyword::In("in")),
+            Token::Ident("x".into()),
+            Token::Punct(Punct::Comma),
+            Token::Number("1n".into()),
+            Token::EoF,
+        ],
+    );
+}
+
+#[test]
+fn signs_are_punctuation() {
+    compare(
+        "-5",
+        &[
+            Token::Punct(Punct::Dash),
+            Token::Number("5".into()),
+            Token::EoF,
+        ],
+    );
+    for js in &["a-5", "a- 5", "a -5"] {
+        compare(
+            js,
+            &[
+                Token::Ident("a".into()),
+                Token::Punct(Punct::Dash),
+                Token::Number("5".into()),
+                Token::EoF,
+            ],
+        );
+    }
+    compare(
+        "1-2",
+        &[
+            Token::Number("1".into()),
+            Token::Punct(Punct::Dash),
+            Token::Number("2".into()),
+            Token::EoF,
+        ],
+    );
+    // a sign after an exponent is part of the number
+    compare(
+        "x=+.5e-3",
+        &[
+            Token::Ident("x".into()),
+            Token::Punct(Punct::Equal),
+            Token::Punct(Punct::Plus),
+            Token::Number(".5e-3".into()),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "1--2",
+        &[
+            Token::Number("1".into()),
+            Token::Punct(Punct::DoubleDash),
+            Token::Number("2".into()),
+            Token::EoF,
+        ],
+    );
+}
+
 #[test]
 fn regex_column() {
     compare_with_position(
@@ -334,6 +1155,474 @@ fn run_failure(js: &str) {
     for _ in panicing_scanner(js) {}
 }
 
-fn panicing_scanner<'a>(js: &'a str) -> impl Iterator<Item = Token<&'a str>> {
+fn panicing_scanner(js: &str) -> impl Iterator<Item = Token<&str>> {
     Scanner::new(js).map(|r| r.unwrap().token)
 }
+
+#[test]
+fn spread_regex_context() {
+    compare(
+        "[...a] / 2",
+        &[
+            Token::Punct(Punct::OpenBracket),
+            Token::Punct(Punct::Ellipsis),
+            Token::Ident("a".into()),
+            Token::Punct(Punct::CloseBracket),
+            Token::Punct(Punct::ForwardSlash),
+            Token::Number("2".into()),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "f(...args)",
+        &[
+            Token::Ident("f".into()),
+            Token::Punct(Punct::OpenParen),
+            Token::Punct(Punct::Ellipsis),
+            Token::Ident("args".into()),
+            Token::Punct(Punct::CloseParen),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "[.../a/g]",
+        &[
+            Token::Punct(Punct::OpenBracket),
+            Token::Punct(Punct::Ellipsis),
+            Token::RegEx(RegEx::from_parts("a", Some("g"))),
+            Token::Punct(Punct::CloseBracket),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "[...function f(){} / 2]",
+        &[
+            Token::Punct(Punct::OpenBracket),
+            Token::Punct(Punct::Ellipsis),
+            Token::Keyword(Keyword::Function("function")),
+            Token::Ident("f".into()),
+            Token::Punct(Punct::OpenParen),
+            Token::Punct(Punct::CloseParen),
+            Token::Punct(Punct::OpenBrace),
+            Token::Punct(Punct::CloseBrace),
+            Token::Punct(Punct::ForwardSlash),
+            Token::Number("2".into()),
+            Token::Punct(Punct::CloseBracket),
+            Token::EoF,
+        ],
+    );
+    let spread: Token<&str> = Token::Punct(Punct::Ellipsis);
+    assert!(spread.matches_punct(Punct::Ellipsis));
+    assert!(!spread.matches_punct(Punct::Period));
+}
+
+#[test]
+fn regex_source_is_exact() {
+    let js = r"x = /[/]\/\\/g;";
+    let mut scanner = Scanner::new(js);
+    let item = scanner
+        .find(|item| item.as_ref().map(|i| i.token.is_regex()).unwrap_or(true))
+        .unwrap()
+        .unwrap();
+    assert_eq!(scanner.str_for(&item.span), Some(r"/[/]\/\\/g"));
+    let re = item.token.as_regex().unwrap();
+    assert_eq!(re.body, r"[/]\/\\");
+    assert_eq!(re.flags, Some("g"));
+    assert_eq!(item.token.to_source(), r"/[/]\/\\/g");
+}
+
+#[test]
+fn escaped_delimiters_are_not_rewritten() {
+    for (js, content) in &[
+        (r"'a\'b'", r"a\'b"),
+        (r#""a\"b""#, r#"a\"b"#),
+        (r#"'a"b'"#, r#"a"b"#),
+        (r#""a'b""#, r#"a'b"#),
+    ] {
+        let item = Scanner::new(js).next().unwrap().unwrap();
+        assert_eq!(item.token.as_string(), Some(*content));
+        assert_eq!(item.span.end - item.span.start, js.len());
+    }
+    let item = Scanner::new(r"/a\/b/").next().unwrap().unwrap();
+    assert_eq!(item.token.as_regex().unwrap().body, r"a\/b");
+}
+
+#[test]
+fn lossless_round_trip() {
+    let tricky =
+        "\u{feff}#!/usr/bin/env node\r\n'use strict';\tvar \\u{61}b = 0x1F_FFn, c = .5e-3;\r
+x = `a\\`${ `b${c}\r\nd` }\\${` + '\\'' + \"\\\"\" /* multi\u{2028}line */ // single\n\
+<!-- html --> tail\n/* a */--> b\nlet re = /[/\\]]+/dgimsuy, y = a / 2 /= b;\u{a0}\n\
+if (a) /b/.test(c)\nclass A { static #p = this?.q ?? null; }\n";
+    for js in &[
+        tricky,
+        include_str!("../../benches/fixtures/large.js"),
+        "",
+        " \n\t",
+    ] {
+        let items: Vec<_> = Scanner::new(js).lossless().map(Result::unwrap).collect();
+        let mut raw = String::new();
+        let mut source = String::new();
+        for (trivia, item) in &items {
+            raw.push_str(trivia);
+            raw.push_str(&js[item.span.range()]);
+            source.push_str(trivia);
+            source.push_str(&item.token.to_source());
+        }
+        assert_eq!(&raw, js);
+        assert_eq!(&source, js);
+        assert!(items.last().unwrap().1.is_eof());
+    }
+    let js = "a\n  --> close\n";
+    let raw: String = Scanner::new(js)
+        .lossless()
+        .map(|item| {
+            let (trivia, item) = item.unwrap();
+            format!("{}{}", trivia, &js[item.span.range()])
+        })
+        .collect();
+    assert_eq!(raw, js);
+}
+
+#[test]
+fn crlf_line_endings() {
+    let js = "// one\r\nlet a = `x\r\ny`\r\n/* two\r\n */\r\nb = 'c\\\r\nd'\r\ne\rf\u{2028}g\u{2029}h\r\n";
+    compare_with_position(
+        js,
+        &[
+            (Token::Comment(Comment::new_single_line(" one")), 1, 1),
+            (Token::Keyword(Keyword::Let("let")), 2, 1),
+            (Token::Ident("a".into()), 2, 5),
+            (Token::Punct(Punct::Equal), 2, 7),
+            (
+                Token::Template(Template::no_sub_template("x\r\ny", false, false, false)),
+                2,
+                9,
+            ),
+            (Token::Comment(Comment::new_multi_line(" two\r\n ")), 4, 1),
+            (Token::Ident("b".into()), 6, 1),
+            (Token::Punct(Punct::Equal), 6, 3),
+            (Token::String(StringLit::single("c\\\r\nd", false)), 6, 5),
+            (Token::Ident("e".into()), 8, 1),
+            (Token::Ident("f".into()), 9, 1),
+            (Token::Ident("g".into()), 10, 1),
+            (Token::Ident("h".into()), 11, 1),
+            (Token::EoF, 12, 1),
+        ],
+    );
+    let items: Vec<_> = Scanner::new(js).map(Result::unwrap).collect();
+    assert_eq!(items[0].span, ress::Span::new(0, 6));
+    assert_eq!(items[0].location.end, Position::new(1, 7));
+    assert_eq!(&js[items[4].span.range()], "`x\r\ny`");
+    assert_eq!(&js[items[5].span.range()], "/* two\r\n */");
+    assert_eq!(&js[items[8].span.range()], "'c\\\r\nd'");
+}
+
+#[test]
+fn crlf_error_positions() {
+    // an error points at the same place the token
+    // would have if it were terminated
+    for (js, line, column) in [
+        ("a\r\nb\r\n  'c", 3, 3),
+        ("a\r\n\r\n'c", 3, 1),
+        ("a\rb\r  'c", 3, 3),
+        ("a\r\nb\u{2028} 'c", 3, 2),
+        ("/* \r\n */ \r\n x = 'c", 3, 6),
+    ] {
+        let e = Scanner::new(js)
+            .find_map(Result::err)
+            .expect("unterminated string");
+        assert_eq!((e.line, e.column), (line, column), "{:?}", js);
+        let fixed = format!("{}'", js);
+        let string = Scanner::new(&fixed)
+            .map(Result::unwrap)
+            .find(|item| item.token.is_string())
+            .unwrap();
+        assert_eq!(
+            string.location.start,
+            Position::new(line, column),
+            "{:?}",
+            js
+        );
+        assert_eq!(string.span.start, e.idx);
+    }
+}
+
+#[test]
+fn template_escapes() {
+    compare(
+        r"`a\`b`",
+        &[
+            Token::Template(Template::no_sub_template(r"a\`b", false, false, false)),
+            Token::EoF,
+        ],
+    );
+    compare(
+        r"`\${x}`",
+        &[
+            Token::Template(Template::no_sub_template(r"\${x}", false, false, false)),
+            Token::EoF,
+        ],
+    );
+    compare(
+        r"`\n${x}`",
+        &[
+            Token::Template(Template::template_head(r"\n", false, false, false)),
+            Token::Ident("x".into()),
+            Token::Template(Template::template_tail("", false, false, false)),
+            Token::EoF,
+        ],
+    );
+    compare(
+        r"`${a}\${b}\\${c}\``",
+        &[
+            Token::Template(Template::template_head("", false, false, false)),
+            Token::Ident("a".into()),
+            Token::Template(Template::template_middle(r"\${b}\\", false, false, false)),
+            Token::Ident("c".into()),
+            Token::Template(Template::template_tail(r"\`", false, false, false)),
+            Token::EoF,
+        ],
+    );
+}
+
+#[test]
+fn regex_at_start_of_substitution() {
+    compare(
+        "`a${ /b/g }${ {} / 2 }`",
+        &[
+            Token::Template(Template::template_head("a", false, false, false)),
+            Token::RegEx(RegEx::from_parts("b", Some("g"))),
+            Token::Template(Template::template_middle("", false, false, false)),
+            Token::Punct(Punct::OpenBrace),
+            Token::Punct(Punct::CloseBrace),
+            Token::Punct(Punct::ForwardSlash),
+            Token::Number("2".into()),
+            Token::Template(Template::template_tail("", false, false, false)),
+            Token::EoF,
+        ],
+    );
+}
+
+#[test]
+fn optional_chaining() {
+    compare(
+        "a?.()/2",
+        &[
+            Token::Ident("a".into()),
+            Token::Punct(Punct::QuestionMarkDot),
+            Token::Punct(Punct::OpenParen),
+            Token::Punct(Punct::CloseParen),
+            Token::Punct(Punct::ForwardSlash),
+            Token::Number("2".into()),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "a?.b?.[i]?.(c).class / 2",
+        &[
+            Token::Ident("a".into()),
+            Token::Punct(Punct::QuestionMarkDot),
+            Token::Ident("b".into()),
+            Token::Punct(Punct::QuestionMarkDot),
+            Token::Punct(Punct::OpenBracket),
+            Token::Ident("i".into()),
+            Token::Punct(Punct::CloseBracket),
+            Token::Punct(Punct::QuestionMarkDot),
+            Token::Punct(Punct::OpenParen),
+            Token::Ident("c".into()),
+            Token::Punct(Punct::CloseParen),
+            Token::Punct(Punct::Period),
+            Token::Ident("class".into()),
+            Token::Punct(Punct::ForwardSlash),
+            Token::Number("2".into()),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "a?.new",
+        &[
+            Token::Ident("a".into()),
+            Token::Punct(Punct::QuestionMarkDot),
+            Token::Ident("new".into()),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "a?.5:1",
+        &[
+            Token::Ident("a".into()),
+            Token::Punct(Punct::QuestionMark),
+            Token::Number(".5".into()),
+            Token::Punct(Punct::Colon),
+            Token::Number("1".into()),
+            Token::EoF,
+        ],
+    );
+}
+
+#[test]
+fn meta_properties() {
+    compare(
+        "import.meta.url",
+        &[
+            Token::Keyword(Keyword::Import("import")),
+            Token::Punct(Punct::Period),
+            Token::Ident("meta".into()),
+            Token::Punct(Punct::Period),
+            Token::Ident("url".into()),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "new.target / 2",
+        &[
+            Token::Keyword(Keyword::New("new")),
+            Token::Punct(Punct::Period),
+            Token::Ident("target".into()),
+            Token::Punct(Punct::ForwardSlash),
+            Token::Number("2".into()),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "import('a')",
+        &[
+            Token::Keyword(Keyword::Import("import")),
+            Token::Punct(Punct::OpenParen),
+            Token::String(StringLit::single("a", false)),
+            Token::Punct(Punct::CloseParen),
+            Token::EoF,
+        ],
+    );
+}
+
+#[test]
+fn regex_after_in_and_of() {
+    compare(
+        "for (x of /a/) {}",
+        &[
+            Token::Keyword(Keyword::For("for")),
+            Token::Punct(Punct::OpenParen),
+            Token::Ident("x".into()),
+            Token::Ident("of".into()),
+            Token::RegEx(RegEx::from_parts("a", None)),
+            Token::Punct(Punct::CloseParen),
+            Token::Punct(Punct::OpenBrace),
+            Token::Punct(Punct::CloseBrace),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "x in /a/",
+        &[
+            Token::Ident("x".into()),
+            Token::Keyword(Keyword::In("in")),
+            Token::RegEx(RegEx::from_parts("a", None)),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "for await ([a] of /b/g);",
+        &[
+            Token::Keyword(Keyword::For("for")),
+            Token::Keyword(Keyword::Await("await")),
+            Token::Punct(Punct::OpenParen),
+            Token::Punct(Punct::OpenBracket),
+            Token::Ident("a".into()),
+            Token::Punct(Punct::CloseBracket),
+            Token::Ident("of".into()),
+            Token::RegEx(RegEx::from_parts("b", Some("g"))),
+            Token::Punct(Punct::CloseParen),
+            Token::Punct(Punct::SemiColon),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "for (i = of / 2; a.of / 2;);",
+        &[
+            Token::Keyword(Keyword::For("for")),
+            Token::Punct(Punct::OpenParen),
+            Token::Ident("i".into()),
+            Token::Punct(Punct::Equal),
+            Token::Ident("of".into()),
+            Token::Punct(Punct::ForwardSlash),
+            Token::Number("2".into()),
+            Token::Punct(Punct::SemiColon),
+            Token::Ident("a".into()),
+            Token::Punct(Punct::Period),
+            Token::Ident("of".into()),
+            Token::Punct(Punct::ForwardSlash),
+            Token::Number("2".into()),
+            Token::Punct(Punct::SemiColon),
+            Token::Punct(Punct::CloseParen),
+            Token::Punct(Punct::SemiColon),
+            Token::EoF,
+        ],
+    );
+}
+
+#[test]
+fn comment_in_regex_position() {
+    compare(
+        "return //comment\n x",
+        &[
+            Token::Keyword(Keyword::Return("return")),
+            Token::Comment(Comment::new_single_line("comment")),
+            Token::Ident("x".into()),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "x = //\n/re/",
+        &[
+            Token::Ident("x".into()),
+            Token::Punct(Punct::Equal),
+            Token::Comment(Comment::new_single_line("")),
+            Token::RegEx(RegEx::from_parts("re", None)),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "a / / b /",
+        &[
+            Token::Ident("a".into()),
+            Token::Punct(Punct::ForwardSlash),
+            Token::RegEx(RegEx::from_parts(" b ", None)),
+            Token::EoF,
+        ],
+    );
+    compare(
+        "a = / /",
+        &[
+            Token::Ident("a".into()),
+            Token::Punct(Punct::Equal),
+            Token::RegEx(RegEx::from_parts(" ", None)),
+            Token::EoF,
+        ],
+    );
+}
+
+#[test]
+fn template_raw_and_cooked() {
+    let js = "`a\r\n\\tb${x}\\u{63}\rd`";
+    let parts: Vec<_> = Scanner::new(js)
+        .filter_map(|item| match item.unwrap().token {
+            Token::Template(t) => Some(t),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(parts.len(), 2);
+    assert_eq!(parts[0].raw(), "a\r\n\\tb");
+    assert_eq!(parts[0].cooked().unwrap(), "a\n\tb");
+    assert_eq!(parts[1].raw(), "\\u{63}\rd");
+    assert_eq!(parts[1].cooked().unwrap(), "c\nd");
+    let invalid = Scanner::new(r"tag`\unicode`").nth(1).unwrap().unwrap();
+    match invalid.token {
+        Token::Template(t) => {
+            assert_eq!(t.raw(), r"\unicode");
+            assert_eq!(t.cooked(), None);
+        }
+        _ => panic!("expected a template, found {:?}", invalid.token),
+    }
+}